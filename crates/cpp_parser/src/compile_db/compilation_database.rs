@@ -0,0 +1,125 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{parser::ParserConfig, preprocessor::normalize_path};
+
+use super::{compile_command::split_command, CppCompileCommand};
+
+/// The compile commands of a project, from `compile_commands.json` or `compile_flags.txt`
+#[derive(Debug, Clone, Default)]
+pub struct CppCompilationDatabase {
+    commands: Vec<CppCompileCommand>,
+    /// The flags of `compile_flags.txt`, they are the same for every file
+    fallback: Option<CppCompileCommand>,
+}
+
+/// An entry of `compile_commands.json`, CMake writes `command` and Bazel `arguments`
+#[derive(Debug, Deserialize)]
+struct CompileCommandEntry {
+    directory: PathBuf,
+    file: PathBuf,
+    #[serde(default)]
+    arguments: Option<Vec<String>>,
+    #[serde(default)]
+    command: Option<String>,
+}
+
+impl CppCompilationDatabase {
+    pub fn from_compile_commands(json: &str) -> Result<Self, serde_json::Error> {
+        let entries: Vec<CompileCommandEntry> = serde_json::from_str(json)?;
+        let commands = entries
+            .into_iter()
+            .map(|entry| {
+                let arguments = match (entry.arguments, entry.command) {
+                    (Some(arguments), _) => arguments,
+                    (None, Some(command)) => split_command(&command),
+                    (None, None) => Vec::new(),
+                };
+                CppCompileCommand::new(&entry.directory, &entry.file, arguments)
+            })
+            .collect();
+
+        Ok(CppCompilationDatabase {
+            commands,
+            fallback: None,
+        })
+    }
+
+    /// `compile_flags.txt` has one flag per line, relative paths start at `directory`
+    pub fn from_compile_flags(text: &str, directory: &Path) -> Self {
+        let mut arguments = vec!["clang++".to_string()];
+        arguments.extend(
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        );
+
+        CppCompilationDatabase {
+            commands: Vec::new(),
+            fallback: Some(CppCompileCommand::new(directory, Path::new(""), arguments)),
+        }
+    }
+
+    /// Read `compile_commands.json` from `directory` or its `build` directory, then
+    /// `compile_flags.txt` from `directory`. A malformed `compile_commands.json` is skipped
+    /// for the next file and only reported when no file can be read.
+    pub fn load(directory: &Path) -> Result<Option<Self>, serde_json::Error> {
+        let mut error = None;
+        for dir in [directory.to_path_buf(), directory.join("build")] {
+            if let Ok(json) = std::fs::read_to_string(dir.join("compile_commands.json")) {
+                match Self::from_compile_commands(&json) {
+                    Ok(database) => return Ok(Some(database)),
+                    Err(err) => error = error.or(Some(err)),
+                }
+            }
+        }
+
+        match std::fs::read_to_string(directory.join("compile_flags.txt")) {
+            Ok(text) => Ok(Some(Self::from_compile_flags(&text, directory))),
+            Err(_) => error.map_or(Ok(None), Err),
+        }
+    }
+
+    pub fn get_commands(&self) -> &[CppCompileCommand] {
+        &self.commands
+    }
+
+    /// The command of `file`. A file without one, usually a header, borrows the command of
+    /// the nearest source file: the one with the same name, then the one that shares the
+    /// most directories with it.
+    pub fn get_command(&self, file: &Path) -> Option<CppCompileCommand> {
+        let file = normalize_path(file);
+        if let Some(command) = self.commands.iter().find(|command| command.file == file) {
+            return Some(command.clone());
+        }
+
+        // The first of equally near commands wins
+        let nearest = self
+            .commands
+            .iter()
+            .rev()
+            .max_by_key(|command| nearness(&file, &command.file))
+            .or(self.fallback.as_ref())?;
+        Some(CppCompileCommand {
+            file,
+            ..nearest.clone()
+        })
+    }
+
+    pub fn get_parser_config(&self, file: &Path) -> Option<ParserConfig<'static>> {
+        Some(self.get_command(file)?.to_parser_config())
+    }
+}
+
+/// Whether `source` has the name of `file` and how many directories they share
+fn nearness(file: &Path, source: &Path) -> (bool, usize) {
+    let same_name = file.file_stem() == source.file_stem();
+    let shared = file
+        .components()
+        .zip(source.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    (same_name, shared)
+}
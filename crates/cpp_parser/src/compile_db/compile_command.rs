@@ -0,0 +1,233 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    kind::CppLanguageLevel,
+    parser::ParserConfig,
+    preprocessor::{normalize_path, CppRealVfs},
+};
+
+/// The command line that compiles `file`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CppCompileCommand {
+    /// The working directory of the command, relative paths in the arguments start here
+    pub directory: PathBuf,
+    pub file: PathBuf,
+    /// The whole command line, the compiler first
+    pub arguments: Vec<String>,
+}
+
+/// Flags that take a value, either joined like `-Iinclude` or as the next argument
+const GNU_VALUE_FLAGS: [&str; 10] = [
+    "-D",
+    "-U",
+    "-I",
+    "-isystem",
+    "-iquote",
+    "-idirafter",
+    "-F",
+    "-iframework",
+    "-include",
+    "-imsvc",
+];
+const MSVC_VALUE_FLAGS: [&str; 9] = [
+    "-D", "/D", "-U", "/U", "-I", "/I", "/FI", "-imsvc", "-include",
+];
+
+impl CppCompileCommand {
+    pub fn new(directory: &Path, file: &Path, arguments: Vec<String>) -> Self {
+        CppCompileCommand {
+            directory: normalize_path(directory),
+            file: normalize_path(&directory.join(file)),
+            arguments,
+        }
+    }
+
+    /// `cl.exe`, `clang-cl` or `--driver-mode=cl` take MSVC style flags like `/DNAME`
+    pub fn is_msvc(&self) -> bool {
+        let driver = self.arguments.first().map_or("", |driver| {
+            let name = driver.rsplit(['/', '\\']).next().unwrap_or(driver);
+            name.strip_suffix(".exe").unwrap_or(name)
+        });
+        driver.eq_ignore_ascii_case("cl")
+            || driver.eq_ignore_ascii_case("clang-cl")
+            || self
+                .arguments
+                .iter()
+                .any(|argument| argument == "--driver-mode=cl")
+    }
+
+    /// The language level, macros and search paths of the command, headers are read from
+    /// disk. `-std=gnu++17` is C++17 with GNU extensions, an MSVC command without `/std:`
+    /// gives `MsvcCpp`.
+    pub fn to_parser_config(&self) -> ParserConfig<'static> {
+        let is_msvc = self.is_msvc();
+        let value_flags: &[&str] = if is_msvc {
+            &MSVC_VALUE_FLAGS
+        } else {
+            &GNU_VALUE_FLAGS
+        };
+
+        let mut level = None;
+        let mut gnu_extensions = false;
+        let mut flags = Vec::new();
+        let mut arguments = self.arguments.iter().skip(1);
+        while let Some(argument) = arguments.next() {
+            // What follows `/link` is for the linker: /link /DEBUG
+            if is_msvc && argument.eq_ignore_ascii_case("/link") {
+                break;
+            }
+            if let Some(std) = argument
+                .strip_prefix("-std=")
+                .or_else(|| argument.strip_prefix("/std:"))
+                .or_else(|| argument.strip_prefix("-std:"))
+            {
+                if let Some((std_level, is_gnu)) = language_level(std) {
+                    level = Some(std_level);
+                    gnu_extensions = is_gnu;
+                }
+                continue;
+            }
+
+            let Some(flag) = value_flags.iter().find(|flag| argument.starts_with(**flag)) else {
+                continue;
+            };
+            let value = if argument.len() == flag.len() {
+                match arguments.next() {
+                    Some(value) => value.as_str(),
+                    None => break,
+                }
+            } else {
+                &argument[flag.len()..]
+            };
+            // Other options share the prefix of `/D` and `/U`, a macro name must follow
+            if matches!(*flag, "-D" | "/D" | "-U" | "/U") && !starts_with_macro_name(value) {
+                continue;
+            }
+            flags.push((*flag, value));
+        }
+
+        let level = match level {
+            Some(level) => level,
+            None if is_msvc => CppLanguageLevel::MsvcCpp,
+            None => CppLanguageLevel::default(),
+        };
+        let mut config = ParserConfig::new(level, None);
+        config.gnu_extensions = gnu_extensions;
+        config.msvc_extensions = is_msvc;
+        // Searched after every other system path, wherever they are on the line
+        let mut after_paths = Vec::new();
+        for (flag, value) in flags {
+            match flag {
+                "-D" | "/D" => {
+                    let definition = match value.split_once('=') {
+                        Some((name, body)) => format!("{} {}", name, body),
+                        None => format!("{} 1", value),
+                    };
+                    config.predefined_macros.define_text(&definition);
+                }
+                "-U" | "/U" => {
+                    config.predefined_macros.undef(value);
+                }
+                "-I" | "/I" => config
+                    .include_resolver
+                    .include_paths
+                    .push(self.resolve_path(value)),
+                "-iquote" => config
+                    .include_resolver
+                    .quote_paths
+                    .push(self.resolve_path(value)),
+                "-isystem" | "-imsvc" => config
+                    .include_resolver
+                    .system_paths
+                    .push(self.resolve_path(value)),
+                "-idirafter" => after_paths.push(self.resolve_path(value)),
+                "-F" | "-iframework" => config
+                    .include_resolver
+                    .framework_paths
+                    .push(self.resolve_path(value)),
+                "-include" | "/FI" => config.forced_includes.push(self.resolve_path(value)),
+                _ => {}
+            }
+        }
+
+        config.include_resolver.system_paths.extend(after_paths);
+        config.file_path = Some(self.file.clone());
+        config.vfs = Some(Box::new(CppRealVfs));
+        config
+    }
+
+    fn resolve_path(&self, path: &str) -> PathBuf {
+        normalize_path(&self.directory.join(path))
+    }
+}
+
+/// The value of `-D` or `-U` starts with the name of the macro
+fn starts_with_macro_name(value: &str) -> bool {
+    value
+        .chars()
+        .next()
+        .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+}
+
+/// The level of a `-std=` value and whether it turns on GNU extensions, newer standards
+/// than the parser knows are the newest one
+fn language_level(std: &str) -> Option<(CppLanguageLevel, bool)> {
+    let std = std.to_ascii_lowercase();
+    let (version, is_gnu) = match std.strip_prefix("gnu++") {
+        Some(version) => (version, true),
+        None => (std.strip_prefix("c++")?, false),
+    };
+    let version = match version {
+        "98" | "03" | "0x" => "11",
+        "1y" => "14",
+        "1z" => "17",
+        "2a" => "20",
+        "2b" | "2c" | "26" | "latest" => "23",
+        version => version,
+    };
+    Some((version.parse().ok()?, is_gnu))
+}
+
+/// Split a shell command line into its arguments. Quotes group words, a backslash escapes
+/// quotes, spaces and itself but is kept in front of anything else, like in Windows paths.
+pub(crate) fn split_command(command: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut current = String::new();
+    let mut in_argument = false;
+    let mut quote = None;
+    let mut chars = command.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (ch, quote) {
+            ('\\', Some('\'')) => current.push(ch),
+            ('\\', _) => {
+                in_argument = true;
+                match chars.peek() {
+                    Some(&next @ ('"' | '\'' | '\\' | ' ')) => {
+                        current.push(next);
+                        chars.next();
+                    }
+                    _ => current.push(ch),
+                }
+            }
+            ('"' | '\'', None) => {
+                quote = Some(ch);
+                in_argument = true;
+            }
+            (_, Some(open)) if ch == open => quote = None,
+            (_, None) if ch.is_whitespace() => {
+                if in_argument {
+                    arguments.push(std::mem::take(&mut current));
+                    in_argument = false;
+                }
+            }
+            _ => {
+                current.push(ch);
+                in_argument = true;
+            }
+        }
+    }
+    if in_argument {
+        arguments.push(current);
+    }
+    arguments
+}
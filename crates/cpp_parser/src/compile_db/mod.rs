@@ -0,0 +1,6 @@
+mod compilation_database;
+mod compile_command;
+mod test;
+
+pub use compilation_database::CppCompilationDatabase;
+pub use compile_command::CppCompileCommand;
@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{CppCompilationDatabase, CppCompileCommand, CppLanguageLevel, ParserConfig};
+
+    fn command(arguments: &[&str]) -> CppCompileCommand {
+        CppCompileCommand::new(
+            Path::new("project/build"),
+            Path::new("../src/main.cpp"),
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect(),
+        )
+    }
+
+    fn macro_body(config: &ParserConfig, name: &str) -> Option<String> {
+        let body = config.predefined_macros.get(name)?.get_body();
+        Some(
+            body.iter()
+                .map(|token| token.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    #[test]
+    fn test_language_level() {
+        let level = |arguments: &[&str]| {
+            let config = command(arguments).to_parser_config();
+            (config.level, config.gnu_extensions, config.msvc_extensions)
+        };
+        assert_eq!(
+            level(&["g++", "-std=c++20"]),
+            (CppLanguageLevel::Cpp20, false, false)
+        );
+        assert_eq!(
+            level(&["g++", "-std=c++1z"]),
+            (CppLanguageLevel::Cpp17, false, false)
+        );
+        assert_eq!(
+            level(&["g++", "-std=c++2b"]),
+            (CppLanguageLevel::Cpp23, false, false)
+        );
+        assert_eq!(
+            level(&["g++", "-std=gnu++17"]),
+            (CppLanguageLevel::Cpp17, true, false)
+        );
+        assert_eq!(
+            level(&["g++", "-std=gnu++2a"]),
+            (CppLanguageLevel::Cpp20, true, false)
+        );
+        assert_eq!(
+            level(&["g++", "-std=gnu++14", "-std=c++17"]),
+            (CppLanguageLevel::Cpp17, false, false)
+        );
+        assert_eq!(
+            level(&["clang++", "-std=c++11", "-std=c++14"]),
+            (CppLanguageLevel::Cpp14, false, false)
+        );
+        assert_eq!(
+            level(&["clang++", "-c"]),
+            (CppLanguageLevel::default(), false, false)
+        );
+        assert_eq!(
+            level(&["C:\\VS\\bin\\cl.exe", "/std:c++20"]),
+            (CppLanguageLevel::Cpp20, false, true)
+        );
+        assert_eq!(
+            level(&["clang", "--driver-mode=cl", "-std:c++17"]),
+            (CppLanguageLevel::Cpp17, false, true)
+        );
+        assert_eq!(
+            level(&["cl.exe", "/c"]),
+            (CppLanguageLevel::MsvcCpp, false, true)
+        );
+    }
+
+    #[test]
+    fn test_compile_flags() {
+        let config = command(&[
+            "clang++",
+            "-DDEBUG",
+            "-D",
+            "VERSION=2",
+            "-DMAX(a,b)=((a)>(b)?(a):(b))",
+            "-DREMOVED",
+            "-UREMOVED",
+            "-I../include",
+            "-idirafter",
+            "/opt/after",
+            "-isystem",
+            "third_party",
+            "-iquote",
+            ".",
+            "-F/Library/Frameworks",
+            "-include",
+            "pch.h",
+            "-o",
+            "main.o",
+            "-c",
+            "../src/main.cpp",
+        ])
+        .to_parser_config();
+
+        assert_eq!(macro_body(&config, "DEBUG").as_deref(), Some("1"));
+        assert_eq!(macro_body(&config, "VERSION").as_deref(), Some("2"));
+        assert_eq!(
+            config
+                .predefined_macros
+                .get("MAX")
+                .and_then(|it| it.get_params()),
+            Some(&["a".to_string(), "b".to_string()][..])
+        );
+        assert_eq!(macro_body(&config, "REMOVED"), None);
+
+        let resolver = &config.include_resolver;
+        assert_eq!(
+            resolver.include_paths,
+            vec![PathBuf::from("project/include")]
+        );
+        assert_eq!(resolver.quote_paths, vec![PathBuf::from("project/build")]);
+        assert_eq!(
+            resolver.system_paths,
+            vec![
+                PathBuf::from("project/build/third_party"),
+                PathBuf::from("/opt/after")
+            ]
+        );
+        assert_eq!(
+            resolver.framework_paths,
+            vec![PathBuf::from("/Library/Frameworks")]
+        );
+        assert_eq!(
+            config.forced_includes,
+            vec![PathBuf::from("project/build/pch.h")]
+        );
+
+        let config = command(&[
+            "cl.exe",
+            "/DWIN32",
+            "/D",
+            "_DEBUG",
+            "/D=1",
+            "/Iinclude",
+            "/FI",
+            "pch.h",
+            "main.cpp",
+            "/link",
+            "/DEBUG",
+            "/INCREMENTAL",
+        ])
+        .to_parser_config();
+        assert_eq!(macro_body(&config, "WIN32").as_deref(), Some("1"));
+        assert_eq!(macro_body(&config, "_DEBUG").as_deref(), Some("1"));
+        assert_eq!(macro_body(&config, "EBUG"), None);
+        assert_eq!(config.predefined_macros.iter().count(), 2);
+        assert_eq!(
+            config.include_resolver.include_paths,
+            vec![PathBuf::from("project/build/include")]
+        );
+        assert_eq!(
+            config.forced_includes,
+            vec![PathBuf::from("project/build/pch.h")]
+        );
+    }
+
+    #[test]
+    fn test_compile_commands() {
+        let json = r#"[
+            {
+                "directory": "project/build",
+                "command": "/usr/bin/g++ -DNAME=\"a b\" -I'dir with space' -std=c++20 -c ../src/main.cpp",
+                "file": "../src/main.cpp"
+            },
+            {
+                "directory": "project",
+                "arguments": ["clang++", "-DUTIL", "-c", "src/util/util.cpp"],
+                "file": "src/util/util.cpp"
+            },
+            {
+                "directory": "project",
+                "arguments": ["clang++", "-DOTHER", "-c", "lib/other.cpp"],
+                "file": "lib/other.cpp"
+            }
+        ]"#;
+        let database = CppCompilationDatabase::from_compile_commands(json).unwrap();
+        assert_eq!(database.get_commands().len(), 3);
+
+        let main = database
+            .get_command(Path::new("project/src/main.cpp"))
+            .unwrap();
+        assert_eq!(
+            main.arguments,
+            vec![
+                "/usr/bin/g++",
+                "-DNAME=a b",
+                "-Idir with space",
+                "-std=c++20",
+                "-c",
+                "../src/main.cpp"
+            ]
+        );
+        let config = database
+            .get_parser_config(Path::new("project/src/main.cpp"))
+            .unwrap();
+        assert_eq!(config.level, CppLanguageLevel::Cpp20);
+        assert_eq!(macro_body(&config, "NAME").as_deref(), Some("a b"));
+
+        // Headers borrow the command of the source file with their name, then of the
+        // nearest one
+        let header = |path: &str| {
+            let command = database.get_command(Path::new(path)).unwrap();
+            assert_eq!(command.file, PathBuf::from(path));
+            command.arguments[1].clone()
+        };
+        assert_eq!(header("project/src/util/util.h"), "-DUTIL");
+        assert_eq!(header("project/lib/other.h"), "-DOTHER");
+        assert_eq!(header("project/src/config.h"), "-DNAME=a b");
+        assert_eq!(header("project/include/other.h"), "-DOTHER");
+
+        assert!(CppCompilationDatabase::from_compile_commands("{").is_err());
+        assert!(CppCompilationDatabase::default()
+            .get_command(Path::new("main.cpp"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_load() {
+        let directory =
+            std::env::temp_dir().join(format!("cpp_parser_load_{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("build")).unwrap();
+        assert!(matches!(CppCompilationDatabase::load(&directory), Ok(None)));
+
+        // A malformed compile_commands.json is reported without a fallback
+        std::fs::write(directory.join("compile_commands.json"), "[{").unwrap();
+        assert!(CppCompilationDatabase::load(&directory).is_err());
+
+        // and skipped for the next file with one
+        std::fs::write(
+            directory.join("build/compile_commands.json"),
+            r#"[{"directory": ".", "file": "main.cpp", "arguments": ["g++", "-DBUILD"]}]"#,
+        )
+        .unwrap();
+        let database = CppCompilationDatabase::load(&directory).unwrap().unwrap();
+        assert_eq!(database.get_commands().len(), 1);
+
+        std::fs::remove_file(directory.join("build/compile_commands.json")).unwrap();
+        std::fs::write(directory.join("compile_flags.txt"), "-DFLAGS\n").unwrap();
+        let database = CppCompilationDatabase::load(&directory).unwrap().unwrap();
+        let config = database
+            .get_parser_config(&directory.join("main.cpp"))
+            .unwrap();
+        assert_eq!(macro_body(&config, "FLAGS").as_deref(), Some("1"));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_compile_flags_txt() {
+        let database = CppCompilationDatabase::from_compile_flags(
+            "-std=c++17\n-Iinclude\n\n-DFLAGS\n",
+            Path::new("project"),
+        );
+        let config = database
+            .get_parser_config(Path::new("project/src/any.h"))
+            .unwrap();
+        assert_eq!(config.level, CppLanguageLevel::Cpp17);
+        assert_eq!(
+            config.include_resolver.include_paths,
+            vec![PathBuf::from("project/include")]
+        );
+        assert_eq!(macro_body(&config, "FLAGS").as_deref(), Some("1"));
+    }
+}
@@ -1,336 +1,859 @@
 use crate::{
     grammar::ParseResult,
-    kind::{BinaryOperator, CppSyntaxKind, CppTokenKind, CppOpKind, UnaryOperator},
+    kind::{
+        BinaryOperator, CppFeature, CppOpKind, CppSyntaxKind, CppTokenKind, UNARY_PRECEDENCE,
+        UnaryOperator,
+    },
     parser::{CppParser, MarkerEventContainer},
     parser_error::CppParseError,
 };
 
-use super::{expect_token, if_token_bump, parse_compound_stat};
+use super::{
+    expect_token, if_token_bump, parse_compound_stat,
+    stats::{
+        parse_attribute_lists, parse_decl_specifier, parse_noexcept_specifier,
+        parse_parameter_list, parse_template_parameter_list,
+    },
+    types::{
+        is_builtin_type_keyword, is_type_start, parse_name, parse_type, parse_type_id,
+        parse_type_specifier, scan_abstract_declarator, scan_balanced_parens, scan_type_id,
+    },
+};
 
+/// Parse a full expression, including the comma operator
 pub fn parse_expr(p: &mut CppParser) -> ParseResult {
-    // parse_sub_expr(p, 0)
-    todo!()
-}
-
-// fn parse_sub_expr(p: &mut LuaParser, limit: i32) -> ParseResult {
-//     let uop = LuaOpKind::to_unary_operator(p.current_token());
-//     let mut cm = if uop != UnaryOperator::OpNop {
-//         let m = p.mark(CppSyntaxKind::UnaryExpr);
-//         let range = p.current_token_range();
-//         p.bump();
-//         match parse_sub_expr(p, UNARY_PRIORITY) {
-//             Ok(_) => {}
-//             Err(err) => {
-//                 p.push_error(LuaParseError::syntax_error_from(
-//                     &t!("unary operator not followed by expression"),
-//                     range,
-//                 ));
-//                 return Err(err);
-//             }
-//         }
-//         m.complete(p)
-//     } else {
-//         parse_simple_expr(p)?
-//     };
-
-//     let mut bop = LuaOpKind::to_binary_operator(p.current_token());
-//     while bop != BinaryOperator::OpNop && bop.get_priority().left > limit {
-//         let range = p.current_token_range();
-//         let m = cm.precede(p, CppSyntaxKind::BinaryExpr);
-//         p.bump();
-//         match parse_sub_expr(p, bop.get_priority().right) {
-//             Ok(_) => {}
-//             Err(err) => {
-//                 p.push_error(LuaParseError::syntax_error_from(
-//                     &t!("binary operator not followed by expression"),
-//                     range,
-//                 ));
-
-//                 return Err(err);
-//             }
-//         }
-
-//         cm = m.complete(p);
-//         bop = LuaOpKind::to_binary_operator(p.current_token());
-//     }
-
-//     Ok(cm)
-// }
-
-// fn parse_simple_expr(p: &mut LuaParser) -> ParseResult {
-//     match p.current_token() {
-//         CppTokenKind::TkInt
-//         | CppTokenKind::TkFloat
-//         | CppTokenKind::TkComplex
-//         | CppTokenKind::TkNil
-//         | CppTokenKind::TkTrue
-//         | CppTokenKind::TkFalse
-//         | CppTokenKind::TkDots
-//         | CppTokenKind::TkString
-//         | CppTokenKind::TkLongString => {
-//             let m = p.mark(CppSyntaxKind::LiteralExpr);
-//             p.bump();
-//             Ok(m.complete(p))
-//         }
-//         CppTokenKind::TkLeftBrace => parse_table_expr(p),
-//         CppTokenKind::TkFunction => parse_closure_expr(p),
-//         _ => parse_suffixed_expr(p),
-//     }
-// }
-
-// pub fn parse_closure_expr(p: &mut LuaParser) -> ParseResult {
-//     let m = p.mark(CppSyntaxKind::ClosureExpr);
-
-//     if_token_bump(p, CppTokenKind::TkFunction);
-//     parse_param_list(p)?;
-
-//     if p.current_token() != CppTokenKind::TkEnd {
-//         parse_block(p)?;
-//     }
-
-//     expect_token(p, CppTokenKind::TkEnd)?;
-//     Ok(m.complete(p))
-// }
-
-// fn parse_param_list(p: &mut LuaParser) -> ParseResult {
-//     let m = p.mark(CppSyntaxKind::ParamList);
-
-//     expect_token(p, CppTokenKind::TkLeftParen)?;
-//     if p.current_token() != CppTokenKind::TkRightParen {
-//         parse_param_name(p)?;
-//         while p.current_token() == CppTokenKind::TkComma {
-//             p.bump();
-//             parse_param_name(p)?;
-//         }
-//     }
-
-//     expect_token(p, CppTokenKind::TkRightParen)?;
-//     Ok(m.complete(p))
-// }
-
-// fn parse_param_name(p: &mut LuaParser) -> ParseResult {
-//     let m = p.mark(CppSyntaxKind::ParamName);
-
-//     if p.current_token() == CppTokenKind::TkName || p.current_token() == CppTokenKind::TkDots {
-//         p.bump();
-//     } else {
-//         return Err(LuaParseError::syntax_error_from(
-//             &t!("expect parameter name"),
-//             p.current_token_range(),
-//         ));
-//     }
-
-//     Ok(m.complete(p))
-// }
-
-// fn parse_table_expr(p: &mut LuaParser) -> ParseResult {
-//     let mut m = p.mark(CppSyntaxKind::TableEmptyExpr);
-//     p.bump();
-
-//     if p.current_token() == CppTokenKind::TkRightBrace {
-//         p.bump();
-//         return Ok(m.complete(p));
-//     }
-
-//     let mut cm = parse_field(p)?;
-//     match cm.kind {
-//         CppSyntaxKind::TableFieldAssign => {
-//             m.set_kind(p, CppSyntaxKind::TableObjectExpr);
-//         }
-//         CppSyntaxKind::TableFieldValue => {
-//             m.set_kind(p, CppSyntaxKind::TableArrayExpr);
-//         }
-//         _ => {}
-//     }
-
-//     while p.current_token() == CppTokenKind::TkComma
-//         || p.current_token() == CppTokenKind::TkSemicolon
-//     {
-//         p.bump();
-//         if p.current_token() == CppTokenKind::TkRightBrace {
-//             break;
-//         }
-//         cm = parse_field(p)?;
-//         if cm.kind == CppSyntaxKind::TableFieldAssign {
-//             m.set_kind(p, CppSyntaxKind::TableObjectExpr);
-//         }
-//     }
-
-//     expect_token(p, CppTokenKind::TkRightBrace)?;
-//     Ok(m.complete(p))
-// }
-
-// fn parse_field(p: &mut LuaParser) -> ParseResult {
-//     let mut m = p.mark(CppSyntaxKind::TableFieldValue);
-
-//     if p.current_token() == CppTokenKind::TkLeftBracket {
-//         m.set_kind(p, CppSyntaxKind::TableFieldAssign);
-//         p.bump();
-//         parse_expr(p)?;
-//         expect_token(p, CppTokenKind::TkRightBracket)?;
-//         expect_token(p, CppTokenKind::TkAssign)?;
-//         parse_expr(p)?;
-//     } else if p.current_token() == CppTokenKind::TkName {
-//         if p.peek_next_token() == CppTokenKind::TkAssign {
-//             m.set_kind(p, CppSyntaxKind::TableFieldAssign);
-//             p.bump();
-//             p.bump();
-//             parse_expr(p)?;
-//         } else {
-//             parse_expr(p)?;
-//         }
-//     } else {
-//         parse_expr(p)?;
-//     }
-
-//     Ok(m.complete(p))
-// }
-
-// fn parse_suffixed_expr(p: &mut LuaParser) -> ParseResult {
-//     let mut cm = match p.current_token() {
-//         CppTokenKind::TkName => parse_name_or_special_function(p)?,
-//         CppTokenKind::TkLeftParen => {
-//             let m = p.mark(CppSyntaxKind::ParenExpr);
-//             p.bump();
-//             parse_expr(p)?;
-//             expect_token(p, CppTokenKind::TkRightParen)?;
-//             m.complete(p)
-//         }
-//         _ => {
-//             return Err(LuaParseError::syntax_error_from(
-//                 &t!("expect primary expression"),
-//                 p.current_token_range(),
-//             ))
-//         }
-//     };
-
-//     loop {
-//         match p.current_token() {
-//             CppTokenKind::TkDot | CppTokenKind::TkColon | CppTokenKind::TkLeftBracket => {
-//                 let m = cm.precede(p, CppSyntaxKind::IndexExpr);
-//                 parse_index_struct(p)?;
-//                 cm = m.complete(p);
-//             }
-//             CppTokenKind::TkLeftParen
-//             | CppTokenKind::TkLongString
-//             | CppTokenKind::TkString
-//             | CppTokenKind::TkLeftBrace => {
-//                 let m = cm.precede(p, CppSyntaxKind::CallExpr);
-//                 parse_args(p)?;
-//                 cm = m.complete(p);
-//             }
-//             _ => {
-//                 return Ok(cm);
-//             }
-//         }
-//     }
-// }
-
-// fn parse_name_or_special_function(p: &mut LuaParser) -> ParseResult {
-//     let m = p.mark(CppSyntaxKind::NameExpr);
-//     let special_kind = match p.parse_config.get_special_function(p.current_token_text()) {
-//         SpecialFunction::Require => CppSyntaxKind::RequireCallExpr,
-//         SpecialFunction::Assert => CppSyntaxKind::AssertCallExpr,
-//         SpecialFunction::Error => CppSyntaxKind::ErrorCallExpr,
-//         SpecialFunction::Type => CppSyntaxKind::TypeCallExpr,
-//         SpecialFunction::Setmatable => CppSyntaxKind::SetmetatableCallExpr,
-//         _ => CppSyntaxKind::None,
-//     };
-//     p.bump();
-//     let mut cm = m.complete(p);
-//     if special_kind == CppSyntaxKind::None {
-//         return Ok(cm);
-//     }
-
-//     if matches!(
-//         p.current_token(),
-//         CppTokenKind::TkLeftParen
-//             | CppTokenKind::TkLongString
-//             | CppTokenKind::TkString
-//             | CppTokenKind::TkLeftBrace
-//     ) {
-//         let m1 = cm.precede(p, special_kind);
-//         parse_args(p)?;
-//         cm = m1.complete(p);
-//     }
-
-//     Ok(cm)
-// }
-
-// fn parse_index_struct(p: &mut LuaParser) -> Result<(), LuaParseError> {
-//     match p.current_token() {
-//         CppTokenKind::TkLeftBracket => {
-//             p.bump();
-//             parse_expr(p)?;
-//             expect_token(p, CppTokenKind::TkRightBracket)?;
-//         }
-//         CppTokenKind::TkDot => {
-//             p.bump();
-//             expect_token(p, CppTokenKind::TkName)?;
-//         }
-//         CppTokenKind::TkColon => {
-//             p.bump();
-//             expect_token(p, CppTokenKind::TkName)?;
-//             if !matches!(
-//                 p.current_token(),
-//                 CppTokenKind::TkLeftParen
-//                     | CppTokenKind::TkLeftBrace
-//                     | CppTokenKind::TkString
-//                     | CppTokenKind::TkLongString
-//             ) {
-//                 return Err(LuaParseError::syntax_error_from(
-//                     &t!("colon accessor must be followed by a function call or table constructor or string literal"),
-//                     p.current_token_range(),
-//                 ));
-//             }
-//         }
-//         _ => {
-//             return Err(LuaParseError::syntax_error_from(
-//                 &t!("expect index struct"),
-//                 p.current_token_range(),
-//             ));
-//         }
-//     }
-
-//     Ok(())
-// }
-
-// fn parse_args(p: &mut LuaParser) -> ParseResult {
-//     let m = p.mark(CppSyntaxKind::CallArgList);
-//     match p.current_token() {
-//         CppTokenKind::TkLeftParen => {
-//             p.bump();
-//             if p.current_token() != CppTokenKind::TkRightParen {
-//                 parse_expr(p)?;
-//                 while p.current_token() == CppTokenKind::TkComma {
-//                     p.bump();
-//                     if p.current_token() == CppTokenKind::TkRightParen {
-//                         p.push_error(LuaParseError::syntax_error_from(
-//                             &t!("expect expression"),
-//                             p.current_token_range(),
-//                         ));
-//                         break;
-//                     }
-//                     parse_expr(p)?;
-//                 }
-//             }
-//             expect_token(p, CppTokenKind::TkRightParen)?;
-//         }
-//         CppTokenKind::TkLeftBrace => {
-//             parse_table_expr(p)?;
-//         }
-//         CppTokenKind::TkString | CppTokenKind::TkLongString => {
-//             let m1 = p.mark(CppSyntaxKind::LiteralExpr);
-//             p.bump();
-//             m1.complete(p);
-//         }
-//         _ => {
-//             return Err(LuaParseError::syntax_error_from(
-//                 &t!("expect args"),
-//                 p.current_token_range(),
-//             ));
-//         }
-//     }
-
-//     Ok(m.complete(p))
-// }
+    parse_sub_expr(p, limit_including(BinaryOperator::Comma))
+}
+
+/// Parse an assignment-expression, used where a comma separates items (arguments, initializers)
+pub fn parse_assign_expr(p: &mut CppParser) -> ParseResult {
+    parse_sub_expr(p, limit_including(BinaryOperator::Assign))
+}
+
+/// `parse_sub_expr` only consumes binary operators that bind tighter than its limit,
+/// this returns the limit that still accepts `op`
+fn limit_including(op: BinaryOperator) -> u8 {
+    op.get_precedence().map_or(0, |it| it.precedence + 1)
+}
+
+fn parse_sub_expr(p: &mut CppParser, limit: u8) -> ParseResult {
+    let mut cm = parse_unary_expr(p)?;
+
+    loop {
+        // Inside `<...>` a `>` closes the template argument list
+        if p.is_in_template_args()
+            && matches!(
+                p.current_token(),
+                CppTokenKind::Greater
+                    | CppTokenKind::RightShift
+                    | CppTokenKind::GreaterEqual
+                    | CppTokenKind::RightShiftAssign
+            )
+        {
+            break;
+        }
+
+        let bop = CppOpKind::to_binary_operator(p.current_token());
+        let Some(precedence) = bop.get_precedence() else {
+            break;
+        };
+        if precedence.precedence >= limit {
+            break;
+        }
+
+        let range = p.current_token_range();
+        if bop == BinaryOperator::Conditional {
+            let m = cm.precede(p, CppSyntaxKind::TernaryExpr);
+            p.bump(); // Consume '?'
+            parse_expr(p)?;
+            expect_token(p, CppTokenKind::Colon)?;
+            parse_assign_expr(p)?;
+            cm = m.complete(p);
+            continue;
+        }
+
+        if bop == BinaryOperator::Spaceship {
+            p.check_feature(
+                CppFeature::ThreeWayComparison,
+                &t!("three-way comparison requires C++20"),
+                range,
+            );
+        }
+        let m = cm.precede(p, CppSyntaxKind::BinaryExpr);
+        p.bump();
+        // Fold expression: (args + ...)
+        if p.current_token() == CppTokenKind::Ellipsis {
+            p.bump();
+        } else {
+            let right_limit = if precedence.right_associative {
+                precedence.precedence + 1
+            } else {
+                precedence.precedence
+            };
+            match parse_sub_expr(p, right_limit) {
+                Ok(_) => {}
+                Err(err) => {
+                    p.push_error(CppParseError::syntax_error_from(
+                        &t!("binary operator not followed by expression"),
+                        range,
+                    ));
+
+                    return Err(err);
+                }
+            }
+        }
+
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+fn parse_unary_expr(p: &mut CppParser) -> ParseResult {
+    match p.current_token() {
+        CppTokenKind::SizeofKeyword => parse_sizeof_expr(p),
+        CppTokenKind::AlignofKeyword => parse_alignof_expr(p),
+        CppTokenKind::NoexceptKeyword => parse_noexcept_expr(p),
+        CppTokenKind::TypeidKeyword => parse_typeid_expr(p),
+        CppTokenKind::ThrowKeyword => parse_throw_expr(p),
+        CppTokenKind::CoAwaitKeyword => parse_co_await_expr(p),
+        CppTokenKind::CoYieldKeyword => parse_co_yield_expr(p),
+        CppTokenKind::NewKeyword => parse_new_expr(p),
+        CppTokenKind::DeleteKeyword => parse_delete_expr(p),
+        CppTokenKind::Scope if p.peek_next_token() == CppTokenKind::NewKeyword => parse_new_expr(p),
+        CppTokenKind::Scope if p.peek_next_token() == CppTokenKind::DeleteKeyword => {
+            parse_delete_expr(p)
+        }
+        CppTokenKind::LeftParen if is_c_style_cast(p) => parse_cast_expr(p),
+        kind if CppOpKind::to_unary_operator(kind) != UnaryOperator::Nop => {
+            let m = p.mark(CppSyntaxKind::UnaryExpr);
+            let range = p.current_token_range();
+            p.bump();
+            match parse_sub_expr(p, UNARY_PRECEDENCE) {
+                Ok(_) => {}
+                Err(err) => {
+                    p.push_error(CppParseError::syntax_error_from(
+                        &t!("unary operator not followed by expression"),
+                        range,
+                    ));
+                    return Err(err);
+                }
+            }
+            Ok(m.complete(p))
+        }
+        _ => parse_postfix_expr(p),
+    }
+}
+
+/// Parse `sizeof expr`, `sizeof(type)` or `sizeof...(pack)`
+fn parse_sizeof_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::SizeofExpr);
+    p.bump(); // Consume 'sizeof'
+
+    if if_token_bump(p, CppTokenKind::Ellipsis) {
+        expect_token(p, CppTokenKind::LeftParen)?;
+        expect_token(p, CppTokenKind::Identifier)?;
+        expect_token(p, CppTokenKind::RightParen)?;
+    } else if is_parenthesized_type(p) {
+        p.bump(); // Consume '('
+        parse_type_id(p)?;
+        expect_token(p, CppTokenKind::RightParen)?;
+    } else {
+        parse_sub_expr(p, UNARY_PRECEDENCE)?;
+    }
+
+    Ok(m.complete(p))
+}
+
+/// Parse `alignof(type)`
+fn parse_alignof_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::AlignofExpr);
+    p.bump(); // Consume 'alignof'
+    expect_token(p, CppTokenKind::LeftParen)?;
+    parse_type_id(p)?;
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `noexcept(expr)`
+fn parse_noexcept_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::NoexceptExpr);
+    p.bump(); // Consume 'noexcept'
+    expect_token(p, CppTokenKind::LeftParen)?;
+    parse_paren_content(p, parse_expr)?;
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `typeid(type)` or `typeid(expr)`
+fn parse_typeid_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::TypeidExpr);
+    p.bump(); // Consume 'typeid'
+    if is_parenthesized_type(p) {
+        p.bump(); // Consume '('
+        parse_type_id(p)?;
+    } else {
+        expect_token(p, CppTokenKind::LeftParen)?;
+        parse_paren_content(p, parse_expr)?;
+    }
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `throw` or `throw expr`
+fn parse_throw_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ThrowExpr);
+    p.bump(); // Consume 'throw'
+    if !matches!(
+        p.current_token(),
+        CppTokenKind::Semicolon
+            | CppTokenKind::RightParen
+            | CppTokenKind::RightBracket
+            | CppTokenKind::RightBrace
+            | CppTokenKind::Comma
+            | CppTokenKind::Colon
+            | CppTokenKind::Eof
+    ) {
+        parse_assign_expr(p)?;
+    }
+    Ok(m.complete(p))
+}
+
+/// Parse `co_await expr`, it binds like the other unary operators
+fn parse_co_await_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::CoAwaitExpr);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Coroutines, &t!("coroutines require C++20"), range);
+    p.bump(); // Consume 'co_await'
+    parse_sub_expr(p, UNARY_PRECEDENCE)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `co_yield expr` or `co_yield {args}`, it takes an assignment-expression like `throw`
+fn parse_co_yield_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::CoYieldExpr);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Coroutines, &t!("coroutines require C++20"), range);
+    p.bump(); // Consume 'co_yield'
+    parse_initializer_clause(p)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `::new (placement) T[n] (args)` or `new (T) {args}`
+fn parse_new_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::NewExpr);
+    if_token_bump(p, CppTokenKind::Scope);
+    p.bump(); // Consume 'new'
+
+    // `new (buf) T` has placement arguments, `new (T)` a parenthesized type
+    if p.current_token() == CppTokenKind::LeftParen
+        && scan_balanced_parens(p, 0).is_some_and(|end| {
+            matches!(
+                p.peek_token_at(end),
+                CppTokenKind::Identifier | CppTokenKind::Scope | CppTokenKind::LeftParen
+            ) || is_type_start(p.peek_token_at(end))
+        })
+    {
+        parse_argument_list(p)?;
+    }
+
+    if is_parenthesized_type(p) {
+        p.bump(); // Consume '('
+        parse_type(p)?;
+        expect_token(p, CppTokenKind::RightParen)?;
+    } else {
+        let mut cm = parse_type(p)?;
+        while p.current_token() == CppTokenKind::LeftBracket {
+            let array_m = cm.precede(p, CppSyntaxKind::ArrayType);
+            p.bump(); // Consume '['
+            if p.current_token() != CppTokenKind::RightBracket {
+                parse_paren_content(p, parse_expr)?;
+            }
+            expect_token(p, CppTokenKind::RightBracket)?;
+            cm = array_m.complete(p);
+        }
+    }
+
+    match p.current_token() {
+        CppTokenKind::LeftParen => {
+            parse_argument_list(p)?;
+        }
+        CppTokenKind::LeftBrace => {
+            parse_init_list_expr(p)?;
+        }
+        _ => {}
+    }
+
+    Ok(m.complete(p))
+}
+
+/// Parse `delete p`, `delete[] p` or `::delete p`
+fn parse_delete_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::DeleteExpr);
+    if_token_bump(p, CppTokenKind::Scope);
+    p.bump(); // Consume 'delete'
+    if p.current_token() == CppTokenKind::LeftBracket
+        && p.peek_next_token() == CppTokenKind::RightBracket
+    {
+        p.bump();
+        p.bump();
+    }
+    parse_sub_expr(p, UNARY_PRECEDENCE)?;
+    Ok(m.complete(p))
+}
+
+/// Parse a C-style cast: `(int)x`, `(const Foo*)ptr`, `(void (*)(int))fn`
+fn parse_cast_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::CastExpr);
+    p.bump(); // Consume '('
+    parse_type_id(p)?;
+    expect_token(p, CppTokenKind::RightParen)?;
+    parse_sub_expr(p, UNARY_PRECEDENCE)?;
+    Ok(m.complete(p))
+}
+
+fn parse_postfix_expr(p: &mut CppParser) -> ParseResult {
+    let mut cm = parse_primary_expr(p)?;
+
+    loop {
+        match p.current_token() {
+            CppTokenKind::LeftParen => {
+                let m = cm.precede(p, CppSyntaxKind::CallExpr);
+                parse_argument_list(p)?;
+                cm = m.complete(p);
+            }
+            CppTokenKind::LeftBracket => {
+                let m = cm.precede(p, CppSyntaxKind::IndexExpr);
+                p.bump(); // Consume '['
+                if p.current_token() == CppTokenKind::LeftBrace {
+                    parse_init_list_expr(p)?;
+                } else {
+                    parse_paren_content(p, parse_expr)?;
+                }
+                expect_token(p, CppTokenKind::RightBracket)?;
+                cm = m.complete(p);
+            }
+            CppTokenKind::Dot | CppTokenKind::Arrow => {
+                let kind = if p.current_token() == CppTokenKind::Dot {
+                    CppSyntaxKind::MemberExpr
+                } else {
+                    CppSyntaxKind::ArrowExpr
+                };
+                let m = cm.precede(p, kind);
+                p.bump(); // Consume '.' or '->'
+                parse_name(p, false)?;
+                cm = m.complete(p);
+            }
+            kind if CppOpKind::to_postfix_operator(kind) != UnaryOperator::Nop => {
+                let m = cm.precede(p, CppSyntaxKind::UnaryExpr);
+                p.bump();
+                cm = m.complete(p);
+            }
+            _ => return Ok(cm),
+        }
+    }
+}
+
+fn parse_primary_expr(p: &mut CppParser) -> ParseResult {
+    match p.current_token() {
+        CppTokenKind::IntegerLiteral
+        | CppTokenKind::FloatingLiteral
+        | CppTokenKind::CharLiteral
+        | CppTokenKind::UserDefinedLiteral
+        | CppTokenKind::TrueKeyword
+        | CppTokenKind::FalseKeyword
+        | CppTokenKind::NullptrKeyword => {
+            let m = p.mark(CppSyntaxKind::LiteralExpr);
+            p.bump();
+            Ok(m.complete(p))
+        }
+        CppTokenKind::StringLiteral => {
+            // Adjacent string literals are concatenated: "a" "b"
+            let m = p.mark(CppSyntaxKind::LiteralExpr);
+            while matches!(
+                p.current_token(),
+                CppTokenKind::StringLiteral | CppTokenKind::UserDefinedLiteral
+            ) {
+                p.bump();
+            }
+            Ok(m.complete(p))
+        }
+        CppTokenKind::ThisKeyword => {
+            let m = p.mark(CppSyntaxKind::ThisExpr);
+            p.bump();
+            Ok(m.complete(p))
+        }
+        CppTokenKind::LeftParen => parse_paren_expr(p),
+        CppTokenKind::LeftBrace => parse_init_list_expr(p),
+        CppTokenKind::StaticCastKeyword
+        | CppTokenKind::DynamicCastKeyword
+        | CppTokenKind::ReinterpretCastKeyword
+        | CppTokenKind::ConstCastKeyword => parse_named_cast_expr(p),
+        CppTokenKind::LeftBracket => parse_paren_content(p, parse_lambda_expr),
+        CppTokenKind::RequiresKeyword => parse_paren_content(p, parse_requires_expr),
+        CppTokenKind::Identifier
+        | CppTokenKind::Scope
+        | CppTokenKind::OperatorKeyword
+        | CppTokenKind::TemplateKeyword => {
+            let m = p.mark(CppSyntaxKind::IdentifierExpr);
+            parse_name(p, false)?;
+            let cm = m.complete(p);
+            // Functional cast with a braced initializer: Foo{1, 2}
+            if p.current_token() == CppTokenKind::LeftBrace {
+                let m = cm.precede(p, CppSyntaxKind::CallExpr);
+                parse_init_list_expr(p)?;
+                return Ok(m.complete(p));
+            }
+            Ok(cm)
+        }
+        // Functional cast: int(x), unsigned{0}, typename T::type(x)
+        kind if is_builtin_type_keyword(kind) || kind == CppTokenKind::TypenameKeyword => {
+            let m = p.mark(CppSyntaxKind::CastExpr);
+            parse_type_specifier(p)?;
+            match p.current_token() {
+                CppTokenKind::LeftParen => {
+                    parse_argument_list(p)?;
+                }
+                CppTokenKind::LeftBrace => {
+                    parse_init_list_expr(p)?;
+                }
+                _ => {
+                    return Err(CppParseError::syntax_error_from(
+                        &t!("expect ( or { after type in functional cast"),
+                        p.current_token_range(),
+                    ));
+                }
+            }
+            Ok(m.complete(p))
+        }
+        _ => Err(CppParseError::syntax_error_from(
+            &t!("expect primary expression"),
+            p.current_token_range(),
+        )),
+    }
+}
+
+/// Parse `static_cast<T>(expr)` and the other named casts
+fn parse_named_cast_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::CastExpr);
+    p.bump(); // Consume the cast keyword
+
+    expect_token(p, CppTokenKind::Less)?;
+    let in_template_args = p.set_in_template_args(true);
+    let result = parse_type_id(p);
+    p.set_in_template_args(in_template_args);
+    result?;
+    // static_cast<std::vector<int>>(x)
+    p.split_greater_token();
+    expect_token(p, CppTokenKind::Greater)?;
+
+    expect_token(p, CppTokenKind::LeftParen)?;
+    parse_paren_content(p, parse_expr)?;
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse a lambda expression:
+/// `[captures] <template params> requires C (params) specifiers noexcept [[attrs]] -> R { body }`
+fn parse_lambda_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::LambdaExpr);
+    parse_capture_list(p)?;
+
+    // Generic lambda (C++20): []<typename T> requires C<T> (T x) {}
+    if p.current_token() == CppTokenKind::Less {
+        parse_template_parameter_list(p)?;
+        if is_requires_clause_start(p) {
+            parse_requires_clause(p)?;
+        }
+    }
+
+    // Attributes of the call operator (C++23): [] [[nodiscard]] (int x) {}
+    parse_attribute_lists(p)?;
+
+    if p.current_token() == CppTokenKind::LeftParen {
+        parse_parameter_list(p)?;
+    }
+
+    // Specifiers may also follow an omitted parameter list (C++23): [] mutable {}
+    while matches!(
+        p.current_token(),
+        CppTokenKind::MutableKeyword
+            | CppTokenKind::ConstexprKeyword
+            | CppTokenKind::ConstevalKeyword
+            | CppTokenKind::StaticKeyword
+    ) {
+        parse_decl_specifier(p);
+    }
+    parse_noexcept_specifier(p)?;
+
+    parse_attribute_lists(p)?;
+
+    // Trailing return type: [](int x) -> long { ... }
+    if p.current_token() == CppTokenKind::Arrow {
+        p.bump();
+        parse_type_id(p)?;
+    }
+
+    if is_requires_clause_start(p) {
+        parse_requires_clause(p)?;
+    }
+
+    if p.current_token() != CppTokenKind::LeftBrace {
+        return Err(CppParseError::syntax_error_from(
+            &t!("expect lambda body"),
+            p.current_token_range(),
+        ));
+    }
+    parse_compound_stat(p)?;
+
+    Ok(m.complete(p))
+}
+
+/// Parse lambda captures: `[=, &x, this, y = std::move(v)]`
+fn parse_capture_list(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::CaptureList);
+    p.bump(); // Consume '['
+
+    while p.current_token() != CppTokenKind::RightBracket && !p.is_eof() {
+        parse_capture(p)?;
+        if !if_token_bump(p, CppTokenKind::Comma) {
+            break;
+        }
+    }
+
+    expect_token(p, CppTokenKind::RightBracket)?;
+    Ok(m.complete(p))
+}
+
+/// Parse a single capture: `=`, `&`, `this`, `*this`, `x`, `&x`, `args...`,
+/// or an init-capture `x = init`, `&x{init}`, `...xs = std::move(args)`
+fn parse_capture(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::Capture);
+    match p.current_token() {
+        // Capture defaults: [=] and [&]
+        CppTokenKind::Assign => p.bump(),
+        CppTokenKind::Ampersand
+            if matches!(
+                p.peek_next_token(),
+                CppTokenKind::Comma | CppTokenKind::RightBracket
+            ) =>
+        {
+            p.bump()
+        }
+        CppTokenKind::ThisKeyword => p.bump(),
+        CppTokenKind::Star => {
+            p.bump(); // Consume '*'
+            expect_token(p, CppTokenKind::ThisKeyword)?;
+        }
+        _ => {
+            if_token_bump(p, CppTokenKind::Ampersand);
+            let is_init_pack = if_token_bump(p, CppTokenKind::Ellipsis);
+            expect_token(p, CppTokenKind::Identifier)?;
+            match p.current_token() {
+                CppTokenKind::Assign => {
+                    p.bump();
+                    parse_initializer_clause(p)?;
+                }
+                CppTokenKind::LeftParen => {
+                    parse_argument_list(p)?;
+                }
+                CppTokenKind::LeftBrace => {
+                    parse_init_list_expr(p)?;
+                }
+                CppTokenKind::Ellipsis if !is_init_pack => p.bump(),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(m.complete(p))
+}
+
+/// The current `requires` starts a requires clause
+pub fn is_requires_clause_start(p: &CppParser) -> bool {
+    p.current_token() == CppTokenKind::RequiresKeyword
+}
+
+/// Parse `requires` followed by a constraint: `requires Integral<T> && (sizeof(T) > 4)`
+pub fn parse_requires_clause(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::RequiresClause);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Concepts, &t!("concepts require C++20"), range);
+    p.bump(); // Consume 'requires'
+    parse_constraint_expr(p, CppTokenKind::LogicalOr)?;
+    Ok(m.complete(p))
+}
+
+/// A constraint only joins primary expressions with `||` and `&&`, `&&` binding tighter
+fn parse_constraint_expr(p: &mut CppParser, op: CppTokenKind) -> ParseResult {
+    let parse_operand = |p: &mut CppParser| {
+        if op == CppTokenKind::LogicalOr {
+            parse_constraint_expr(p, CppTokenKind::LogicalAnd)
+        } else {
+            parse_constraint_primary(p)
+        }
+    };
+
+    let mut cm = parse_operand(p)?;
+    while p.current_token() == op {
+        let m = cm.precede(p, CppSyntaxKind::BinaryExpr);
+        p.bump();
+        parse_operand(p)?;
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+/// A `<` after a name in a constraint always opens the template arguments of a concept-id,
+/// the declaration after it may start with anything: `requires C<T> void f();`
+fn parse_constraint_primary(p: &mut CppParser) -> ParseResult {
+    match p.current_token() {
+        CppTokenKind::Identifier | CppTokenKind::Scope => {
+            let m = p.mark(CppSyntaxKind::IdentifierExpr);
+            parse_name(p, true)?;
+            Ok(m.complete(p))
+        }
+        _ => parse_primary_expr(p),
+    }
+}
+
+/// Parse a requires expression: `requires (T a, T b) { a + b; typename T::type; }`
+fn parse_requires_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::RequiresExpr);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Concepts, &t!("concepts require C++20"), range);
+    p.bump(); // Consume 'requires'
+
+    if p.current_token() == CppTokenKind::LeftParen {
+        parse_parameter_list(p)?;
+    }
+
+    expect_token(p, CppTokenKind::LeftBrace)?;
+    while p.current_token() != CppTokenKind::RightBrace && !p.is_eof() {
+        parse_requirement(p)?;
+    }
+    expect_token(p, CppTokenKind::RightBrace)?;
+
+    Ok(m.complete(p))
+}
+
+/// Parse a single requirement of a requires expression, each one ends with `;`
+fn parse_requirement(p: &mut CppParser) -> ParseResult {
+    let m = match p.current_token() {
+        // { expr } noexcept -> std::same_as<T>;
+        CppTokenKind::LeftBrace => {
+            let m = p.mark(CppSyntaxKind::CompoundRequirement);
+            p.bump(); // Consume '{'
+            parse_expr(p)?;
+            expect_token(p, CppTokenKind::RightBrace)?;
+            if_token_bump(p, CppTokenKind::NoexceptKeyword);
+            if if_token_bump(p, CppTokenKind::Arrow) {
+                parse_type_specifier(p)?;
+            }
+            m
+        }
+        // typename T::type;
+        CppTokenKind::TypenameKeyword => {
+            let m = p.mark(CppSyntaxKind::TypeRequirement);
+            parse_type_specifier(p)?;
+            m
+        }
+        // requires Copyable<T>;
+        CppTokenKind::RequiresKeyword => {
+            let m = p.mark(CppSyntaxKind::NestedRequirement);
+            p.bump(); // Consume 'requires'
+            parse_expr(p)?;
+            m
+        }
+        _ => {
+            let m = p.mark(CppSyntaxKind::SimpleRequirement);
+            parse_expr(p)?;
+            m
+        }
+    };
+
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `(expr)`, including the fold expressions `(... op pack)` and `(pack op ...)`
+fn parse_paren_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ParenExpr);
+    p.bump(); // Consume '('
+
+    if p.current_token() == CppTokenKind::Ellipsis {
+        let fold_m = p.mark(CppSyntaxKind::BinaryExpr);
+        p.bump(); // Consume '...'
+        let Some(precedence) = CppOpKind::to_binary_operator(p.current_token()).get_precedence()
+        else {
+            return Err(CppParseError::syntax_error_from(
+                &t!("expect fold operator"),
+                p.current_token_range(),
+            ));
+        };
+        p.bump();
+        parse_paren_content(p, |p| parse_sub_expr(p, precedence.precedence))?;
+        fold_m.complete(p);
+    } else {
+        parse_paren_content(p, parse_expr)?;
+    }
+
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `(arg, arg...)`, each argument is an initializer clause
+pub fn parse_argument_list(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ArgumentList);
+    expect_token(p, CppTokenKind::LeftParen)?;
+
+    if p.current_token() != CppTokenKind::RightParen {
+        parse_paren_content(p, |p| {
+            parse_initializer_clauses(p, CppTokenKind::RightParen)
+        })?;
+    }
+
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse a braced initializer list: `{1, 2}`, `{.x = 1, .y = {2}}`
+pub fn parse_init_list_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::InitListExpr);
+    expect_token(p, CppTokenKind::LeftBrace)?;
+
+    if p.current_token() != CppTokenKind::RightBrace {
+        parse_paren_content(p, |p| {
+            parse_initializer_clauses(p, CppTokenKind::RightBrace)
+        })?;
+    }
+
+    expect_token(p, CppTokenKind::RightBrace)?;
+    Ok(m.complete(p))
+}
+
+fn parse_initializer_clauses(p: &mut CppParser, close: CppTokenKind) -> Result<(), CppParseError> {
+    loop {
+        if p.current_token() == CppTokenKind::Dot && p.peek_next_token() == CppTokenKind::Identifier
+        {
+            parse_designated_init_expr(p)?;
+        } else {
+            parse_initializer_clause(p)?;
+        }
+
+        // Pack expansion: f(args...)
+        if_token_bump(p, CppTokenKind::Ellipsis);
+
+        if p.current_token() != CppTokenKind::Comma {
+            break;
+        }
+        p.bump(); // Consume ','
+
+        // A trailing comma is allowed in a braced initializer list
+        if p.current_token() == close && close == CppTokenKind::RightBrace {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse an initializer clause: a braced initializer list or an assignment-expression
+pub fn parse_initializer_clause(p: &mut CppParser) -> ParseResult {
+    if p.current_token() == CppTokenKind::LeftBrace {
+        parse_init_list_expr(p)
+    } else {
+        parse_assign_expr(p)
+    }
+}
+
+/// Parse `.x = value` or `.x{value}`
+fn parse_designated_init_expr(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::DesignatedInitExpr);
+    let range = p.current_token_range();
+    p.check_feature(
+        CppFeature::DesignatedInitializers,
+        &t!("designated initializers require C++20"),
+        range,
+    );
+    p.bump(); // Consume '.'
+    p.bump(); // Consume the field name
+    if if_token_bump(p, CppTokenKind::Assign) {
+        parse_initializer_clause(p)?;
+    } else {
+        parse_init_list_expr(p)?;
+    }
+    Ok(m.complete(p))
+}
+
+/// Brackets nested inside a template argument list reset the `>` handling
+fn parse_paren_content<T, F>(p: &mut CppParser, f: F) -> Result<T, CppParseError>
+where
+    F: FnOnce(&mut CppParser) -> Result<T, CppParseError>,
+{
+    let in_template_args = p.set_in_template_args(false);
+    let result = f(p);
+    p.set_in_template_args(in_template_args);
+    result
+}
+
+/// The current `(` starts a parenthesized type-id: `(int)`, `(const Foo&)`
+fn is_parenthesized_type(p: &CppParser) -> bool {
+    if p.current_token() != CppTokenKind::LeftParen {
+        return false;
+    }
+
+    if is_type_start(p.peek_token_at(1)) {
+        return true;
+    }
+
+    scan_type_id(p, 1).is_some_and(|end| {
+        p.peek_token_at(scan_abstract_declarator(p, end)) == CppTokenKind::RightParen
+    })
+}
+
+/// `(T)x` and `(a)-b` look the same to the parser, a parenthesized name is only
+/// treated as a cast when it cannot be a primary expression followed by an operator
+fn is_c_style_cast(p: &CppParser) -> bool {
+    let Some(type_end) = scan_type_id(p, 1) else {
+        return false;
+    };
+    let end = scan_abstract_declarator(p, type_end);
+    if p.peek_token_at(end) != CppTokenKind::RightParen {
+        return false;
+    }
+
+    // Builtin types and declarators can only appear in a type
+    if end != type_end
+        || is_type_start(p.peek_token_at(1))
+        || matches!(
+            p.peek_token_at(end - 1),
+            CppTokenKind::Star
+                | CppTokenKind::Ampersand
+                | CppTokenKind::LogicalAnd
+                | CppTokenKind::ConstKeyword
+                | CppTokenKind::VolatileKeyword
+        )
+    {
+        return true;
+    }
+
+    matches!(
+        p.peek_token_at(end + 1),
+        CppTokenKind::Identifier
+            | CppTokenKind::IntegerLiteral
+            | CppTokenKind::FloatingLiteral
+            | CppTokenKind::CharLiteral
+            | CppTokenKind::StringLiteral
+            | CppTokenKind::UserDefinedLiteral
+            | CppTokenKind::TrueKeyword
+            | CppTokenKind::FalseKeyword
+            | CppTokenKind::NullptrKeyword
+            | CppTokenKind::ThisKeyword
+            | CppTokenKind::LogicalNot
+            | CppTokenKind::Tilde
+            | CppTokenKind::SizeofKeyword
+            | CppTokenKind::NewKeyword
+    )
+}
@@ -1,6 +1,7 @@
 mod exprs;
 mod stats;
 mod test;
+mod types;
 
 use stats::{parse_stat, parse_stats};
 
@@ -33,6 +34,7 @@ pub fn parse_cpp_unit(p: &mut CppParser) {
         }
     }
 
+    p.flush_trivia();
     m.complete(p);
 }
 
@@ -9,6 +9,8 @@
  *    - do-while loops  
  *    - for loops (including C-style and range-based)
  *    - switch/case/default statements
+ *    - return/break/continue/goto and labels
+ *    - co_return (C++20)
  * 
  * 2. Declaration Statements:
  *    - Class declarations and definitions
@@ -17,6 +19,8 @@
  *    - Namespace declarations
  *    - Function declarations with full C++ syntax support
  *    - Field declarations
+ *    - Variable and typedef declarations, told apart from expression statements
+ *      by tentative parsing
  * 
  * 3. Class/Struct Features:
  *    - Inheritance with access specifiers (public, private, protected)
@@ -29,7 +33,12 @@
  * 
  * 4. Advanced C++ Features:
  *    - Parameter lists with default values
+ *    - Declarators with pointers, references, arrays, function pointers
+ *      and pointers to members
  *    - Template argument lists
+ *    - Template declarations, explicit specializations and explicit instantiations
+ *    - Concept definitions and requires clauses (C++20)
+ *    - Module declarations, module fragments, imports and exports (C++20)
  *    - Scoped enums (enum class)
  *    - Forward declarations
  * 
@@ -39,12 +48,24 @@
 
 use crate::{
     grammar::ParseResult,
-    kind::{self, CppSyntaxKind, CppTokenKind},
-    parser::{CppParser, MarkerEventContainer},
+    kind::{CppFeature, CppSyntaxKind, CppTokenKind},
+    parser::{CompleteMarker, CppParser, Marker, MarkerEventContainer},
     parser_error::CppParseError,
 };
 
-use super::{expect_token, exprs::parse_expr, parse_compound_stat};
+use super::{
+    expect_token,
+    exprs::{
+        is_requires_clause_start, parse_argument_list, parse_assign_expr, parse_expr,
+        parse_init_list_expr, parse_initializer_clause, parse_requires_clause,
+    },
+    if_token_bump, parse_compound_stat,
+    types::{
+        is_parameter_list_start, parse_declarator, parse_name, parse_type, parse_type_id,
+        parse_type_specifier, scan_abstract_declarator, scan_balanced_parens, scan_name,
+        scan_type_id, DeclaratorMode,
+    },
+};
 
 pub fn parse_stats(p: &mut CppParser) {
     while !block_follow(p) {
@@ -89,6 +110,8 @@ fn block_follow(p: &CppParser) -> bool {
 
 pub fn parse_stat(p: &mut CppParser) -> ParseResult {
     let cm = match p.current_token() {
+        // Attributes of the declaration or statement: [[nodiscard]] int f();
+        _ if is_attribute_list_start(p) => parse_attributed(p, parse_stat)?,
         // Control flow statements
         CppTokenKind::IfKeyword => parse_if_statement(p)?,
         CppTokenKind::WhileKeyword => parse_while_statement(p)?,
@@ -102,7 +125,40 @@ pub fn parse_stat(p: &mut CppParser) -> ParseResult {
         CppTokenKind::StructKeyword => parse_struct_declaration(p)?,
         CppTokenKind::EnumKeyword => parse_enum_declaration(p)?,
         CppTokenKind::NamespaceKeyword => parse_namespace_declaration(p)?,
-        // CppTokenKind::UsingKeyword => parse_using_declaration(p)?,
+        CppTokenKind::InlineKeyword if p.peek_next_token() == CppTokenKind::NamespaceKeyword => {
+            parse_namespace_declaration(p)?
+        }
+        CppTokenKind::UsingKeyword => parse_using_declaration(p)?,
+        CppTokenKind::TemplateKeyword => parse_template_declaration(p, parse_stat)?,
+        CppTokenKind::ExternKeyword if p.peek_next_token() == CppTokenKind::TemplateKeyword => {
+            parse_template_declaration(p, parse_stat)?
+        }
+        CppTokenKind::ConceptKeyword => parse_concept_declaration(p)?,
+        CppTokenKind::AsmKeyword => parse_asm_declaration(p)?,
+        // Jump statements
+        CppTokenKind::ReturnKeyword => parse_return_statement(p)?,
+        CppTokenKind::CoReturnKeyword => parse_co_return_statement(p)?,
+        CppTokenKind::BreakKeyword => parse_jump_statement(p, CppSyntaxKind::BreakStat)?,
+        CppTokenKind::ContinueKeyword => parse_jump_statement(p, CppSyntaxKind::ContinueStat)?,
+        CppTokenKind::GotoKeyword => parse_goto_statement(p)?,
+        CppTokenKind::Semicolon => {
+            let m = p.mark(CppSyntaxKind::EmptyStat);
+            p.bump();
+            m.complete(p)
+        }
+        // Module declarations, `module` and `import` are identifiers anywhere else
+        CppTokenKind::Identifier if p.is_at_line_start() && is_module_declaration_start(p) => {
+            let m = p.mark(CppSyntaxKind::ModuleDecl);
+            parse_module_declaration(p, m)?
+        }
+        CppTokenKind::Identifier if p.is_at_line_start() && is_import_declaration_start(p) => {
+            let m = p.mark(CppSyntaxKind::ImportDecl);
+            parse_import_declaration(p, m)?
+        }
+        CppTokenKind::ExportKeyword => parse_export_declaration(p)?,
+        CppTokenKind::Identifier if p.peek_next_token() == CppTokenKind::Colon => {
+            parse_label_statement(p)?
+        }
         // CppTokenKind::TypedefKeyword => parse_typedef_declaration(p)?,
         // CppTokenKind::ConstKeyword => parse_const_declaration(p)?,
         // CppTokenKind::StaticKeyword => parse_static_declaration(p)?,
@@ -121,9 +177,7 @@ fn parse_if_statement(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::IfStat);
 
     p.bump(); // Consume 'if'
-    expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
-    parse_expr(p)?; // Parse the condition expression
-    expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
+    parse_if_condition(p)?;
 
     parse_compound_stat(p)?; // Parse the 'then' block
 
@@ -132,9 +186,7 @@ fn parse_if_statement(p: &mut CppParser) -> ParseResult {
             let m_else_if = p.mark(CppSyntaxKind::ElseIfStat);
             p.bump();
             p.bump(); // Consume 'else if'
-            expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
-            parse_expr(p)?; // Parse the condition expression for 'else if'
-            expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
+            parse_if_condition(p)?;
 
             parse_compound_stat(p)?; // Parse the 'else if' block
             m_else_if.complete(p);
@@ -151,12 +203,77 @@ fn parse_if_statement(p: &mut CppParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+/// Parse what follows `if`: `(cond)`, `constexpr (init; cond)`, `consteval` or `!consteval`
+fn parse_if_condition(p: &mut CppParser) -> Result<(), CppParseError> {
+    // Consteval if (C++23) has no condition and its branches are blocks: if !consteval { }
+    if is_if_consteval_start(p) {
+        if_token_bump(p, CppTokenKind::LogicalNot);
+        let range = p.current_token_range();
+        p.check_feature(
+            CppFeature::IfConsteval,
+            &t!("if consteval requires C++23"),
+            range,
+        );
+        p.bump(); // Consume 'consteval'
+        if p.current_token() != CppTokenKind::LeftBrace {
+            return Err(CppParseError::syntax_error_from(
+                &t!("expected '{' after if consteval"),
+                p.current_token_range(),
+            ));
+        }
+        return Ok(());
+    }
+
+    if p.current_token() == CppTokenKind::ConstexprKeyword {
+        let range = p.current_token_range();
+        p.check_feature(
+            CppFeature::IfConstexpr,
+            &t!("if constexpr requires C++17"),
+            range,
+        );
+        p.bump(); // Consume 'constexpr'
+    }
+
+    expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
+    parse_init_statement(p)?;
+    parse_condition(p)?;
+    expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
+    Ok(())
+}
+
+/// Parse the condition of if, while, switch or for, an expression or a declaration with
+/// an initializer: `if (auto* d = dynamic_cast<D*>(b))`, `while (auto n = next())`
+fn parse_condition(p: &mut CppParser) -> Result<(), CppParseError> {
+    let state = p.save_state();
+    let m = p.mark(CppSyntaxKind::VariableDecl);
+    let is_declaration = parse_declaration_head(p).is_ok_and(|head| !head.is_typedef)
+        && matches!(
+            p.current_token(),
+            CppTokenKind::Assign | CppTokenKind::LeftBrace
+        );
+    if is_declaration {
+        parse_init_declarator_rest(p)?;
+        m.complete(p);
+        return Ok(());
+    }
+
+    p.restore_state(state);
+    parse_expr(p)?;
+    Ok(())
+}
+
+/// `consteval` or `!consteval` after `if`
+fn is_if_consteval_start(p: &CppParser) -> bool {
+    let start = usize::from(p.current_token() == CppTokenKind::LogicalNot);
+    p.peek_token_at(start) == CppTokenKind::ConstevalKeyword
+}
+
 fn parse_while_statement(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::WhileStat);
 
     p.bump(); // Consume 'while'
     expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
-    parse_expr(p)?; // Parse the condition expression
+    parse_condition(p)?;
     expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
 
     parse_compound_stat(p)?; // Parse the loop body
@@ -180,26 +297,40 @@ fn parse_do_while_statement(p: &mut CppParser) -> ParseResult {
 }
 
 fn parse_for_statement(p: &mut CppParser) -> ParseResult {
-    let m = p.mark(CppSyntaxKind::ForStat);
+    let mut m = p.mark(CppSyntaxKind::ForStat);
 
     p.bump(); // Consume 'for'
     expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
 
-    // Parse the initialization part
-    if p.current_token() != CppTokenKind::Semicolon {
+    // Range-based for: for (auto& item : container), for (auto v = make(); auto& item : v)
+    let init_end = scan_init_statement(p, 0);
+    if is_range_for(p, init_end.map_or(0, |end| end + 1)) {
+        m.set_kind(p, CppSyntaxKind::RangeForStat);
+        parse_init_statement(p)?;
+        let decl_m = p.mark(CppSyntaxKind::VariableDecl);
+        parse_declaration_head(p)?;
+        decl_m.complete(p);
+        expect_token(p, CppTokenKind::Colon)?; // Expect ':'
+        parse_initializer_clause(p)?; // Parse the range expression
+        expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
+        parse_compound_stat(p)?; // Parse the loop body
+        return Ok(m.complete(p));
+    }
+
+    // Parse the initialization part, the statement consumes its own ';'
+    if !if_token_bump(p, CppTokenKind::Semicolon) {
         parse_declaration_or_expression_statement(p)?;
     }
-    expect_token(p, CppTokenKind::Semicolon)?; // Expect ';'
 
     // Parse the condition part
     if p.current_token() != CppTokenKind::Semicolon {
-        parse_expr(p)?;
+        parse_condition(p)?;
     }
     expect_token(p, CppTokenKind::Semicolon)?; // Expect ';'
 
     // Parse the increment part
     if p.current_token() != CppTokenKind::RightParen {
-        parse_declaration_or_expression_statement(p)?;
+        parse_expr(p)?;
     }
     expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
 
@@ -208,12 +339,123 @@ fn parse_for_statement(p: &mut CppParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+/// A `:` outside of any brackets before the first `;` after lookahead offset `start` makes
+/// the for loop range-based
+fn is_range_for(p: &CppParser, start: usize) -> bool {
+    let mut depth = 0;
+    let mut index = start;
+    loop {
+        match p.peek_token_at(index) {
+            CppTokenKind::LeftParen | CppTokenKind::LeftBracket | CppTokenKind::LeftBrace => {
+                depth += 1
+            }
+            CppTokenKind::RightParen | CppTokenKind::RightBracket | CppTokenKind::RightBrace => {
+                if depth == 0 {
+                    return false;
+                }
+                depth -= 1;
+            }
+            CppTokenKind::Colon if depth == 0 => return true,
+            CppTokenKind::Question | CppTokenKind::Semicolon | CppTokenKind::Eof => return false,
+            _ => {}
+        }
+        index += 1;
+    }
+}
+
+/// Parse the init-statement in front of a condition if there is one:
+/// `if (auto it = m.find(k); it != m.end())`, `switch (auto x = f(); x)`
+fn parse_init_statement(p: &mut CppParser) -> Result<(), CppParseError> {
+    if scan_init_statement(p, 0).is_none() {
+        return Ok(());
+    }
+
+    let m = p.mark(CppSyntaxKind::InitStat);
+    // The statement consumes its own ';'
+    if !if_token_bump(p, CppTokenKind::Semicolon) {
+        parse_declaration_or_expression_statement(p)?;
+    }
+    m.complete(p);
+    Ok(())
+}
+
+/// Offset of the `;` that ends an init-statement at lookahead offset `start`, `None` when
+/// the parenthesized condition has no `;` outside of brackets
+fn scan_init_statement(p: &CppParser, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut index = start;
+    loop {
+        match p.peek_token_at(index) {
+            CppTokenKind::LeftParen | CppTokenKind::LeftBracket | CppTokenKind::LeftBrace => {
+                depth += 1
+            }
+            CppTokenKind::RightParen | CppTokenKind::RightBracket | CppTokenKind::RightBrace => {
+                if depth == 0 {
+                    return None;
+                }
+                depth -= 1;
+            }
+            CppTokenKind::Semicolon if depth == 0 => return Some(index),
+            CppTokenKind::Eof | CppTokenKind::None => return None,
+            _ => {}
+        }
+        index += 1;
+    }
+}
+
+fn parse_return_statement(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ReturnStat);
+    p.bump(); // Consume 'return'
+    if p.current_token() != CppTokenKind::Semicolon {
+        parse_initializer_clause(p)?;
+    }
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `co_return;`, `co_return expr;` or `co_return {args};`
+fn parse_co_return_statement(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::CoReturnStat);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Coroutines, &t!("coroutines require C++20"), range);
+    p.bump(); // Consume 'co_return'
+    if p.current_token() != CppTokenKind::Semicolon {
+        parse_initializer_clause(p)?;
+    }
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `break;` or `continue;`
+fn parse_jump_statement(p: &mut CppParser, kind: CppSyntaxKind) -> ParseResult {
+    let m = p.mark(kind);
+    p.bump();
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+fn parse_goto_statement(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::GotoStat);
+    p.bump(); // Consume 'goto'
+    expect_token(p, CppTokenKind::Identifier)?;
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+fn parse_label_statement(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::LabelStat);
+    p.bump(); // Consume label name
+    p.bump(); // Consume ':'
+    Ok(m.complete(p))
+}
+
 fn parse_switch_statement(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::SwitchStat);
 
     p.bump(); // Consume 'switch'
     expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
-    parse_expr(p)?; // Parse the switch expression
+    parse_init_statement(p)?;
+    parse_condition(p)?; // Parse the switch condition
     expect_token(p, CppTokenKind::RightParen)?; // Expect ')'
 
     expect_token(p, CppTokenKind::LeftBrace)?; // Expect '{'
@@ -242,12 +484,16 @@ fn parse_switch_statement(p: &mut CppParser) -> ParseResult {
 
 fn parse_class_declaration(p: &mut CppParser) -> ParseResult {
     let mut m = p.mark(CppSyntaxKind::ClassDecl);
-
+    
     p.bump(); // Consume 'class'
+    parse_attribute_lists(p)?;
     
-    // Parse class name (optional for anonymous classes)
-    if p.current_token() == CppTokenKind::Identifier {
-        p.bump(); // Consume class name
+    // Parse class name (optional for anonymous classes), specializations carry template arguments
+    if matches!(
+        p.current_token(),
+        CppTokenKind::Identifier | CppTokenKind::Scope
+    ) {
+        parse_name(p, true)?;
     }
     
     // Parse inheritance (optional)
@@ -283,10 +529,14 @@ fn parse_struct_declaration(p: &mut CppParser) -> ParseResult {
     let mut m = p.mark(CppSyntaxKind::StructDecl);
     
     p.bump(); // Consume 'struct'
+    parse_attribute_lists(p)?;
     
-    // Parse struct name (optional for anonymous structs)
-    if p.current_token() == CppTokenKind::Identifier {
-        p.bump(); // Consume struct name
+    // Parse struct name (optional for anonymous structs), specializations carry template arguments
+    if matches!(
+        p.current_token(),
+        CppTokenKind::Identifier | CppTokenKind::Scope
+    ) {
+        parse_name(p, true)?;
     }
     
     // Parse inheritance (optional)
@@ -329,6 +579,7 @@ fn parse_enum_declaration(p: &mut CppParser) -> ParseResult {
         m.set_kind(p, CppSyntaxKind::EnumClassDecl);
         p.bump();
     }
+    parse_attribute_lists(p)?;
     
     // Parse enum name (optional for anonymous enums)
     if p.current_token() == CppTokenKind::Identifier {
@@ -338,12 +589,7 @@ fn parse_enum_declaration(p: &mut CppParser) -> ParseResult {
     // Parse underlying type (optional): enum class Color : int
     if p.current_token() == CppTokenKind::Colon {
         p.bump(); // Consume ':'
-        // Parse the underlying type
-        while p.current_token() != CppTokenKind::LeftBrace 
-            && p.current_token() != CppTokenKind::Semicolon 
-            && !p.is_eof() {
-            p.bump();
-        }
+        parse_type(p)?; // Parse the underlying type
     }
     
     // Check if this is a forward declaration or full definition
@@ -376,56 +622,353 @@ fn parse_enum_declaration(p: &mut CppParser) -> ParseResult {
 }
 
 fn parse_namespace_declaration(p: &mut CppParser) -> ParseResult {
-    // Placeholder for namespace declaration
     let m = p.mark(CppSyntaxKind::NamespaceDecl);
+    if_token_bump(p, CppTokenKind::InlineKeyword);
     p.bump(); // Consume 'namespace'
-    // Here you would typically parse the namespace name and members
-    // For now, we just complete the marker
-    if p.current_token() != CppTokenKind::Semicolon {
-        parse_compound_stat(p)?; // Parse namespace body
+    parse_attribute_lists(p)?;
+
+    // Parse namespace name (optional for anonymous namespaces), nested names: a::inline b
+    if p.current_token() == CppTokenKind::Identifier {
+        p.bump();
+        while p.current_token() == CppTokenKind::Scope {
+            p.bump(); // Consume '::'
+            if_token_bump(p, CppTokenKind::InlineKeyword);
+            expect_token(p, CppTokenKind::Identifier)?;
+        }
+    }
+    parse_attribute_lists(p)?;
+
+    // Namespace alias: namespace fs = std::filesystem;
+    if p.current_token() == CppTokenKind::Assign {
+        p.bump();
+        parse_name(p, true)?;
+        expect_token(p, CppTokenKind::Semicolon)?;
+        return Ok(m.complete(p));
+    }
+
+    if p.current_token() != CppTokenKind::LeftBrace {
+        return Err(CppParseError::syntax_error_from(
+            &t!("expected '{' after namespace name"),
+            p.current_token_range(),
+        ));
+    }
+    parse_compound_stat(p)?; // Parse namespace body
+    Ok(m.complete(p))
+}
+
+/// Parse using declaration: using namespace std; using std::string; using T = int;
+fn parse_using_declaration(p: &mut CppParser) -> ParseResult {
+    let mut m = p.mark(CppSyntaxKind::UsingDecl);
+    p.bump(); // Consume 'using'
+
+    if p.current_token() == CppTokenKind::NamespaceKeyword {
+        m.set_kind(p, CppSyntaxKind::UsingDirective);
+        p.bump(); // Consume 'namespace'
+        parse_name(p, true)?;
+    } else if p.current_token() == CppTokenKind::Identifier
+        && p.peek_token_at(skip_attribute_lists(p, 1)) == CppTokenKind::Assign
+    {
+        // Alias declaration: using Alias = Type; using Alias [[deprecated]] = Type;
+        p.bump(); // Consume alias name
+        parse_attribute_lists(p)?;
+        p.bump(); // Consume '='
+        parse_type_id(p)?;
     } else {
-        p.bump(); // Consume ';' if present
+        if_token_bump(p, CppTokenKind::TypenameKeyword);
+        parse_name(p, true)?;
     }
+
+    expect_token(p, CppTokenKind::Semicolon)?;
     Ok(m.complete(p))
 }
 
-/// Parse inheritance list: : public Base1, private Base2, ...
-fn parse_inheritance_list(p: &mut CppParser) -> ParseResult {
-    let m = p.mark(CppSyntaxKind::BaseSpecifier);
-    
+/// Parse a template declaration `template <typename T> decl`, an explicit specialization
+/// `template <> decl` or an explicit instantiation `extern template decl`.
+/// The templated declaration is read by `parse_decl`, which depends on the enclosing scope.
+fn parse_template_declaration(
+    p: &mut CppParser,
+    parse_decl: fn(&mut CppParser) -> ParseResult,
+) -> ParseResult {
+    let mut m = p.mark(CppSyntaxKind::TemplateDecl);
+
+    let is_extern = if_token_bump(p, CppTokenKind::ExternKeyword);
+    p.bump(); // Consume 'template'
+
+    if !is_extern && p.current_token() == CppTokenKind::Less {
+        if p.peek_next_token() == CppTokenKind::Greater {
+            m.set_kind(p, CppSyntaxKind::TemplateSpecialization);
+        }
+        parse_template_parameter_list(p)?;
+        if is_requires_clause_start(p) {
+            parse_requires_clause(p)?;
+        }
+    } else {
+        m.set_kind(p, CppSyntaxKind::ExplicitInstantiation);
+    }
+
+    parse_decl(p)?;
+    Ok(m.complete(p))
+}
+
+/// Parse a concept definition after its template head: `concept Integral = std::is_integral_v<T>;`
+fn parse_concept_declaration(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ConceptDecl);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Concepts, &t!("concepts require C++20"), range);
+    p.bump(); // Consume 'concept'
+    expect_token(p, CppTokenKind::Identifier)?;
+    expect_token(p, CppTokenKind::Assign)?;
+    parse_expr(p)?;
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// `module;`, `module :private;` or `module a.b:part;`
+fn is_module_declaration_start(p: &CppParser) -> bool {
+    p.current_token() == CppTokenKind::Identifier
+        && p.current_token_text() == "module"
+        && matches!(
+            p.peek_next_token(),
+            CppTokenKind::Semicolon | CppTokenKind::Colon | CppTokenKind::Identifier
+        )
+}
+
+/// `import a.b;`, `import :part;` or `import <vector>;`
+fn is_import_declaration_start(p: &CppParser) -> bool {
+    p.current_token() == CppTokenKind::Identifier
+        && p.current_token_text() == "import"
+        && matches!(
+            p.peek_next_token(),
+            CppTokenKind::HeaderName | CppTokenKind::Colon | CppTokenKind::Identifier
+        )
+}
+
+/// Parse a module declaration or a module fragment: `module a.b:part;`, `module;` and
+/// `module :private;`. `m` may already hold an `export`.
+fn parse_module_declaration(p: &mut CppParser, mut m: Marker) -> ParseResult {
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Modules, &t!("modules require C++20"), range);
+    p.bump(); // Consume 'module'
+
+    match p.current_token() {
+        CppTokenKind::Semicolon => m.set_kind(p, CppSyntaxKind::GlobalModuleFragment),
+        CppTokenKind::Colon if p.peek_next_token() == CppTokenKind::PrivateKeyword => {
+            m.set_kind(p, CppSyntaxKind::PrivateModuleFragment);
+            p.bump(); // Consume ':'
+            p.bump(); // Consume 'private'
+        }
+        _ => {
+            m.set_kind(p, CppSyntaxKind::ModuleDecl);
+            parse_module_name(p)?;
+            if p.current_token() == CppTokenKind::Colon {
+                parse_module_partition(p)?;
+            }
+        }
+    }
+
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// Parse an import: `import a.b;`, `import :part;`, `import <vector>;` or `import "a.h";`.
+/// `m` may already hold an `export`.
+fn parse_import_declaration(p: &mut CppParser, mut m: Marker) -> ParseResult {
+    m.set_kind(p, CppSyntaxKind::ImportDecl);
+    let range = p.current_token_range();
+    p.check_feature(CppFeature::Modules, &t!("modules require C++20"), range);
+    p.bump(); // Consume 'import'
+
+    match p.current_token() {
+        CppTokenKind::HeaderName => p.bump(),
+        CppTokenKind::Colon => {
+            parse_module_partition(p)?;
+        }
+        _ => {
+            parse_module_name(p)?;
+        }
+    }
+
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// Parse an export: `export module a;`, `export import a;`, `export { ... }` or
+/// `export int f();`
+fn parse_export_declaration(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ExportDecl);
+    let range = p.current_token_range();
+    p.bump(); // Consume 'export'
+
+    if is_module_declaration_start(p) {
+        return parse_module_declaration(p, m);
+    } else if is_import_declaration_start(p) {
+        return parse_import_declaration(p, m);
+    }
+
+    p.check_feature(CppFeature::Modules, &t!("modules require C++20"), range);
+    if p.current_token() == CppTokenKind::LeftBrace {
+        parse_compound_stat(p)?;
+    } else {
+        parse_stat(p)?;
+    }
+    Ok(m.complete(p))
+}
+
+/// Parse a dotted module name: `a.b`
+fn parse_module_name(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ModuleName);
+    expect_dotted_name(p)?;
+    Ok(m.complete(p))
+}
+
+/// Parse a module partition: `:part`
+fn parse_module_partition(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ModulePartition);
     p.bump(); // Consume ':'
-    
-    // Parse base class list
-    loop {
-        // Parse access specifier (public, private, protected) - optional
-        if matches!(p.current_token(), 
-            CppTokenKind::PublicKeyword | 
-            CppTokenKind::PrivateKeyword | 
-            CppTokenKind::ProtectedKeyword
-        ) {
-            p.bump(); // Consume access specifier
+    expect_dotted_name(p)?;
+    Ok(m.complete(p))
+}
+
+fn expect_dotted_name(p: &mut CppParser) -> Result<(), CppParseError> {
+    expect_token(p, CppTokenKind::Identifier)?;
+    while p.current_token() == CppTokenKind::Dot {
+        p.bump(); // Consume '.'
+        expect_token(p, CppTokenKind::Identifier)?;
+    }
+    Ok(())
+}
+
+/// Parse an asm declaration: `asm("nop");`, including the GNU form
+/// `asm volatile("mov %1, %0" : "=r"(dst) : "r"(src));` whose operands are kept as tokens
+fn parse_asm_declaration(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::AsmDecl);
+    p.bump(); // Consume 'asm'
+    while matches!(
+        p.current_token(),
+        CppTokenKind::VolatileKeyword | CppTokenKind::InlineKeyword | CppTokenKind::GotoKeyword
+    ) {
+        p.bump();
+    }
+
+    let Some(end) = scan_balanced_parens(p, 0) else {
+        return Err(CppParseError::syntax_error_from(
+            &t!("expect ( after asm"),
+            p.current_token_range(),
+        ));
+    };
+    for _ in 0..end {
+        p.bump();
+    }
+
+    expect_token(p, CppTokenKind::Semicolon)?;
+    Ok(m.complete(p))
+}
+
+/// Parse template parameter list: <typename T, int N = 3, class... Ts>
+pub fn parse_template_parameter_list(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::TemplateParameterList);
+
+    expect_token(p, CppTokenKind::Less)?; // Expect '<'
+
+    let in_template_args = p.set_in_template_args(true);
+    let result = parse_template_parameters(p);
+    p.set_in_template_args(in_template_args);
+    result?;
+
+    expect_token(p, CppTokenKind::Greater)?; // Expect '>'
+
+    Ok(m.complete(p))
+}
+
+fn parse_template_parameters(p: &mut CppParser) -> Result<(), CppParseError> {
+    while p.current_token() != CppTokenKind::Greater && !p.is_eof() {
+        parse_template_parameter(p)?;
+
+        if p.current_token() == CppTokenKind::Comma {
+            p.bump(); // Consume ','
+        } else {
+            break;
         }
-        
-        // Parse virtual keyword - optional
-        if p.current_token() == CppTokenKind::VirtualKeyword {
+    }
+
+    Ok(())
+}
+
+/// Parse single template parameter: typename T = int, int N = 3, class... Ts,
+/// template <class> class TT
+fn parse_template_parameter(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::TemplateParameter);
+
+    match p.current_token() {
+        // Template template parameter: template <class> class TT = std::vector
+        CppTokenKind::TemplateKeyword => {
             p.bump();
+            parse_template_parameter_list(p)?;
+            if !matches!(
+                p.current_token(),
+                CppTokenKind::ClassKeyword | CppTokenKind::TypenameKeyword
+            ) {
+                return Err(CppParseError::syntax_error_from(
+                    &t!("expect class or typename"),
+                    p.current_token_range(),
+                ));
+            }
+            p.bump();
+            parse_type_parameter_rest(p)?;
         }
-        
-        // Parse base class name
-        if p.current_token() == CppTokenKind::Identifier {
+        CppTokenKind::ClassKeyword | CppTokenKind::TypenameKeyword
+            if is_type_parameter(p) =>
+        {
             p.bump();
-            
-            // Parse template arguments if present
-            if p.current_token() == CppTokenKind::Less {
-                parse_template_argument_list(p)?;
+            parse_type_parameter_rest(p)?;
+        }
+        // Non-type template parameter: int N = 3, auto V, Ts... values
+        _ => {
+            let base = parse_type_specifier(p)?;
+            parse_declarator(p, Some(base), DeclaratorMode::Optional)?;
+            if if_token_bump(p, CppTokenKind::Assign) {
+                parse_assign_expr(p)?;
             }
-        } else {
-            return Err(CppParseError::syntax_error_from(
-                "expected base class name",
-                p.current_token_range(),
-            ));
         }
-        
+    }
+
+    Ok(m.complete(p))
+}
+
+/// Parse the optional pack, name and default type after `class` or `typename`
+fn parse_type_parameter_rest(p: &mut CppParser) -> Result<(), CppParseError> {
+    if_token_bump(p, CppTokenKind::Ellipsis);
+    if_token_bump(p, CppTokenKind::Identifier);
+    if if_token_bump(p, CppTokenKind::Assign) {
+        parse_type_id(p)?;
+    }
+    Ok(())
+}
+
+/// `class`/`typename` introduces a type parameter unless it starts the type of a
+/// non-type parameter: `typename T::value_type V`
+fn is_type_parameter(p: &CppParser) -> bool {
+    match p.peek_token_at(1) {
+        CppTokenKind::Identifier => matches!(
+            p.peek_token_at(2),
+            CppTokenKind::Comma | CppTokenKind::Greater | CppTokenKind::Assign
+        ),
+        CppTokenKind::Ellipsis
+        | CppTokenKind::Comma
+        | CppTokenKind::Greater
+        | CppTokenKind::Assign => true,
+        _ => false,
+    }
+}
+
+/// Parse inheritance list: : public Base1, private Base2, ...
+fn parse_inheritance_list(p: &mut CppParser) -> Result<(), CppParseError> {
+    p.bump(); // Consume ':'
+
+    // Parse base class list
+    loop {
+        parse_base_specifier(p)?;
+
         // Check for more base classes
         if p.current_token() == CppTokenKind::Comma {
             p.bump(); // Consume ','
@@ -433,7 +976,41 @@ fn parse_inheritance_list(p: &mut CppParser) -> ParseResult {
             break;
         }
     }
-    
+
+    Ok(())
+}
+
+/// Parse single base class: virtual public Base<T>
+fn parse_base_specifier(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::BaseSpecifier);
+    parse_attribute_lists(p)?;
+
+    // Access specifier and virtual keyword may appear in any order
+    while matches!(
+        p.current_token(),
+        CppTokenKind::PublicKeyword
+            | CppTokenKind::PrivateKeyword
+            | CppTokenKind::ProtectedKeyword
+            | CppTokenKind::VirtualKeyword
+    ) {
+        p.bump();
+    }
+
+    // Parse base class name
+    if !matches!(
+        p.current_token(),
+        CppTokenKind::Identifier | CppTokenKind::Scope | CppTokenKind::DecltypeKeyword
+    ) {
+        return Err(CppParseError::syntax_error_from(
+            &t!("expected base class name"),
+            p.current_token_range(),
+        ));
+    }
+    parse_type_specifier(p)?;
+
+    // Pack expansion: Bases...
+    if_token_bump(p, CppTokenKind::Ellipsis);
+
     Ok(m.complete(p))
 }
 
@@ -482,62 +1059,249 @@ fn parse_access_specifier(p: &mut CppParser) -> ParseResult {
 
 /// Parse member declaration (method, field, constructor, etc.)
 fn parse_member_declaration(p: &mut CppParser) -> ParseResult {
-    // Check for constructor/destructor
-    if p.current_token() == CppTokenKind::Tilde {
-        parse_destructor_declaration(p)
-    } else if p.current_token() == CppTokenKind::Identifier {
-        // Could be constructor, method, or field
-        // This is a simplified check - in real C++, you'd need more lookahead
-        if p.peek_next_token() == CppTokenKind::LeftParen {
-            parse_constructor_or_method_declaration(p)
-        } else {
-            parse_field_declaration(p)
+    match p.current_token() {
+        _ if is_attribute_list_start(p) => parse_attributed(p, parse_member_declaration),
+        CppTokenKind::ClassKeyword
+        | CppTokenKind::StructKeyword
+        | CppTokenKind::EnumKeyword
+        | CppTokenKind::UsingKeyword
+        | CppTokenKind::TypedefKeyword => parse_stat(p),
+        CppTokenKind::TemplateKeyword => parse_template_declaration(p, parse_member_declaration),
+        CppTokenKind::FriendKeyword => parse_friend_declaration(p),
+        _ => {
+            let index = count_decl_specifiers(p);
+            match p.peek_token_at(index) {
+                // Destructor or conversion function: ~A(), operator bool()
+                CppTokenKind::Tilde | CppTokenKind::OperatorKeyword => parse_method_declaration(p),
+                // Constructor: Name(...), but not a nested declarator: T (&ref)[N];
+                CppTokenKind::Identifier
+                    if p.peek_token_at(index + 1) == CppTokenKind::LeftParen
+                        && scan_abstract_declarator(p, index + 1) == index + 1 =>
+                {
+                    parse_method_declaration(p)
+                }
+                _ => match scan_member_declarator(p, index) {
+                    // Methods, including operators: bool operator==(const A&) const;
+                    Some(name_index)
+                        if p.peek_token_at(name_index) == CppTokenKind::OperatorKeyword
+                            || p.peek_token_at(name_index + 1) == CppTokenKind::LeftParen =>
+                    {
+                        parse_method_declaration(p)
+                    }
+                    Some(_) => parse_field_declaration(p),
+                    // Function pointer or array reference member: void (*callback)(int);
+                    None if scan_type_id(p, index)
+                        .is_some_and(|end| scan_abstract_declarator(p, end) != end) =>
+                    {
+                        parse_field_declaration(p)
+                    }
+                    // Parse other member declarations
+                    None => parse_declaration_or_expression_statement(p),
+                },
+            }
         }
-    } else {
-        // Parse other member declarations (methods, fields with type specifiers)
-        parse_declaration_or_expression_statement(p)
     }
 }
 
-/// Parse constructor or method declaration
-fn parse_constructor_or_method_declaration(p: &mut CppParser) -> ParseResult {
-    let m = p.mark(CppSyntaxKind::FunctionDecl);
-    
-    // Parse return type (optional for constructors)
-    // Look ahead to see if this is a constructor or method
-    let mut is_constructor = false;
-    
-    // Simple heuristic: if the identifier is followed by '(', it might be a constructor
-    // In a real implementation, you'd need to check if the name matches the class name
-    if p.current_token() == CppTokenKind::Identifier && p.peek_next_token() == CppTokenKind::LeftParen {
-        is_constructor = true;
-    }
-    
+/// Parse a friend declaration: `friend class B;`, `friend T;` or a friend function
+fn parse_friend_declaration(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::FriendDecl);
+    p.bump(); // Consume 'friend'
+
+    let is_friend_type = match p.current_token() {
+        CppTokenKind::ClassKeyword | CppTokenKind::StructKeyword | CppTokenKind::UnionKeyword => {
+            true
+        }
+        _ => scan_type_id(p, 0).is_some_and(|end| p.peek_token_at(end) == CppTokenKind::Semicolon),
+    };
+    if is_friend_type {
+        parse_type_specifier(p)?;
+        expect_token(p, CppTokenKind::Semicolon)?;
+    } else {
+        parse_member_declaration(p)?;
+    }
+
+    Ok(m.complete(p))
+}
+
+/// Number of leading declaration specifiers such as `static`, `virtual` or `constexpr`,
+/// attribute specifiers between them count with their tokens
+fn count_decl_specifiers(p: &CppParser) -> usize {
+    let mut index = 0;
+    loop {
+        if is_decl_specifier(p.peek_token_at(index)) {
+            index += 1;
+        } else if let Some(end) = scan_attribute_list(p, index) {
+            index = end;
+        } else {
+            return index;
+        }
+    }
+}
+
+/// Parse declaration specifiers and the attributes between them: `static [[maybe_unused]] inline`
+fn parse_decl_specifiers(p: &mut CppParser) -> Result<(), CppParseError> {
+    loop {
+        if is_decl_specifier(p.current_token()) {
+            parse_decl_specifier(p);
+        } else if is_attribute_list_start(p) {
+            parse_attribute_list(p)?;
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+/// Consume one declaration specifier, `consteval` and `constinit` are reported below C++20
+pub fn parse_decl_specifier(p: &mut CppParser) {
+    let range = p.current_token_range();
+    match p.current_token() {
+        CppTokenKind::ConstevalKeyword => p.check_feature(
+            CppFeature::Consteval,
+            &t!("consteval requires C++20"),
+            range,
+        ),
+        CppTokenKind::ConstinitKeyword => p.check_feature(
+            CppFeature::Constinit,
+            &t!("constinit requires C++20"),
+            range,
+        ),
+        _ => {}
+    }
+    p.bump();
+}
+
+pub fn is_decl_specifier(kind: CppTokenKind) -> bool {
+    matches!(
+        kind,
+        CppTokenKind::StaticKeyword
+            | CppTokenKind::VirtualKeyword
+            | CppTokenKind::InlineKeyword
+            | CppTokenKind::ExplicitKeyword
+            | CppTokenKind::ConstexprKeyword
+            | CppTokenKind::MutableKeyword
+            | CppTokenKind::ExternKeyword
+            | CppTokenKind::ThreadLocalKeyword
+            | CppTokenKind::RegisterKeyword
+            | CppTokenKind::ConstevalKeyword
+            | CppTokenKind::ConstinitKeyword
+    )
+}
+
+/// Skip `Type *name` starting at lookahead offset `start`, returns the offset of the declarator name
+fn scan_member_declarator(p: &CppParser, start: usize) -> Option<usize> {
+    let index = scan_type_id(p, start)?;
+    match p.peek_token_at(index) {
+        CppTokenKind::Identifier | CppTokenKind::OperatorKeyword => Some(index),
+        _ => None,
+    }
+}
+
+/// Parse constructor, destructor or method declaration
+fn parse_method_declaration(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::FunctionDecl);
+    parse_decl_specifiers(p)?;
+
+    // Constructors, destructors and conversion functions have no return type
+    let is_constructor = matches!(
+        p.current_token(),
+        CppTokenKind::Tilde | CppTokenKind::OperatorKeyword
+    ) || (p.current_token() == CppTokenKind::Identifier
+            && p.peek_next_token() == CppTokenKind::LeftParen);
     if !is_constructor {
-        // Parse return type for methods
-        while p.current_token() != CppTokenKind::Identifier 
-            && p.current_token() != CppTokenKind::LeftParen
-            && !p.is_eof() {
+        parse_type(p)?;
+    }
+
+    // Parse function name
+    parse_name(p, false)?;
+
+    parse_function_rest(p, m)
+}
+
+/// Parse a function declarator after its name: parameters, qualifiers, trailing return type,
+/// virt-specifiers, constructor initializers and the body
+fn parse_function_rest(p: &mut CppParser, m: Marker) -> ParseResult {
+    // Parse parameter list
+    parse_parameter_list(p)?;
+    parse_function_qualifiers(p)?;
+    parse_function_tail(p, m)
+}
+
+/// Parse what follows the parameters and qualifiers of a function declarator, from the
+/// trailing return type to the body or `;`
+fn parse_function_tail(p: &mut CppParser, mut m: Marker) -> ParseResult {
+    // Trailing return type: auto f() -> int
+    if p.current_token() == CppTokenKind::Arrow {
+        p.bump();
+        parse_type_id(p)?;
+    }
+
+    // Trailing requires clause: void f() requires Integral<T>
+    if is_requires_clause_start(p) {
+        parse_requires_clause(p)?;
+    }
+
+    // Parse override/final specifiers (C++11)
+    while p.current_token() == CppTokenKind::Identifier
+        && matches!(p.current_token_text(), "override" | "final")
+    {
+        p.bump();
+    }
+
+    // Parse pure virtual, defaulted or deleted specifier: = 0, = default, = delete
+    if p.current_token() == CppTokenKind::Assign {
+        p.bump();
+        if matches!(
+            p.current_token(),
+            CppTokenKind::IntegerLiteral | CppTokenKind::DefaultKeyword | CppTokenKind::DeleteKeyword
+        ) {
             p.bump();
         }
     }
-    
-    // Parse function name
-    if p.current_token() == CppTokenKind::Identifier {
+
+    // Parse constructor initializer list: : a(x), b{y}
+    if p.current_token() == CppTokenKind::Colon {
         p.bump();
+        loop {
+            parse_member_initializer(p)?;
+            if !if_token_bump(p, CppTokenKind::Comma) {
+                break;
+            }
+        }
     }
-    
-    // Parse parameter list
-    if p.current_token() == CppTokenKind::LeftParen {
-        parse_parameter_list(p)?;
+
+    // Parse function body or semicolon
+    if p.current_token() == CppTokenKind::LeftBrace {
+        m.set_kind(p, CppSyntaxKind::FunctionDef);
+        parse_compound_stat(p)?;
+    } else {
+        expect_token(p, CppTokenKind::Semicolon)?;
     }
-    
+
+    Ok(m.complete(p))
+}
+
+/// Parse the cv, ref and noexcept qualifiers after a parameter list: `const &&`, `noexcept(true)`
+pub fn parse_function_qualifiers(p: &mut CppParser) -> Result<(), CppParseError> {
     // Parse const qualifier for methods
-    if p.current_token() == CppTokenKind::ConstKeyword {
+    while matches!(
+        p.current_token(),
+        CppTokenKind::ConstKeyword
+            | CppTokenKind::VolatileKeyword
+            | CppTokenKind::Ampersand
+            | CppTokenKind::LogicalAnd
+    ) {
         p.bump();
     }
-    
-    // Parse noexcept specifier (C++11)
+
+    parse_noexcept_specifier(p)?;
+    // Attributes of the function type: void f() noexcept [[gnu::hot]];
+    parse_attribute_lists(p)?;
+    Ok(())
+}
+
+/// Parse an optional `noexcept` or `noexcept(expr)` specifier (C++11)
+pub fn parse_noexcept_specifier(p: &mut CppParser) -> Result<(), CppParseError> {
     if p.current_token() == CppTokenKind::NoexceptKeyword {
         p.bump();
         // Parse optional noexcept expression
@@ -547,69 +1311,245 @@ fn parse_constructor_or_method_declaration(p: &mut CppParser) -> ParseResult {
             expect_token(p, CppTokenKind::RightParen)?;
         }
     }
-      // Parse override/final specifiers (C++11)
-    while matches!(p.current_token(), CppTokenKind::Identifier) {
-        // TODO: Need to implement current_token_text() method to check for "override" or "final"
-        // For now, just skip identifiers that might be override/final
-        break;
+
+    Ok(())
+}
+
+/// The current tokens open an attribute specifier: `[[`, `alignas`, `__attribute__` or
+/// `__declspec`
+pub fn is_attribute_list_start(p: &CppParser) -> bool {
+    scan_attribute_list(p, 0).is_some()
+}
+
+/// Skip the attribute specifiers at lookahead offset `start`, returns the offset of the
+/// first token after them
+pub fn skip_attribute_lists(p: &CppParser, start: usize) -> usize {
+    let mut index = start;
+    while let Some(end) = scan_attribute_list(p, index) {
+        index = end;
     }
-    
-    // Parse pure virtual specifier: = 0
-    if p.current_token() == CppTokenKind::Assign {
-        p.bump();
-        if p.current_token() == CppTokenKind::IntegerLiteral {
-            p.bump(); // Should be '0' for pure virtual
+    index
+}
+
+/// Scan one attribute specifier at lookahead offset `start`, returns the offset after it
+fn scan_attribute_list(p: &CppParser, start: usize) -> Option<usize> {
+    match p.peek_token_at(start) {
+        CppTokenKind::LeftBracket if p.peek_token_at(start + 1) == CppTokenKind::LeftBracket => {
+            let mut depth = 0;
+            let mut index = start;
+            loop {
+                match p.peek_token_at(index) {
+                    CppTokenKind::LeftBracket => depth += 1,
+                    CppTokenKind::RightBracket => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(index + 1);
+                        }
+                    }
+                    CppTokenKind::Semicolon | CppTokenKind::Eof | CppTokenKind::None => {
+                        return None;
+                    }
+                    _ => {}
+                }
+                index += 1;
+            }
         }
+        CppTokenKind::AlignasKeyword => scan_balanced_parens(p, start + 1),
+        CppTokenKind::Identifier
+            if matches!(p.peek_token_text_at(start), "__attribute__" | "__declspec")
+                && p.peek_token_at(start + 1) == CppTokenKind::LeftParen =>
+        {
+            scan_balanced_parens(p, start + 1)
+        }
+        _ => None,
     }
-    
-    // Parse function body or semicolon
-    if p.current_token() == CppTokenKind::LeftBrace {
-        parse_compound_stat(p)?;
-    } else if p.current_token() == CppTokenKind::Semicolon {
-        p.bump();
+}
+
+/// Parse a run of attribute specifiers, returns the first one
+pub fn parse_attribute_lists(p: &mut CppParser) -> Result<Option<CompleteMarker>, CppParseError> {
+    let mut first = None;
+    while is_attribute_list_start(p) {
+        let cm = parse_attribute_list(p)?;
+        first.get_or_insert(cm);
     }
-    
+    Ok(first)
+}
+
+/// Parse the attribute specifiers in front of a declaration or statement, then the
+/// declaration itself. The attributes become the first children of its node.
+fn parse_attributed(
+    p: &mut CppParser,
+    parse_decl: fn(&mut CppParser) -> ParseResult,
+) -> ParseResult {
+    let Some(first) = parse_attribute_lists(p)? else {
+        return parse_decl(p);
+    };
+    let cm = parse_decl(p)?;
+    Ok(cm.extend_to(p, &first))
+}
+
+/// Parse an attribute specifier: [[nodiscard]], [[gnu::always_inline, deprecated("msg")]],
+/// [[using gnu: hot, cold]], alignas(16), __attribute__((aligned(8))), __declspec(dllexport)
+pub fn parse_attribute_list(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::AttributeList);
+    match p.current_token() {
+        CppTokenKind::LeftBracket => {
+            p.bump(); // Consume '['
+            p.bump(); // Consume '['
+
+            // Attribute namespace of the whole list (C++17): [[using gnu: hot, cold]]
+            if if_token_bump(p, CppTokenKind::UsingKeyword) {
+                expect_token(p, CppTokenKind::Identifier)?;
+                expect_token(p, CppTokenKind::Colon)?;
+            }
+
+            parse_attribute_sequence(p, CppTokenKind::RightBracket)?;
+            expect_token(p, CppTokenKind::RightBracket)?;
+            expect_token(p, CppTokenKind::RightBracket)?;
+        }
+        // The argument is a type or an expression, kept as tokens like other arguments
+        CppTokenKind::AlignasKeyword => {
+            let attribute_m = p.mark(CppSyntaxKind::Attribute);
+            p.bump(); // Consume 'alignas'
+            parse_attribute_arguments(p)?;
+            if_token_bump(p, CppTokenKind::Ellipsis);
+            attribute_m.complete(p);
+        }
+        _ if p.current_token_text() == "__attribute__" => {
+            p.bump(); // Consume '__attribute__'
+            expect_token(p, CppTokenKind::LeftParen)?;
+            expect_token(p, CppTokenKind::LeftParen)?;
+            parse_attribute_sequence(p, CppTokenKind::RightParen)?;
+            expect_token(p, CppTokenKind::RightParen)?;
+            expect_token(p, CppTokenKind::RightParen)?;
+        }
+        _ => {
+            p.bump(); // Consume '__declspec'
+            expect_token(p, CppTokenKind::LeftParen)?;
+            // Separated by spaces: __declspec(dllexport noinline)
+            while p.current_token() != CppTokenKind::RightParen && !p.is_eof() {
+                parse_attribute(p)?;
+            }
+            expect_token(p, CppTokenKind::RightParen)?;
+        }
+    }
+
     Ok(m.complete(p))
 }
 
-/// Parse destructor declaration: ~ClassName() { ... }
-fn parse_destructor_declaration(p: &mut CppParser) -> ParseResult {
-    let m = p.mark(CppSyntaxKind::FunctionDecl);
-    
-    p.bump(); // Consume '~'
-    
-    // Parse destructor name
-    if p.current_token() == CppTokenKind::Identifier {
-        p.bump();
+/// Parse comma separated attributes up to `close`, empty ones are allowed: [[, nodiscard]]
+fn parse_attribute_sequence(p: &mut CppParser, close: CppTokenKind) -> Result<(), CppParseError> {
+    while p.current_token() != close && !p.is_eof() {
+        if p.current_token() != CppTokenKind::Comma {
+            parse_attribute(p)?;
+        }
+        if !if_token_bump(p, CppTokenKind::Comma) {
+            break;
+        }
     }
-    
-    // Parse parameter list (should be empty for destructor)
+
+    Ok(())
+}
+
+/// Parse a single attribute, its arguments are kept as a balanced token sequence
+fn parse_attribute(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::Attribute);
+    expect_attribute_name(p)?;
+    if if_token_bump(p, CppTokenKind::Scope) {
+        expect_attribute_name(p)?;
+    }
+
     if p.current_token() == CppTokenKind::LeftParen {
-        parse_parameter_list(p)?;
+        parse_attribute_arguments(p)?;
     }
-    
-    // Parse function body or semicolon
-    if p.current_token() == CppTokenKind::LeftBrace {
-        parse_compound_stat(p)?;
-    } else if p.current_token() == CppTokenKind::Semicolon {
+    // Pack expansion: [[vendor::attr(args)...]]
+    if_token_bump(p, CppTokenKind::Ellipsis);
+
+    Ok(m.complete(p))
+}
+
+/// Attribute names may be keywords: __attribute__((const)), [[gnu::noinline]]
+fn expect_attribute_name(p: &mut CppParser) -> Result<(), CppParseError> {
+    let is_name = !p.is_eof()
+        && p.current_token_text()
+            .starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+        && p.current_token_text()
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    if !is_name {
+        return Err(CppParseError::syntax_error_from(
+            &t!("expect attribute name"),
+            p.current_token_range(),
+        ));
+    }
+
+    p.bump();
+    Ok(())
+}
+
+fn parse_attribute_arguments(p: &mut CppParser) -> Result<(), CppParseError> {
+    let Some(end) = scan_balanced_parens(p, 0) else {
+        return Err(CppParseError::syntax_error_from(
+            &t!("expect ) after attribute arguments"),
+            p.current_token_range(),
+        ));
+    };
+    for _ in 0..end {
         p.bump();
     }
-    
+
+    Ok(())
+}
+
+/// Parse member initializer: member(args) or Base{args}
+fn parse_member_initializer(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::MemberInitializer);
+    parse_name(p, true)?;
+    if p.current_token() == CppTokenKind::LeftBrace {
+        parse_init_list_expr(p)?;
+    } else {
+        parse_argument_list(p)?;
+    }
+    if_token_bump(p, CppTokenKind::Ellipsis);
     Ok(m.complete(p))
 }
 
-/// Parse field declaration: int x; or int x = 5;
+/// Parse field declaration: int x; int x = 5, *y; unsigned flag : 1; void (*fp)(int);
 fn parse_field_declaration(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::FieldDecl);
-    
-    // For now, just parse as a declaration statement
-    parse_declaration_or_expression_statement(p)?;
-    
+    parse_decl_specifiers(p)?;
+    let mut base = Some(parse_type_specifier(p)?);
+
+    loop {
+        // Unnamed bit-field: int : 4;
+        if p.current_token() != CppTokenKind::Colon {
+            parse_declarator(p, base.take(), DeclaratorMode::Named)?;
+        }
+
+        // Bit-field width: unsigned flag : 1;
+        if p.current_token() == CppTokenKind::Colon {
+            p.bump();
+            parse_assign_expr(p)?;
+        }
+
+        // Default member initializer: = value or {value}
+        if if_token_bump(p, CppTokenKind::Assign) {
+            parse_initializer_clause(p)?;
+        } else if p.current_token() == CppTokenKind::LeftBrace {
+            parse_init_list_expr(p)?;
+        }
+
+        if !if_token_bump(p, CppTokenKind::Comma) {
+            break;
+        }
+    }
+
+    expect_token(p, CppTokenKind::Semicolon)?;
     Ok(m.complete(p))
 }
 
 /// Parse parameter list: (int x, double y, ...)
-fn parse_parameter_list(p: &mut CppParser) -> ParseResult {
+pub fn parse_parameter_list(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::ParameterList);
     
     expect_token(p, CppTokenKind::LeftParen)?; // Expect '('
@@ -629,87 +1569,295 @@ fn parse_parameter_list(p: &mut CppParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
-/// Parse single parameter: int x or const std::string& name = "default"
+/// Parse single parameter: int x, const std::string& name = "default" or int (*callback)(int)
 fn parse_parameter(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::Parameter);
-    
-    // Parse type (simplified - just consume tokens until we get to identifier or special chars)
-    while p.current_token() != CppTokenKind::Identifier 
-        && p.current_token() != CppTokenKind::RightParen
-        && p.current_token() != CppTokenKind::Comma
-        && !p.is_eof() {
-        p.bump();
-    }
-    
-    // Parse parameter name
-    if p.current_token() == CppTokenKind::Identifier {
-        p.bump();
+    parse_attribute_lists(p)?;
+
+    // C-style variadic parameter: printf(const char*, ...)
+    if if_token_bump(p, CppTokenKind::Ellipsis) {
+        return Ok(m.complete(p));
     }
-    
+
+    let base = parse_type_specifier(p)?;
+    parse_declarator(p, Some(base), DeclaratorMode::Optional)?;
+
     // Parse default value if present
     if p.current_token() == CppTokenKind::Assign {
         p.bump();
-        parse_expr(p)?;
+        parse_initializer_clause(p)?;
     }
-    
+
     Ok(m.complete(p))
 }
 
-/// Parse template argument list: <T, int N, ...>
-fn parse_template_argument_list(p: &mut CppParser) -> ParseResult {
-    let m = p.mark(CppSyntaxKind::TemplateArgumentList);
-    
-    expect_token(p, CppTokenKind::Less)?; // Expect '<'
-    
-    while p.current_token() != CppTokenKind::Greater && !p.is_eof() {
-        // Parse template argument (type or expression)
-        parse_template_argument(p)?;
-        
-        if p.current_token() == CppTokenKind::Comma {
-            p.bump(); // Consume ','
-        } else {
-            break;
+/// Parse declaration or expression statement
+///
+/// Anything that can be read as a declaration is one. The specifiers, the type and the first
+/// declarator name are parsed tentatively, when they do not form a declaration the parser
+/// rewinds and reads an expression statement instead.
+fn parse_declaration_or_expression_statement(p: &mut CppParser) -> ParseResult {
+    // Out-of-line constructor, destructor or conversion function: Foo::Foo(int x) : x(x) {}
+    if is_ctor_or_dtor_definition(p) || is_conversion_function_definition(p) {
+        let m = p.mark(CppSyntaxKind::FunctionDecl);
+        parse_name(p, false)?;
+        return parse_function_rest(p, m);
+    }
+
+    let state = p.save_state();
+    let mut m = p.mark(CppSyntaxKind::VariableDecl);
+    let head = match parse_declaration_head(p) {
+        Ok(head) => head,
+        Err(_) => {
+            p.restore_state(state);
+            return parse_expression_statement(p);
         }
+    };
+
+    if head.is_typedef {
+        m.set_kind(p, CppSyntaxKind::TypedefDecl);
+    } else if head.is_function {
+        m.set_kind(p, CppSyntaxKind::FunctionDecl);
+        return parse_function_tail(p, m);
+    } else if is_parameter_list_start(p, 0)
+        || (head.is_plain_void && p.current_token() == CppTokenKind::LeftParen)
+    {
+        m.set_kind(p, CppSyntaxKind::FunctionDecl);
+        return parse_function_rest(p, m);
     }
-    
-    expect_token(p, CppTokenKind::Greater)?; // Expect '>'
-    
+
+    parse_init_declarator_rest(p)?;
+    while if_token_bump(p, CppTokenKind::Comma) {
+        parse_declarator(p, None, DeclaratorMode::Named)?;
+        parse_init_declarator_rest(p)?;
+    }
+
+    expect_token(p, CppTokenKind::Semicolon)?;
     Ok(m.complete(p))
 }
 
-/// Parse single template argument
-fn parse_template_argument(p: &mut CppParser) -> ParseResult {
-    let m = p.mark(CppSyntaxKind::TemplateArgument);
-    
-    // For now, just parse as expression (could be type or value)
-    parse_expr(p)?;
-    
-    Ok(m.complete(p))
+/// What `parse_declaration_head` found out about a simple declaration
+struct DeclarationHead {
+    is_typedef: bool,
+    /// The declarator is `void` with a name only, a `(` after it starts the parameters
+    /// since no variable can have type `void`
+    is_plain_void: bool,
+    /// The parameters are part of a parenthesized declarator: `void (*signal(int))(int)`
+    is_function: bool,
 }
 
-/// Parse declaration or expression statement
-fn parse_declaration_or_expression_statement(p: &mut CppParser) -> ParseResult {
-    // This is a placeholder - in a real parser, you'd need to distinguish
-    // between declarations and expressions through lookahead
-    let m = p.mark(CppSyntaxKind::DeclStat);
-    
-    // For now, just consume tokens until semicolon
-    while p.current_token() != CppTokenKind::Semicolon && !p.is_eof() {
-        if p.current_token() == CppTokenKind::LeftBrace {
-            parse_compound_stat(p)?;
-            break;
-        } else {
-            p.bump();
+/// Parse `specifiers type declarator` of a simple declaration, fails when the tokens cannot
+/// start a declaration.
+fn parse_declaration_head(p: &mut CppParser) -> Result<DeclarationHead, CppParseError> {
+    let mut is_typedef = false;
+    loop {
+        match p.current_token() {
+            CppTokenKind::TypedefKeyword => is_typedef = true,
+            kind if is_decl_specifier(kind) => {
+                parse_decl_specifier(p);
+                continue;
+            }
+            _ if is_attribute_list_start(p) => {
+                parse_attribute_list(p)?;
+                continue;
+            }
+            _ => break,
         }
+        p.bump();
     }
-    
-    if p.current_token() == CppTokenKind::Semicolon {
+
+    let is_void = p.current_token() == CppTokenKind::VoidKeyword;
+    let base = parse_type_specifier(p)?;
+    let mut is_plain_void = false;
+    let mut is_function = false;
+    if matches!(
+        base.kind,
+        CppSyntaxKind::AutoType | CppSyntaxKind::QualifiedType
+    ) && is_structured_binding_start(p)
+    {
+        parse_structured_binding(p, base)?;
+    } else {
+        // A parenthesized declarator needs a suffix, `f(*p);` is a call
+        if p.current_token() == CppTokenKind::LeftParen
+            && !scan_balanced_parens(p, 0).is_some_and(|end| {
+                matches!(
+                    p.peek_token_at(end),
+                    CppTokenKind::LeftParen | CppTokenKind::LeftBracket
+                )
+            })
+        {
+            return Err(CppParseError::syntax_error_from(
+                &t!("expect name"),
+                p.current_token_range(),
+            ));
+        }
+        let declarator = parse_declarator(p, Some(base), DeclaratorMode::Named)?;
+        let kind = declarator.map(|cm| cm.kind);
+        is_plain_void = is_void && kind == Some(CppSyntaxKind::BuiltinType);
+        is_function = kind == Some(CppSyntaxKind::FunctionType);
+    }
+
+    if !matches!(
+        p.current_token(),
+        CppTokenKind::Semicolon
+            | CppTokenKind::Comma
+            | CppTokenKind::Assign
+            | CppTokenKind::LeftBrace
+            | CppTokenKind::LeftParen
+            | CppTokenKind::Colon
+    ) {
+        return Err(CppParseError::syntax_error_from(
+            &t!("unexpected token"),
+            p.current_token_range(),
+        ));
+    }
+
+    Ok(DeclarationHead {
+        is_typedef,
+        is_plain_void,
+        is_function,
+    })
+}
+
+/// `[a, b]` or `&[a, b]` after the `auto` of a structured binding declaration
+fn is_structured_binding_start(p: &CppParser) -> bool {
+    let mut index = match p.current_token() {
+        CppTokenKind::Ampersand | CppTokenKind::LogicalAnd => 1,
+        _ => 0,
+    };
+    if p.peek_token_at(index) != CppTokenKind::LeftBracket {
+        return false;
+    }
+
+    loop {
+        if p.peek_token_at(index + 1) != CppTokenKind::Identifier {
+            return false;
+        }
+        match p.peek_token_at(index + 2) {
+            CppTokenKind::Comma => index += 2,
+            CppTokenKind::RightBracket => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Parse the ref-qualifier and the names of a structured binding (C++17): `auto& [key, value]`
+fn parse_structured_binding(p: &mut CppParser, base: CompleteMarker) -> Result<(), CppParseError> {
+    let ref_kind = match p.current_token() {
+        CppTokenKind::Ampersand => Some(CppSyntaxKind::ReferenceType),
+        CppTokenKind::LogicalAnd => Some(CppSyntaxKind::RValueReferenceType),
+        _ => None,
+    };
+    if let Some(kind) = ref_kind {
+        let m = base.precede(p, kind);
         p.bump();
+        m.complete(p);
     }
-    
+
+    let range = p.current_token_range();
+    p.check_feature(
+        CppFeature::StructuredBindings,
+        &t!("structured bindings require C++17"),
+        range,
+    );
+    let m = p.mark(CppSyntaxKind::StructuredBinding);
+    p.bump(); // Consume '['
+    loop {
+        expect_token(p, CppTokenKind::Identifier)?;
+        if !if_token_bump(p, CppTokenKind::Comma) {
+            break;
+        }
+    }
+    expect_token(p, CppTokenKind::RightBracket)?;
+    m.complete(p);
+    Ok(())
+}
+
+/// Parse the initializer of a declarator: `= {}`, `(1, 2)`, `{x}`
+fn parse_init_declarator_rest(p: &mut CppParser) -> Result<(), CppParseError> {
+    match p.current_token() {
+        CppTokenKind::Assign => {
+            p.bump();
+            parse_initializer_clause(p)?;
+        }
+        CppTokenKind::LeftBrace => {
+            parse_init_list_expr(p)?;
+        }
+        CppTokenKind::LeftParen => {
+            parse_argument_list(p)?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn parse_expression_statement(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::ExpressionStat);
+    parse_expr(p)?;
+    expect_token(p, CppTokenKind::Semicolon)?;
     Ok(m.complete(p))
 }
 
+/// `Foo::Foo(...)` or `Foo::~Foo(...)` followed by a body or an initializer list
+fn is_ctor_or_dtor_definition(p: &CppParser) -> bool {
+    let Some(mut index) = scan_name(p, 0) else {
+        return false;
+    };
+    if p.peek_token_at(index) == CppTokenKind::Scope
+        && p.peek_token_at(index + 1) == CppTokenKind::Tilde
+        && p.peek_token_at(index + 2) == CppTokenKind::Identifier
+    {
+        index += 3;
+    }
+    // A plain `f(x)` followed by `{` is not a declaration
+    if !(1..index).any(|i| p.peek_token_at(i) == CppTokenKind::Scope) {
+        return false;
+    }
+
+    match scan_balanced_parens(p, index) {
+        Some(after) => matches!(
+            p.peek_token_at(after),
+            CppTokenKind::LeftBrace | CppTokenKind::Colon | CppTokenKind::NoexceptKeyword
+        ),
+        None => false,
+    }
+}
+
+/// `Foo::operator int() const` followed by a body
+fn is_conversion_function_definition(p: &CppParser) -> bool {
+    let Some(index) = scan_name(p, 0) else {
+        return false;
+    };
+    if p.peek_token_at(index) != CppTokenKind::Scope
+        || p.peek_token_at(index + 1) != CppTokenKind::OperatorKeyword
+    {
+        return false;
+    }
+    let Some(end) = scan_type_id(p, index + 2) else {
+        return false;
+    };
+    let Some(mut after) = scan_balanced_parens(p, end) else {
+        return false;
+    };
+
+    loop {
+        match p.peek_token_at(after) {
+            CppTokenKind::ConstKeyword
+            | CppTokenKind::VolatileKeyword
+            | CppTokenKind::Ampersand
+            | CppTokenKind::LogicalAnd => after += 1,
+            CppTokenKind::NoexceptKeyword => {
+                after += 1;
+                if let Some(end) = scan_balanced_parens(p, after) {
+                    after = end;
+                }
+            }
+            kind => return kind == CppTokenKind::LeftBrace,
+        }
+    }
+}
+
 /// Parse enum body: { RED, GREEN, BLUE }
 fn parse_enum_body(p: &mut CppParser) -> ParseResult {
     let m = p.mark(CppSyntaxKind::CompoundStat);
@@ -719,18 +1867,21 @@ fn parse_enum_body(p: &mut CppParser) -> ParseResult {
     while p.current_token() != CppTokenKind::RightBrace && !p.is_eof() {
         // Parse enum member
         if p.current_token() == CppTokenKind::Identifier {
-            let member_m = p.mark(CppSyntaxKind::DeclStat); // Use DeclStat for now
+            let member_m = p.mark(CppSyntaxKind::EnumeratorDecl);
             p.bump(); // Consume enum member name
-            
+    
+            // Attributes of the enumerator (C++17): OLD [[deprecated]] = 1
+            parse_attribute_lists(p)?;
+    
             // Parse value assignment if present: RED = 1
             if p.current_token() == CppTokenKind::Assign {
                 p.bump(); // Consume '='
-                parse_expr(p)?; // Parse the value expression
+                parse_assign_expr(p)?; // Parse the value expression
             }
-            
+    
             member_m.complete(p);
         }
-        
+    
         // Check for comma
         if p.current_token() == CppTokenKind::Comma {
             p.bump(); // Consume ','
@@ -0,0 +1,960 @@
+use crate::{
+    grammar::ParseResult,
+    kind::{CppFeature, CppSyntaxKind, CppTokenKind},
+    parser::{CompleteMarker, CppParser, Marker, MarkerEventContainer},
+    parser_error::CppParseError,
+};
+
+use super::{
+    expect_token,
+    exprs::{parse_assign_expr, parse_expr},
+    if_token_bump,
+    stats::{
+        is_decl_specifier, parse_attribute_lists, parse_function_qualifiers, parse_parameter_list,
+        skip_attribute_lists,
+    },
+};
+
+/// Parse a type-id: `const std::vector<int>&`, `unsigned long*`, `decltype(x)`
+pub fn parse_type(p: &mut CppParser) -> ParseResult {
+    let cm = parse_type_specifier(p)?;
+    parse_ptr_operators(p, cm)
+}
+
+/// Parse a type-id with an abstract declarator: `void(int)`, `int (*)(int)`, `char (&)[4]`
+pub fn parse_type_id(p: &mut CppParser) -> ParseResult {
+    let cm = parse_type_specifier(p)?;
+    match parse_declarator(p, Some(cm), DeclaratorMode::Abstract)? {
+        Some(cm) => Ok(cm),
+        None => unreachable!(),
+    }
+}
+
+/// Wrap the already parsed type in pointer and reference layers: `T*`, `T* const`, `T&`, `T&&`, `T C::*`
+pub fn parse_ptr_operators(p: &mut CppParser, mut cm: CompleteMarker) -> ParseResult {
+    while let Some(kind) = ptr_operator_kind(p) {
+        let m = cm.precede(p, kind);
+        cm = parse_ptr_operator(p, m)?;
+    }
+
+    Ok(cm)
+}
+
+fn ptr_operator_kind(p: &CppParser) -> Option<CppSyntaxKind> {
+    match p.current_token() {
+        CppTokenKind::Star => Some(CppSyntaxKind::PointerType),
+        CppTokenKind::Ampersand => Some(CppSyntaxKind::ReferenceType),
+        CppTokenKind::LogicalAnd => Some(CppSyntaxKind::RValueReferenceType),
+        CppTokenKind::Identifier | CppTokenKind::Scope if is_member_pointer_start(p, 0) => {
+            Some(CppSyntaxKind::PointerType)
+        }
+        _ => None,
+    }
+}
+
+fn parse_ptr_operator(p: &mut CppParser, m: Marker) -> ParseResult {
+    parse_ptr_operator_tokens(p)?;
+    Ok(m.complete(p))
+}
+
+/// Parse `*`, `&`, `&&` or `C::*` with the attributes and cv-qualifiers after it
+fn parse_ptr_operator_tokens(p: &mut CppParser) -> Result<(), CppParseError> {
+    // Pointer to member: C::*
+    if matches!(
+        p.current_token(),
+        CppTokenKind::Identifier | CppTokenKind::Scope
+    ) {
+        let class_m = p.mark(CppSyntaxKind::NamedType);
+        parse_name(p, true)?;
+        class_m.complete(p);
+        expect_token(p, CppTokenKind::Scope)?;
+    }
+
+    let is_pointer = p.current_token() == CppTokenKind::Star;
+    p.bump();
+    // Attributes of the pointer or reference: int * [[gnu::aligned(8)]] p
+    parse_attribute_lists(p)?;
+    if is_pointer {
+        while is_cv_qualifier(p.current_token()) {
+            p.bump();
+        }
+    }
+    Ok(())
+}
+
+/// `C::*` at lookahead offset `start`
+fn is_member_pointer_start(p: &CppParser, start: usize) -> bool {
+    match scan_name(p, start) {
+        Some(index) => {
+            p.peek_token_at(index) == CppTokenKind::Scope
+                && p.peek_token_at(index + 1) == CppTokenKind::Star
+        }
+        None => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclaratorMode {
+    /// Variables and fields: the declarator must have a name
+    Named,
+    /// Parameters: the name may be omitted
+    Optional,
+    /// Type-ids: no name at all
+    Abstract,
+}
+
+/// Parse a declarator after the decl-specifiers, `base` is the already parsed type specifier.
+///
+/// The type nodes are built inside-out, the way the declarator reads: pointer operators wrap
+/// the type before them and array or function suffixes wrap everything from the type specifier
+/// up to the suffix, so `int *a[3]` is an ArrayType of a PointerType. The parts of a
+/// parenthesized declarator apply after the suffixes following it, `int (*fp)(int)` is a
+/// PointerType of the FunctionType holding `int` and the ParameterList. The tokens of the
+/// parenthesized part, the declarator name among them, stay inside the innermost node.
+///
+/// Returns the outermost type node, `None` when a declarator without a base type
+/// such as the `b` of `int a, b;` produced no node.
+pub fn parse_declarator(
+    p: &mut CppParser,
+    mut base: Option<CompleteMarker>,
+    mode: DeclaratorMode,
+) -> Result<Option<CompleteMarker>, CppParseError> {
+    // GNU attributes in front of the declarator: int __attribute__((unused)) x
+    if mode != DeclaratorMode::Abstract {
+        parse_attribute_lists(p)?;
+    }
+    while let Some(kind) = ptr_operator_kind(p) {
+        let m = match base.take() {
+            Some(cm) => cm.precede(p, kind),
+            None => p.mark(kind),
+        };
+        base = Some(parse_ptr_operator(p, m)?);
+    }
+
+    // The kind is set once a suffix shows up, a plain name does not get a node
+    let mut start_m = match base {
+        Some(_) => None,
+        None => Some(p.mark(CppSyntaxKind::ArrayType)),
+    };
+
+    let mut has_name = false;
+    let mut nested = Vec::new();
+    let parenthesized = p.current_token() == CppTokenKind::LeftParen
+        && (mode == DeclaratorMode::Named || is_nested_declarator_start(p));
+    if parenthesized {
+        p.bump(); // Consume '('
+        let in_template_args = p.set_in_template_args(false);
+        let result = parse_nested_declarator(p, mode);
+        p.set_in_template_args(in_template_args);
+        nested = result?;
+        expect_token(p, CppTokenKind::RightParen)?;
+    } else {
+        has_name = parse_declarator_name(p, mode)?;
+    }
+
+    // A `(` after a plain name is a direct initializer or the parameters of a function
+    // declaration, both handled by the caller
+    loop {
+        let kind = match p.current_token() {
+            CppTokenKind::LeftBracket => CppSyntaxKind::ArrayType,
+            CppTokenKind::LeftParen
+                if parenthesized
+                    || (!has_name
+                        && mode != DeclaratorMode::Named
+                        && is_parameter_list_start(p, 0)) =>
+            {
+                CppSyntaxKind::FunctionType
+            }
+            _ => break,
+        };
+
+        let m = wrap_declarator_type(p, &mut base, &mut start_m, kind);
+        parse_declarator_suffix(p, kind)?;
+        base = Some(m.complete(p));
+    }
+
+    // int (*fp)(int): the pointer applies to the function type
+    for kind in nested {
+        let m = wrap_declarator_type(p, &mut base, &mut start_m, kind);
+        base = Some(m.complete(p));
+    }
+
+    if let Some(m) = start_m {
+        m.undo(p);
+    }
+
+    Ok(base)
+}
+
+/// Parse the declarator inside the parentheses of `int (*fp)(int)` without building nodes,
+/// returns the kinds of the type nodes it stands for in the order they wrap the outer type
+fn parse_nested_declarator(
+    p: &mut CppParser,
+    mode: DeclaratorMode,
+) -> Result<Vec<CppSyntaxKind>, CppParseError> {
+    if mode != DeclaratorMode::Abstract {
+        parse_attribute_lists(p)?;
+    }
+    let mut kinds = Vec::new();
+    while let Some(kind) = ptr_operator_kind(p) {
+        parse_ptr_operator_tokens(p)?;
+        kinds.push(kind);
+    }
+
+    let mut nested = Vec::new();
+    if p.current_token() == CppTokenKind::LeftParen
+        && (mode == DeclaratorMode::Named || is_nested_declarator_start(p))
+    {
+        p.bump(); // Consume '('
+        nested = parse_nested_declarator(p, mode)?;
+        expect_token(p, CppTokenKind::RightParen)?;
+    } else {
+        parse_declarator_name(p, mode)?;
+    }
+
+    // Inside the parentheses any `(` is a parameter list: void (*signal(int, void (*)(int)))(int)
+    loop {
+        let kind = match p.current_token() {
+            CppTokenKind::LeftBracket => CppSyntaxKind::ArrayType,
+            CppTokenKind::LeftParen => CppSyntaxKind::FunctionType,
+            _ => break,
+        };
+        parse_declarator_suffix(p, kind)?;
+        kinds.push(kind);
+    }
+
+    kinds.extend(nested);
+    Ok(kinds)
+}
+
+/// Parse the name of a declarator, returns whether there is one
+fn parse_declarator_name(p: &mut CppParser, mode: DeclaratorMode) -> Result<bool, CppParseError> {
+    if mode == DeclaratorMode::Abstract {
+        return Ok(false);
+    }
+
+    // Parameter pack: Ts... args
+    if mode == DeclaratorMode::Optional {
+        if_token_bump(p, CppTokenKind::Ellipsis);
+    }
+
+    if matches!(
+        p.current_token(),
+        CppTokenKind::Identifier
+            | CppTokenKind::Scope
+            | CppTokenKind::OperatorKeyword
+            | CppTokenKind::Tilde
+    ) {
+        parse_name(p, false)?;
+        // Attributes of the declared entity: int x [[maybe_unused]] = 0
+        parse_attribute_lists(p)?;
+        Ok(true)
+    } else if mode == DeclaratorMode::Named {
+        Err(CppParseError::syntax_error_from(
+            &t!("expect name"),
+            p.current_token_range(),
+        ))
+    } else {
+        Ok(false)
+    }
+}
+
+/// Start the node of `kind` around the type parsed so far, `start_m` marks the start of
+/// a declarator without a base type
+fn wrap_declarator_type(
+    p: &mut CppParser,
+    base: &mut Option<CompleteMarker>,
+    start_m: &mut Option<Marker>,
+    kind: CppSyntaxKind,
+) -> Marker {
+    match (base.take(), start_m.take()) {
+        (Some(cm), _) => cm.precede(p, kind),
+        (None, Some(mut m)) => {
+            m.set_kind(p, kind);
+            m
+        }
+        (None, None) => unreachable!(),
+    }
+}
+
+/// Parse an array or function suffix of a declarator
+fn parse_declarator_suffix(p: &mut CppParser, kind: CppSyntaxKind) -> Result<(), CppParseError> {
+    if kind == CppSyntaxKind::ArrayType {
+        parse_array_suffix(p)
+    } else {
+        parse_parameter_list(p)?;
+        parse_function_qualifiers(p)
+    }
+}
+
+/// Parse array bounds: `[4]` or `[]`
+fn parse_array_suffix(p: &mut CppParser) -> Result<(), CppParseError> {
+    p.bump(); // Consume '['
+    if p.current_token() != CppTokenKind::RightBracket {
+        let in_template_args = p.set_in_template_args(false);
+        let result = parse_expr(p);
+        p.set_in_template_args(in_template_args);
+        result?;
+    }
+    expect_token(p, CppTokenKind::RightBracket)?;
+    Ok(())
+}
+
+/// `(` opens a nested declarator rather than a parameter list: `(*fp)`, `(&)`, `(C::*pm)`
+fn is_nested_declarator_start(p: &CppParser) -> bool {
+    p.current_token() == CppTokenKind::LeftParen
+        && (matches!(
+            p.peek_token_at(1),
+            CppTokenKind::Star | CppTokenKind::Ampersand | CppTokenKind::LogicalAnd
+        ) || is_member_pointer_start(p, 1))
+}
+
+/// Parse a type specifier with its cv-qualifiers: `const int`, `Foo const`
+pub fn parse_type_specifier(p: &mut CppParser) -> ParseResult {
+    if is_cv_qualifier(p.current_token()) {
+        let m = p.mark(CppSyntaxKind::QualifiedType);
+        while is_cv_qualifier(p.current_token()) {
+            p.bump();
+        }
+        parse_simple_type_specifier(p)?;
+        while is_cv_qualifier(p.current_token()) {
+            p.bump();
+        }
+        return Ok(m.complete(p));
+    }
+
+    let cm = parse_simple_type_specifier(p)?;
+    if is_cv_qualifier(p.current_token()) {
+        let m = cm.precede(p, CppSyntaxKind::QualifiedType);
+        while is_cv_qualifier(p.current_token()) {
+            p.bump();
+        }
+        return Ok(m.complete(p));
+    }
+
+    Ok(cm)
+}
+
+fn parse_simple_type_specifier(p: &mut CppParser) -> ParseResult {
+    match p.current_token() {
+        kind if is_builtin_type_keyword(kind) => {
+            let m = p.mark(CppSyntaxKind::BuiltinType);
+            while is_builtin_type_keyword(p.current_token()) {
+                p.bump();
+            }
+            Ok(m.complete(p))
+        }
+        CppTokenKind::AutoKeyword => {
+            let m = p.mark(CppSyntaxKind::AutoType);
+            p.bump();
+            Ok(m.complete(p))
+        }
+        CppTokenKind::DecltypeKeyword => parse_decltype_type(p),
+        CppTokenKind::TypenameKeyword => {
+            let m = p.mark(CppSyntaxKind::TypenameType);
+            p.bump();
+            parse_name(p, true)?;
+            Ok(m.complete(p))
+        }
+        CppTokenKind::ClassKeyword
+        | CppTokenKind::StructKeyword
+        | CppTokenKind::UnionKeyword
+        | CppTokenKind::EnumKeyword => {
+            let mut m = p.mark(CppSyntaxKind::NamedType);
+            p.bump();
+            if parse_name(p, true)? {
+                m.set_kind(p, CppSyntaxKind::TemplateType);
+            }
+            Ok(m.complete(p))
+        }
+        CppTokenKind::Identifier | CppTokenKind::Scope => {
+            let mut m = p.mark(CppSyntaxKind::NamedType);
+            if parse_name(p, true)? {
+                m.set_kind(p, CppSyntaxKind::TemplateType);
+            }
+            let cm = m.complete(p);
+
+            // Constrained placeholder (C++20): std::integral auto, C<T> decltype(auto)
+            if is_placeholder_start(p, 0) {
+                let range = p.current_token_range();
+                p.check_feature(CppFeature::Concepts, &t!("concepts require C++20"), range);
+                let m = cm.precede(p, CppSyntaxKind::AutoType);
+                if p.current_token() == CppTokenKind::AutoKeyword {
+                    p.bump();
+                } else {
+                    parse_decltype_type(p)?;
+                }
+                return Ok(m.complete(p));
+            }
+            Ok(cm)
+        }
+        _ => Err(CppParseError::syntax_error_from(
+            &t!("expect type"),
+            p.current_token_range(),
+        )),
+    }
+}
+
+/// `auto` or `decltype(auto)` after a type-constraint at lookahead offset `start`
+fn is_placeholder_start(p: &CppParser, start: usize) -> bool {
+    match p.peek_token_at(start) {
+        CppTokenKind::AutoKeyword => true,
+        CppTokenKind::DecltypeKeyword => {
+            p.peek_token_at(start + 1) == CppTokenKind::LeftParen
+                && p.peek_token_at(start + 2) == CppTokenKind::AutoKeyword
+                && p.peek_token_at(start + 3) == CppTokenKind::RightParen
+        }
+        _ => false,
+    }
+}
+
+fn parse_decltype_type(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::DecltypeType);
+    p.bump(); // Consume 'decltype'
+    expect_token(p, CppTokenKind::LeftParen)?;
+    if p.current_token() == CppTokenKind::AutoKeyword
+        && p.peek_next_token() == CppTokenKind::RightParen
+    {
+        p.bump(); // decltype(auto)
+    } else {
+        let in_template_args = p.set_in_template_args(false);
+        let result = parse_expr(p);
+        p.set_in_template_args(in_template_args);
+        result?;
+    }
+    expect_token(p, CppTokenKind::RightParen)?;
+    Ok(m.complete(p))
+}
+
+/// Parse an id-expression without wrapping it in a node:
+/// `a`, `::a::b`, `a<int>::b`, `a::template b<T>`, `~A`, `operator+`.
+///
+/// In a type context a `<` after a name always opens a template argument list,
+/// elsewhere it is only taken as one when the tokens after the matching `>` make sense.
+/// Returns whether the name carries template arguments.
+pub fn parse_name(p: &mut CppParser, in_type: bool) -> Result<bool, CppParseError> {
+    let mut has_template_args = false;
+    if p.current_token() == CppTokenKind::Scope {
+        p.bump();
+    }
+
+    loop {
+        if p.current_token() == CppTokenKind::TemplateKeyword {
+            p.bump();
+        }
+
+        match p.current_token() {
+            CppTokenKind::Identifier => {
+                p.bump();
+                if p.current_token() == CppTokenKind::Less
+                    && (in_type || is_template_argument_list_start(p))
+                {
+                    parse_template_argument_list(p)?;
+                    has_template_args = true;
+                }
+            }
+            CppTokenKind::Tilde => {
+                p.bump();
+                expect_token(p, CppTokenKind::Identifier)?;
+            }
+            CppTokenKind::OperatorKeyword => {
+                parse_operator_function_id(p)?;
+                if p.current_token() == CppTokenKind::Less && is_template_argument_list_start(p) {
+                    parse_template_argument_list(p)?;
+                    has_template_args = true;
+                }
+                break;
+            }
+            _ => {
+                return Err(CppParseError::syntax_error_from(
+                    &t!("expect name"),
+                    p.current_token_range(),
+                ));
+            }
+        }
+
+        if p.current_token() == CppTokenKind::Scope
+            && matches!(
+                p.peek_next_token(),
+                CppTokenKind::Identifier
+                    | CppTokenKind::TemplateKeyword
+                    | CppTokenKind::Tilde
+                    | CppTokenKind::OperatorKeyword
+            )
+        {
+            p.bump();
+        } else {
+            break;
+        }
+    }
+
+    Ok(has_template_args)
+}
+
+/// Parse `operator+`, `operator()`, `operator new[]`, `operator""_km`, `operator co_await` or a
+/// conversion `operator int*`
+fn parse_operator_function_id(p: &mut CppParser) -> Result<(), CppParseError> {
+    p.bump(); // Consume 'operator'
+    match p.current_token() {
+        CppTokenKind::NewKeyword | CppTokenKind::DeleteKeyword => {
+            p.bump();
+            if p.current_token() == CppTokenKind::LeftBracket
+                && p.peek_next_token() == CppTokenKind::RightBracket
+            {
+                p.bump();
+                p.bump();
+            }
+        }
+        CppTokenKind::LeftParen => {
+            p.bump();
+            expect_token(p, CppTokenKind::RightParen)?;
+        }
+        CppTokenKind::LeftBracket => {
+            p.bump();
+            expect_token(p, CppTokenKind::RightBracket)?;
+        }
+        CppTokenKind::StringLiteral => {
+            p.bump();
+            expect_token(p, CppTokenKind::Identifier)?;
+        }
+        CppTokenKind::UserDefinedLiteral => {
+            p.bump();
+        }
+        CppTokenKind::CoAwaitKeyword => {
+            let range = p.current_token_range();
+            p.check_feature(
+                CppFeature::Coroutines,
+                &t!("coroutines require C++20"),
+                range,
+            );
+            p.bump();
+        }
+        kind if is_overloadable_operator(kind) => {
+            p.bump();
+        }
+        _ => {
+            parse_type_specifier(p)?;
+            while matches!(
+                p.current_token(),
+                CppTokenKind::Star | CppTokenKind::Ampersand | CppTokenKind::LogicalAnd
+            ) {
+                p.bump();
+                while is_cv_qualifier(p.current_token()) {
+                    p.bump();
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse template argument list: <T, int N, ...>
+pub fn parse_template_argument_list(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::TemplateArgumentList);
+
+    expect_token(p, CppTokenKind::Less)?; // Expect '<'
+
+    let in_template_args = p.set_in_template_args(true);
+    let result = parse_template_arguments(p);
+    p.set_in_template_args(in_template_args);
+    result?;
+
+    // The `>>` of nested lists closes both of them
+    p.split_greater_token();
+    expect_token(p, CppTokenKind::Greater)?; // Expect '>'
+
+    Ok(m.complete(p))
+}
+
+fn parse_template_arguments(p: &mut CppParser) -> Result<(), CppParseError> {
+    while p.current_token() != CppTokenKind::Greater && !p.is_eof() {
+        // Parse template argument (type or expression)
+        parse_template_argument(p)?;
+
+        if p.current_token() == CppTokenKind::Comma {
+            p.bump(); // Consume ','
+        } else {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse single template argument
+fn parse_template_argument(p: &mut CppParser) -> ParseResult {
+    let m = p.mark(CppSyntaxKind::TemplateArgument);
+
+    if is_type_start(p.current_token()) || is_type_template_argument(p) {
+        parse_type_id(p)?;
+    } else {
+        parse_assign_expr(p)?;
+    }
+
+    // Pack expansion: Ts...
+    if p.current_token() == CppTokenKind::Ellipsis {
+        p.bump();
+    }
+
+    Ok(m.complete(p))
+}
+
+/// A name in template argument position is taken as a type when it is directly
+/// followed by pointer/reference declarators and the end of the argument, or by a
+/// parameter list as in `std::function<Foo(int)>`
+fn is_type_template_argument(p: &CppParser) -> bool {
+    let Some(mut index) = scan_type_id(p, 0) else {
+        return false;
+    };
+    if is_parameter_list_start(p, index) {
+        index = scan_balanced_parens(p, index).unwrap_or(index);
+    } else {
+        index = scan_abstract_declarator(p, index);
+    }
+
+    matches!(
+        p.peek_token_at(index),
+        CppTokenKind::Comma
+            | CppTokenKind::Greater
+            | CppTokenKind::RightShift
+            | CppTokenKind::Ellipsis
+    )
+}
+
+/// `(` at lookahead offset `start` opens a parameter list when its content reads as parameter
+/// declarations, otherwise it is a direct initializer: `Foo f(int)` vs `Foo f(1)`
+pub fn is_parameter_list_start(p: &CppParser, start: usize) -> bool {
+    if p.peek_token_at(start) != CppTokenKind::LeftParen {
+        return false;
+    }
+
+    // Attributes of the first parameter: f([[maybe_unused]] int x)
+    let first = skip_attribute_lists(p, start + 1);
+    match p.peek_token_at(first) {
+        CppTokenKind::RightParen | CppTokenKind::Ellipsis => true,
+        kind if is_type_start(kind) || is_decl_specifier(kind) => true,
+        // A type-id followed by a name, or standing alone as an unnamed parameter: what can be
+        // a declaration is one, so `Foo g(Bar);` declares a function
+        CppTokenKind::Identifier | CppTokenKind::Scope => match scan_type_id(p, first) {
+            Some(index) => matches!(
+                p.peek_token_at(scan_abstract_declarator(p, index)),
+                CppTokenKind::Identifier
+                    | CppTokenKind::Ellipsis
+                    | CppTokenKind::RightParen
+                    | CppTokenKind::Comma
+            ),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// Skip an abstract declarator with a nested part such as `(*)(int)` or `(&)[4]` at lookahead
+/// offset `start`, returns `start` when there is none
+pub fn scan_abstract_declarator(p: &CppParser, start: usize) -> usize {
+    if !matches!(
+        p.peek_token_at(start + 1),
+        CppTokenKind::Star | CppTokenKind::Ampersand | CppTokenKind::LogicalAnd
+    ) {
+        return start;
+    }
+    let Some(mut index) = scan_balanced_parens(p, start) else {
+        return start;
+    };
+
+    loop {
+        match p.peek_token_at(index) {
+            CppTokenKind::LeftParen => match scan_balanced_parens(p, index) {
+                Some(end) => index = end,
+                None => return start,
+            },
+            CppTokenKind::LeftBracket => {
+                while p.peek_token_at(index) != CppTokenKind::RightBracket {
+                    if matches!(
+                        p.peek_token_at(index),
+                        CppTokenKind::Semicolon | CppTokenKind::Eof | CppTokenKind::None
+                    ) {
+                        return start;
+                    }
+                    index += 1;
+                }
+                index += 1;
+            }
+            _ => return index,
+        }
+    }
+}
+
+/// Skip a type-id such as `const std::vector<int>*` starting at lookahead offset `start`,
+/// returns the offset of the first token after it
+pub fn scan_type_id(p: &CppParser, start: usize) -> Option<usize> {
+    let mut index = start;
+    while is_cv_qualifier(p.peek_token_at(index)) {
+        index += 1;
+    }
+
+    match p.peek_token_at(index) {
+        kind if is_builtin_type_keyword(kind) => {
+            while is_builtin_type_keyword(p.peek_token_at(index))
+                || is_cv_qualifier(p.peek_token_at(index))
+            {
+                index += 1;
+            }
+        }
+        CppTokenKind::AutoKeyword => index += 1,
+        CppTokenKind::DecltypeKeyword => {
+            index = scan_balanced_parens(p, index + 1)?;
+        }
+        CppTokenKind::TypenameKeyword
+        | CppTokenKind::ClassKeyword
+        | CppTokenKind::StructKeyword
+        | CppTokenKind::UnionKeyword
+        | CppTokenKind::EnumKeyword => {
+            index = scan_name(p, index + 1)?;
+        }
+        CppTokenKind::Identifier | CppTokenKind::Scope => {
+            index = scan_name(p, index)?;
+            if is_placeholder_start(p, index) {
+                index = match p.peek_token_at(index) {
+                    CppTokenKind::AutoKeyword => index + 1,
+                    _ => index + 4,
+                };
+            }
+        }
+        _ => return None,
+    }
+
+    while matches!(
+        p.peek_token_at(index),
+        CppTokenKind::Star
+            | CppTokenKind::Ampersand
+            | CppTokenKind::LogicalAnd
+            | CppTokenKind::ConstKeyword
+            | CppTokenKind::VolatileKeyword
+    ) {
+        index += 1;
+    }
+
+    Some(index)
+}
+
+/// Skip a parenthesized group starting at the `(` at lookahead offset `start`,
+/// returns the offset of the first token after the matching `)`
+pub fn scan_balanced_parens(p: &CppParser, start: usize) -> Option<usize> {
+    if p.peek_token_at(start) != CppTokenKind::LeftParen {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut index = start;
+    loop {
+        match p.peek_token_at(index) {
+            CppTokenKind::LeftParen => depth += 1,
+            CppTokenKind::RightParen => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            CppTokenKind::Semicolon | CppTokenKind::Eof | CppTokenKind::None => return None,
+            _ => {}
+        }
+        index += 1;
+    }
+}
+
+/// In an expression `a < b` is a comparison unless the tokens after the matching `>`
+/// can only follow a template-id, e.g. `f<int>(x)` or `std::vector<int>::size_type`
+pub fn is_template_argument_list_start(p: &CppParser) -> bool {
+    let Some(end) = scan_template_argument_list(p, 0) else {
+        return false;
+    };
+
+    matches!(
+        p.peek_token_at(end),
+        CppTokenKind::LeftParen
+            | CppTokenKind::RightParen
+            | CppTokenKind::Scope
+            | CppTokenKind::LeftBrace
+            | CppTokenKind::RightBrace
+            | CppTokenKind::RightBracket
+            | CppTokenKind::Comma
+            | CppTokenKind::Semicolon
+            | CppTokenKind::Colon
+            | CppTokenKind::Greater
+            | CppTokenKind::RightShift
+            | CppTokenKind::Dot
+            | CppTokenKind::Arrow
+            | CppTokenKind::Ellipsis
+            | CppTokenKind::Equal
+            | CppTokenKind::NotEqual
+            | CppTokenKind::LogicalAnd
+            | CppTokenKind::LogicalOr
+            | CppTokenKind::Question
+            | CppTokenKind::Eof
+    )
+}
+
+/// Skip the template argument list opened by the `<` at lookahead offset `start`, returns the
+/// offset of the first token after it. When the list ends with the first half of a `>>`
+/// inside an enclosing argument list, the offset of that `>>` is returned instead.
+pub fn scan_template_argument_list(p: &CppParser, start: usize) -> Option<usize> {
+    let mut angle_depth = 0;
+    let mut paren_depth = 0;
+    let mut index = start;
+    loop {
+        match p.peek_token_at(index) {
+            CppTokenKind::Less if paren_depth == 0 => angle_depth += 1,
+            CppTokenKind::Greater if paren_depth == 0 => {
+                angle_depth -= 1;
+                if angle_depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            // Outside of template arguments a lone `>>` is a shift: `a < b >> c`
+            CppTokenKind::RightShift
+                if paren_depth == 0 && (angle_depth > 1 || p.is_in_template_args()) =>
+            {
+                angle_depth -= 2;
+                if angle_depth == 0 {
+                    return Some(index + 1);
+                } else if angle_depth < 0 {
+                    return Some(index);
+                }
+            }
+            CppTokenKind::LeftParen | CppTokenKind::LeftBracket => paren_depth += 1,
+            CppTokenKind::RightParen | CppTokenKind::RightBracket => {
+                if paren_depth == 0 {
+                    return None;
+                }
+                paren_depth -= 1;
+            }
+            CppTokenKind::Semicolon
+            | CppTokenKind::LeftBrace
+            | CppTokenKind::RightBrace
+            | CppTokenKind::Eof
+            | CppTokenKind::None => return None,
+            _ => {}
+        }
+        index += 1;
+    }
+}
+
+/// Skip a qualified name such as `::a::b<int>::c` starting at lookahead offset `start`,
+/// returns the offset of the first token after it
+pub fn scan_name(p: &CppParser, start: usize) -> Option<usize> {
+    let mut index = start;
+    if p.peek_token_at(index) == CppTokenKind::Scope {
+        index += 1;
+    }
+
+    loop {
+        if p.peek_token_at(index) == CppTokenKind::TemplateKeyword {
+            index += 1;
+        }
+        if p.peek_token_at(index) != CppTokenKind::Identifier {
+            return None;
+        }
+        index += 1;
+        if p.peek_token_at(index) == CppTokenKind::Less {
+            index = scan_template_argument_list(p, index)?;
+        }
+        if p.peek_token_at(index) == CppTokenKind::Scope
+            && matches!(
+                p.peek_token_at(index + 1),
+                CppTokenKind::Identifier | CppTokenKind::TemplateKeyword
+            )
+        {
+            index += 1;
+        } else {
+            return Some(index);
+        }
+    }
+}
+
+/// Tokens that can only start a type, never an expression or a declarator
+pub fn is_type_start(kind: CppTokenKind) -> bool {
+    is_builtin_type_keyword(kind)
+        || is_cv_qualifier(kind)
+        || matches!(
+            kind,
+            CppTokenKind::AutoKeyword
+                | CppTokenKind::DecltypeKeyword
+                | CppTokenKind::TypenameKeyword
+                | CppTokenKind::ClassKeyword
+                | CppTokenKind::StructKeyword
+                | CppTokenKind::UnionKeyword
+                | CppTokenKind::EnumKeyword
+        )
+}
+
+pub fn is_builtin_type_keyword(kind: CppTokenKind) -> bool {
+    matches!(
+        kind,
+        CppTokenKind::VoidKeyword
+            | CppTokenKind::BoolKeyword
+            | CppTokenKind::CharKeyword
+            | CppTokenKind::WcharKeyword
+            | CppTokenKind::Char8Keyword
+            | CppTokenKind::Char16Keyword
+            | CppTokenKind::Char32Keyword
+            | CppTokenKind::ShortKeyword
+            | CppTokenKind::IntKeyword
+            | CppTokenKind::LongKeyword
+            | CppTokenKind::FloatKeyword
+            | CppTokenKind::DoubleKeyword
+            | CppTokenKind::SignedKeyword
+            | CppTokenKind::UnsignedKeyword
+    )
+}
+
+pub fn is_cv_qualifier(kind: CppTokenKind) -> bool {
+    matches!(
+        kind,
+        CppTokenKind::ConstKeyword | CppTokenKind::VolatileKeyword
+    )
+}
+
+fn is_overloadable_operator(kind: CppTokenKind) -> bool {
+    matches!(
+        kind,
+        CppTokenKind::Plus
+            | CppTokenKind::Minus
+            | CppTokenKind::Star
+            | CppTokenKind::Slash
+            | CppTokenKind::Percent
+            | CppTokenKind::Caret
+            | CppTokenKind::Ampersand
+            | CppTokenKind::Pipe
+            | CppTokenKind::Tilde
+            | CppTokenKind::LogicalNot
+            | CppTokenKind::Assign
+            | CppTokenKind::Less
+            | CppTokenKind::Greater
+            | CppTokenKind::PlusAssign
+            | CppTokenKind::MinusAssign
+            | CppTokenKind::StarAssign
+            | CppTokenKind::SlashAssign
+            | CppTokenKind::PercentAssign
+            | CppTokenKind::CaretAssign
+            | CppTokenKind::AmpersandAssign
+            | CppTokenKind::PipeAssign
+            | CppTokenKind::LeftShift
+            | CppTokenKind::RightShift
+            | CppTokenKind::LeftShiftAssign
+            | CppTokenKind::RightShiftAssign
+            | CppTokenKind::Equal
+            | CppTokenKind::NotEqual
+            | CppTokenKind::LessEqual
+            | CppTokenKind::GreaterEqual
+            | CppTokenKind::Spaceship
+            | CppTokenKind::LogicalAnd
+            | CppTokenKind::LogicalOr
+            | CppTokenKind::PlusPlus
+            | CppTokenKind::MinusMinus
+            | CppTokenKind::Comma
+            | CppTokenKind::ArrowStar
+            | CppTokenKind::Arrow
+    )
+}
@@ -0,0 +1,171 @@
+mod test;
+
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    parser::{CppDirectiveParser, MarkerEventContainer},
+    parser_error::CppParseError,
+};
+
+/// Parse a preprocessor directive line, starting at its `#`
+pub fn parse_directive(p: &mut CppDirectiveParser) {
+    let mut m = p.mark(CppSyntaxKind::UnknownDirective);
+    p.bump(); // '#'
+
+    let kind = directive_kind(p);
+    m.set_kind(p, kind);
+    match kind {
+        CppSyntaxKind::IncludeDirective => parse_include_directive(p),
+        CppSyntaxKind::DefineDirective => parse_define_directive(p),
+        CppSyntaxKind::UndefDirective
+        | CppSyntaxKind::IfdefDirective
+        | CppSyntaxKind::IfndefDirective => {
+            p.bump();
+            expect_macro_name(p);
+        }
+        CppSyntaxKind::IfDirective | CppSyntaxKind::ElifDirective => parse_condition(p),
+        // GNU line marker: # 42 "file.cpp"
+        CppSyntaxKind::LineDirective if p.current_token() == CppTokenKind::IntegerLiteral => {}
+        CppSyntaxKind::UnknownDirective if p.current_token() == CppTokenKind::Eof => {}
+        _ => p.bump(),
+    }
+
+    // The rest of the line belongs to the directive
+    while p.current_token() != CppTokenKind::Eof {
+        p.bump();
+    }
+
+    m.complete(p);
+}
+
+fn directive_kind(p: &CppDirectiveParser) -> CppSyntaxKind {
+    if p.current_token() == CppTokenKind::IntegerLiteral {
+        return CppSyntaxKind::LineDirective;
+    }
+
+    match p.current_token_text() {
+        "include" | "include_next" | "import" => CppSyntaxKind::IncludeDirective,
+        "define" => CppSyntaxKind::DefineDirective,
+        "undef" => CppSyntaxKind::UndefDirective,
+        "ifdef" => CppSyntaxKind::IfdefDirective,
+        "ifndef" => CppSyntaxKind::IfndefDirective,
+        "if" => CppSyntaxKind::IfDirective,
+        "elif" | "elifdef" | "elifndef" => CppSyntaxKind::ElifDirective,
+        "else" => CppSyntaxKind::ElseDirective,
+        "endif" => CppSyntaxKind::EndifDirective,
+        "pragma" => CppSyntaxKind::PragmaDirective,
+        "error" => CppSyntaxKind::ErrorDirective,
+        "warning" => CppSyntaxKind::WarningDirective,
+        "line" => CppSyntaxKind::LineDirective,
+        _ => CppSyntaxKind::UnknownDirective,
+    }
+}
+
+fn parse_include_directive(p: &mut CppDirectiveParser) {
+    p.bump();
+    // Anything but a header name is a computed include made of macros
+    if p.current_token() == CppTokenKind::Eof {
+        p.push_error(CppParseError::syntax_error_from(
+            &t!("expected header name"),
+            p.current_token_range(),
+        ));
+    }
+}
+
+fn parse_define_directive(p: &mut CppDirectiveParser) {
+    p.bump();
+    if !expect_macro_name(p) {
+        return;
+    }
+
+    // Only a `(` directly after the name makes a function-like macro
+    if p.current_token() == CppTokenKind::LeftParen && p.is_adjacent_to_previous() {
+        parse_macro_parameter_list(p);
+    }
+
+    if p.current_token() != CppTokenKind::Eof {
+        let m = p.mark(CppSyntaxKind::MacroBody);
+        while p.current_token() != CppTokenKind::Eof {
+            p.bump();
+        }
+        m.complete(p);
+    }
+}
+
+fn parse_macro_parameter_list(p: &mut CppDirectiveParser) {
+    let m = p.mark(CppSyntaxKind::MacroParameterList);
+    p.bump(); // '('
+
+    if p.current_token() != CppTokenKind::RightParen {
+        loop {
+            let param = p.mark(CppSyntaxKind::MacroParameter);
+            if p.current_token() == CppTokenKind::Ellipsis {
+                p.bump();
+            } else if is_macro_name(p) {
+                p.bump();
+                // GNU named variadic parameter: args...
+                if p.current_token() == CppTokenKind::Ellipsis {
+                    p.bump();
+                }
+            } else {
+                param.undo(p);
+                p.push_error(CppParseError::syntax_error_from(
+                    &t!("expected macro parameter"),
+                    p.current_token_range(),
+                ));
+                break;
+            }
+            param.complete(p);
+
+            if p.current_token() != CppTokenKind::Comma {
+                break;
+            }
+            p.bump();
+        }
+    }
+
+    if p.current_token() == CppTokenKind::RightParen {
+        p.bump();
+    } else {
+        p.push_error(CppParseError::syntax_error_from(
+            &t!("expected ')' in macro parameter list"),
+            p.current_token_range(),
+        ));
+    }
+
+    m.complete(p);
+}
+
+fn parse_condition(p: &mut CppDirectiveParser) {
+    p.bump();
+    if p.current_token() == CppTokenKind::Eof {
+        p.push_error(CppParseError::syntax_error_from(
+            &t!("expected expression"),
+            p.current_token_range(),
+        ));
+    }
+}
+
+/// Macro names are identifiers, keywords included: `#define private public`
+fn is_macro_name(p: &CppDirectiveParser) -> bool {
+    p.current_token_text()
+        .starts_with(|ch: char| ch.is_alphabetic() || ch == '_')
+        && !matches!(
+            p.current_token(),
+            CppTokenKind::StringLiteral
+                | CppTokenKind::CharLiteral
+                | CppTokenKind::UserDefinedLiteral
+        )
+}
+
+fn expect_macro_name(p: &mut CppDirectiveParser) -> bool {
+    if is_macro_name(p) {
+        p.bump();
+        true
+    } else {
+        p.push_error(CppParseError::syntax_error_from(
+            &t!("expected macro name"),
+            p.current_token_range(),
+        ));
+        false
+    }
+}
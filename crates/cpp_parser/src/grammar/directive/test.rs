@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use crate::{CppParser, CppSyntaxKind, ParserConfig};
+
+    /// Collect every node below the translation unit as `(kind, text)` pairs in preorder
+    fn parse_nodes(code: &str) -> Vec<(CppSyntaxKind, String)> {
+        let tree = CppParser::parse(code, ParserConfig::default());
+        assert!(
+            tree.get_errors().is_empty(),
+            "unexpected errors for `{}`: {:?}",
+            code,
+            tree.get_errors()
+        );
+        assert_eq!(tree.get_red_root().text().to_string(), code);
+
+        tree.get_red_root()
+            .descendants()
+            .skip(1)
+            .map(|node| {
+                (
+                    node.kind().into(),
+                    node.text().to_string().trim().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn assert_nodes(code: &str, expected: &[(CppSyntaxKind, &str)]) {
+        let expected: Vec<(CppSyntaxKind, String)> = expected
+            .iter()
+            .map(|(kind, text)| (*kind, text.to_string()))
+            .collect();
+        assert_eq!(parse_nodes(code), expected, "while parsing `{}`", code);
+    }
+
+    fn parse_errors(code: &str) -> Vec<String> {
+        let tree = CppParser::parse(code, ParserConfig::default());
+        tree.get_errors()
+            .iter()
+            .map(|error| error.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_include() {
+        use CppSyntaxKind::*;
+        // Trailing comments are trivia and stay outside of the directive node
+        assert_nodes(
+            "#include <vector>\n  #  include \"config.h\" // local\n#include_next <limits.h>\n#include HEADER\n",
+            &[
+                (IncludeDirective, "#include <vector>"),
+                (IncludeDirective, "#  include \"config.h\""),
+                (IncludeDirective, "#include_next <limits.h>"),
+                (IncludeDirective, "#include HEADER"),
+            ],
+        );
+        assert_eq!(parse_errors("#include\n"), vec!["expected header name"]);
+    }
+
+    #[test]
+    fn test_define() {
+        use CppSyntaxKind::*;
+        assert_nodes(
+            "#define EMPTY\n#define PI 3.14\n#define NEG (-1)\n#define MAX(a, b) \\\n  ((a) > (b) ? (a) : (b))\n#define LOG(fmt, args...) printf(fmt, ##args)\n#define CALL(...) f(__VA_ARGS__)\n#define private public\n",
+            &[
+                (DefineDirective, "#define EMPTY"),
+                (DefineDirective, "#define PI 3.14"),
+                (MacroBody, "3.14"),
+                (DefineDirective, "#define NEG (-1)"),
+                (MacroBody, "(-1)"),
+                (
+                    DefineDirective,
+                    "#define MAX(a, b) \\\n  ((a) > (b) ? (a) : (b))",
+                ),
+                (MacroParameterList, "(a, b)"),
+                (MacroParameter, "a"),
+                (MacroParameter, "b"),
+                (MacroBody, "((a) > (b) ? (a) : (b))"),
+                (
+                    DefineDirective,
+                    "#define LOG(fmt, args...) printf(fmt, ##args)",
+                ),
+                (MacroParameterList, "(fmt, args...)"),
+                (MacroParameter, "fmt"),
+                (MacroParameter, "args..."),
+                (MacroBody, "printf(fmt, ##args)"),
+                (DefineDirective, "#define CALL(...) f(__VA_ARGS__)"),
+                (MacroParameterList, "(...)"),
+                (MacroParameter, "..."),
+                (MacroBody, "f(__VA_ARGS__)"),
+                (DefineDirective, "#define private public"),
+                (MacroBody, "public"),
+            ],
+        );
+
+        assert_eq!(parse_errors("#define\n"), vec!["expected macro name"]);
+        assert_eq!(parse_errors("#define 1 2\n"), vec!["expected macro name"]);
+        assert_eq!(
+            parse_errors("#define F(a, 1) a\n"),
+            vec![
+                "expected macro parameter",
+                "expected ')' in macro parameter list"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conditionals() {
+        use CppSyntaxKind::*;
+        assert_nodes(
+            "#ifndef GUARD_H\n#define GUARD_H\n#if defined(X) && __has_include(<optional>)\n#elif VERSION > 2\n#elifdef Y\n#else\n#endif\n#endif // GUARD_H\n",
+            &[
+                (IfndefDirective, "#ifndef GUARD_H"),
+                (DefineDirective, "#define GUARD_H"),
+                (IfDirective, "#if defined(X) && __has_include(<optional>)"),
+                (ElifDirective, "#elif VERSION > 2"),
+                (ElifDirective, "#elifdef Y"),
+                (ElseDirective, "#else"),
+                (EndifDirective, "#endif"),
+                (EndifDirective, "#endif"),
+            ],
+        );
+        assert_eq!(parse_errors("#if\n#endif\n"), vec!["expected expression"]);
+        assert_eq!(
+            parse_errors("#ifdef\n#endif\n"),
+            vec!["expected macro name"]
+        );
+    }
+
+    #[test]
+    fn test_other_directives() {
+        use CppSyntaxKind::*;
+        assert_nodes(
+            "#pragma once\n#error can't build \"this\"\n#warning old\n#line 10 \"a.cpp\"\n# 12 \"b.cpp\" 2\n#ident \"v1\"\n#\n#undef X\n",
+            &[
+                (PragmaDirective, "#pragma once"),
+                (ErrorDirective, "#error can't build \"this\""),
+                (WarningDirective, "#warning old"),
+                (LineDirective, "#line 10 \"a.cpp\""),
+                (LineDirective, "# 12 \"b.cpp\" 2"),
+                (UnknownDirective, "#ident \"v1\""),
+                (UnknownDirective, "#"),
+                (UndefDirective, "#undef X"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_directives_between_tokens() {
+        use CppSyntaxKind::*;
+        assert_nodes(
+            "class A {\n#pragma pack(1)\n  int a;\n};\nint x = 1 +\n#ifndef Z\n  2 +\n#endif\n  3;\n",
+            &[
+                (ClassDef, "class A {\n#pragma pack(1)\n  int a;\n};"),
+                (CompoundStat, "{\n#pragma pack(1)\n  int a;\n}"),
+                (PragmaDirective, "#pragma pack(1)"),
+                (FieldDecl, "int a;"),
+                (BuiltinType, "int"),
+                (VariableDecl, "int x = 1 +\n#ifndef Z\n  2 +\n#endif\n  3;"),
+                (BuiltinType, "int"),
+                (BinaryExpr, "1 +\n#ifndef Z\n  2 +\n#endif\n  3"),
+                (BinaryExpr, "1 +\n#ifndef Z\n  2"),
+                (LiteralExpr, "1"),
+                (IfndefDirective, "#ifndef Z"),
+                (LiteralExpr, "2"),
+                (EndifDirective, "#endif"),
+                (LiteralExpr, "3"),
+            ],
+        );
+
+        // A `#` that does not start a line is not a directive
+        assert!(!parse_errors("int a = 1 # 2;\n").is_empty());
+    }
+
+    #[test]
+    fn test_inactive_branches() {
+        use CppSyntaxKind::*;
+        let code = "#ifdef _WIN32\nvoid init(HANDLE h);\n#else\nvoid init(int fd);\n#endif\n#if 0\nit's not code {\n#endif\n";
+        assert_nodes(
+            code,
+            &[
+                (IfdefDirective, "#ifdef _WIN32"),
+                (ElseDirective, "#else"),
+                (FunctionDecl, "void init(int fd);"),
+                (BuiltinType, "void"),
+                (ParameterList, "(int fd)"),
+                (Parameter, "int fd"),
+                (BuiltinType, "int"),
+                (EndifDirective, "#endif"),
+                (IfDirective, "#if 0"),
+                (EndifDirective, "#endif"),
+            ],
+        );
+
+        let tree = CppParser::parse(code, ParserConfig::default());
+        let inactive: Vec<_> = tree
+            .get_inactive_ranges()
+            .iter()
+            .map(|range| &code[usize::from(range.start())..usize::from(range.end())])
+            .collect();
+        assert_eq!(
+            inactive,
+            vec!["void init(HANDLE h);\n", "it's not code {\n"]
+        );
+
+        // Predefined macros pick the branch
+        let mut config = ParserConfig::default();
+        assert!(config.predefined_macros.define_text("_WIN32 1"));
+        let tree = CppParser::parse(code, config);
+        assert!(tree.get_errors().is_empty(), "{:?}", tree.get_errors());
+        let params: Vec<_> = tree
+            .get_red_root()
+            .descendants()
+            .filter(|node| CppSyntaxKind::from(node.kind()) == Parameter)
+            .map(|node| node.text().to_string())
+            .collect();
+        assert_eq!(params, vec!["HANDLE h"]);
+    }
+}
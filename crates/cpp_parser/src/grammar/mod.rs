@@ -2,8 +2,10 @@ use crate::{parser::CompleteMarker, parser_error::CppParseError};
 
 // mod doc;
 mod cpp;
+mod directive;
 
 type ParseResult = Result<CompleteMarker, CppParseError>;
 // pub use doc::parse_comment;
 pub use cpp::parse_cpp_unit;
+pub use directive::parse_directive;
  
\ No newline at end of file
@@ -70,33 +70,40 @@ impl CppLanguageLevel {
             
             // C++20 features
             Concepts | Modules | Coroutines | Ranges | 
-            ThreeWayComparison | DesignatedInitializers => *self >= CppLanguageLevel::Cpp20,
+            ThreeWayComparison | DesignatedInitializers | Consteval |
+            Constinit => *self >= CppLanguageLevel::Cpp20,
             
             // C++23 features
-            DeducingThis | IfConsteval | MultidimensionalSubscript => *self >= CppLanguageLevel::Cpp23,
+            DeducingThis | IfConsteval | MultidimensionalSubscript | SizeTLiterals => *self >= CppLanguageLevel::Cpp23,
             
             // Compiler-specific extensions
             GnuExtensions => matches!(self, CppLanguageLevel::GnuCpp),
             MsvcExtensions => matches!(self, CppLanguageLevel::MsvcCpp),
         }
     }
-    
+}
+
+impl Default for CppLanguageLevel {
     /// Get the default language level
-    pub fn default() -> Self {
+    fn default() -> Self {
         CppLanguageLevel::Cpp17
     }
-    
+}
+
+impl std::str::FromStr for CppLanguageLevel {
+    type Err = ();
+
     /// Parse language level from string
-    pub fn from_str(s: &str) -> Option<Self> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "c++11" | "11" => Some(CppLanguageLevel::Cpp11),
-            "c++14" | "14" => Some(CppLanguageLevel::Cpp14),
-            "c++17" | "17" => Some(CppLanguageLevel::Cpp17),
-            "c++20" | "20" => Some(CppLanguageLevel::Cpp20),
-            "c++23" | "23" => Some(CppLanguageLevel::Cpp23),
-            "gnu" | "gnu++" => Some(CppLanguageLevel::GnuCpp),
-            "msvc" | "visual c++" => Some(CppLanguageLevel::MsvcCpp),
-            _ => None,
+            "c++11" | "11" => Ok(CppLanguageLevel::Cpp11),
+            "c++14" | "14" => Ok(CppLanguageLevel::Cpp14),
+            "c++17" | "17" => Ok(CppLanguageLevel::Cpp17),
+            "c++20" | "20" => Ok(CppLanguageLevel::Cpp20),
+            "c++23" | "23" => Ok(CppLanguageLevel::Cpp23),
+            "gnu" | "gnu++" => Ok(CppLanguageLevel::GnuCpp),
+            "msvc" | "visual c++" => Ok(CppLanguageLevel::MsvcCpp),
+            _ => Err(()),
         }
     }
 }
@@ -142,11 +149,14 @@ pub enum CppFeature {
     Ranges,                      // ranges
     ThreeWayComparison,          // three-way comparison
     DesignatedInitializers,      // designated initializers
+    Consteval,                   // consteval immediate functions
+    Constinit,                   // constinit variables
     
     // C++23 features
     DeducingThis,                // deducing this
     IfConsteval,                 // if consteval
     MultidimensionalSubscript,   // multidimensional subscript operator
+    SizeTLiterals,               // z and uz integer literal suffixes
     
     // Compiler extensions
     GnuExtensions,               // GNU extensions
@@ -105,6 +105,10 @@ pub enum BinaryOperator {
     MemberAccess,
     /// Pointer member access: a->b
     PtrMemberAccess,
+    /// Pointer-to-member access: a.*b
+    MemberPointerAccess,
+    /// Pointer-to-member access through a pointer: a->*b
+    PtrMemberPointerAccess,
     /// Scope resolution: a::b
     Scope,
     /// Array subscript: a[b]
@@ -120,7 +124,7 @@ pub enum BinaryOperator {
 }
 
 /// Operator precedence and associativity for C++
-/// Lower number means lower precedence
+/// Lower number binds tighter, as in the C++ standard precedence table
 #[derive(Debug, Clone, Copy)]
 pub struct OperatorPrecedence {
     pub precedence: u8,
@@ -136,6 +140,8 @@ pub const BINARY_PRECEDENCE: &[(BinaryOperator, OperatorPrecedence)] = &[
     (BinaryOperator::PtrMemberAccess, OperatorPrecedence { precedence: 2, right_associative: false }),
     (BinaryOperator::Call, OperatorPrecedence { precedence: 2, right_associative: false }),
     (BinaryOperator::Subscript, OperatorPrecedence { precedence: 2, right_associative: false }),
+    (BinaryOperator::MemberPointerAccess, OperatorPrecedence { precedence: 4, right_associative: false }),
+    (BinaryOperator::PtrMemberPointerAccess, OperatorPrecedence { precedence: 4, right_associative: false }),
     (BinaryOperator::Mul, OperatorPrecedence { precedence: 5, right_associative: false }),
     (BinaryOperator::Div, OperatorPrecedence { precedence: 5, right_associative: false }),
     (BinaryOperator::Mod, OperatorPrecedence { precedence: 5, right_associative: false }),
@@ -143,18 +149,19 @@ pub const BINARY_PRECEDENCE: &[(BinaryOperator, OperatorPrecedence)] = &[
     (BinaryOperator::Sub, OperatorPrecedence { precedence: 6, right_associative: false }),
     (BinaryOperator::Shl, OperatorPrecedence { precedence: 7, right_associative: false }),
     (BinaryOperator::Shr, OperatorPrecedence { precedence: 7, right_associative: false }),
-    (BinaryOperator::Lt, OperatorPrecedence { precedence: 8, right_associative: false }),
-    (BinaryOperator::Le, OperatorPrecedence { precedence: 8, right_associative: false }),
-    (BinaryOperator::Gt, OperatorPrecedence { precedence: 8, right_associative: false }),
-    (BinaryOperator::Ge, OperatorPrecedence { precedence: 8, right_associative: false }),
-    (BinaryOperator::Eq, OperatorPrecedence { precedence: 9, right_associative: false }),
-    (BinaryOperator::Neq, OperatorPrecedence { precedence: 9, right_associative: false }),
-    (BinaryOperator::BitAnd, OperatorPrecedence { precedence: 10, right_associative: false }),
-    (BinaryOperator::BitXor, OperatorPrecedence { precedence: 11, right_associative: false }),
-    (BinaryOperator::BitOr, OperatorPrecedence { precedence: 12, right_associative: false }),
-    (BinaryOperator::LogicalAnd, OperatorPrecedence { precedence: 13, right_associative: false }),
-    (BinaryOperator::LogicalOr, OperatorPrecedence { precedence: 14, right_associative: false }),
-    (BinaryOperator::Conditional, OperatorPrecedence { precedence: 15, right_associative: true }),
+    (BinaryOperator::Spaceship, OperatorPrecedence { precedence: 8, right_associative: false }),
+    (BinaryOperator::Lt, OperatorPrecedence { precedence: 9, right_associative: false }),
+    (BinaryOperator::Le, OperatorPrecedence { precedence: 9, right_associative: false }),
+    (BinaryOperator::Gt, OperatorPrecedence { precedence: 9, right_associative: false }),
+    (BinaryOperator::Ge, OperatorPrecedence { precedence: 9, right_associative: false }),
+    (BinaryOperator::Eq, OperatorPrecedence { precedence: 10, right_associative: false }),
+    (BinaryOperator::Neq, OperatorPrecedence { precedence: 10, right_associative: false }),
+    (BinaryOperator::BitAnd, OperatorPrecedence { precedence: 11, right_associative: false }),
+    (BinaryOperator::BitXor, OperatorPrecedence { precedence: 12, right_associative: false }),
+    (BinaryOperator::BitOr, OperatorPrecedence { precedence: 13, right_associative: false }),
+    (BinaryOperator::LogicalAnd, OperatorPrecedence { precedence: 14, right_associative: false }),
+    (BinaryOperator::LogicalOr, OperatorPrecedence { precedence: 15, right_associative: false }),
+    (BinaryOperator::Conditional, OperatorPrecedence { precedence: 16, right_associative: true }),
     (BinaryOperator::Assign, OperatorPrecedence { precedence: 16, right_associative: true }),
     (BinaryOperator::AddAssign, OperatorPrecedence { precedence: 16, right_associative: true }),
     (BinaryOperator::SubAssign, OperatorPrecedence { precedence: 16, right_associative: true }),
@@ -167,5 +174,14 @@ pub const BINARY_PRECEDENCE: &[(BinaryOperator, OperatorPrecedence)] = &[
     (BinaryOperator::ShlAssign, OperatorPrecedence { precedence: 16, right_associative: true }),
     (BinaryOperator::ShrAssign, OperatorPrecedence { precedence: 16, right_associative: true }),
     (BinaryOperator::Comma, OperatorPrecedence { precedence: 17, right_associative: false }),
-    (BinaryOperator::Spaceship, OperatorPrecedence { precedence: 9, right_associative: false }),
 ];
+
+impl BinaryOperator {
+    /// Look up the precedence of the operator in `BINARY_PRECEDENCE`
+    pub fn get_precedence(&self) -> Option<OperatorPrecedence> {
+        BINARY_PRECEDENCE
+            .iter()
+            .find(|(op, _)| op == self)
+            .map(|(_, precedence)| *precedence)
+    }
+}
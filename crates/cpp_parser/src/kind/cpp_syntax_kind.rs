@@ -84,10 +84,22 @@ pub enum CppSyntaxKind {
     /// e.g.: int x, y = 5;
     VariableDecl,
 
+    /// Names of a structured binding declaration (C++17)
+    /// e.g.: auto& [key, value] = pair;
+    StructuredBinding,
+
     /// Field declaration - class/struct member variable
     /// e.g.: class A { int member; };
     FieldDecl,
 
+    /// Friend declaration - class member granting access
+    /// e.g.: friend class B; friend bool operator==(const A&, const A&);
+    FriendDecl,
+
+    /// Asm declaration - inline assembly
+    /// e.g.: asm("nop");
+    AsmDecl,
+
     /// Template declaration
     /// e.g.: template<typename T> class MyClass;
     TemplateDecl,
@@ -96,9 +108,45 @@ pub enum CppSyntaxKind {
     /// e.g.: template<> class MyClass<int> { ... };
     TemplateSpecialization,
 
+    /// Explicit template instantiation
+    /// e.g.: template class MyClass<int>; extern template void f<int>(int);
+    ExplicitInstantiation,
+
     /// Concept declaration (C++20)
     /// e.g.: template<typename T> concept Copyable = ...;
     ConceptDecl,
+
+    /// Requires clause - constraint on a template or function (C++20)
+    /// e.g.: template<typename T> requires Copyable<T> void f(T);
+    RequiresClause,
+
+    /// Module declaration - names the module of a module unit (C++20)
+    /// e.g.: export module a.b:part;
+    ModuleDecl,
+
+    /// Global module fragment - the preprocessor-only part before the module declaration (C++20)
+    /// e.g.: module;
+    GlobalModuleFragment,
+
+    /// Private module fragment - the part of a primary module interface no importer sees (C++20)
+    /// e.g.: module :private;
+    PrivateModuleFragment,
+
+    /// Import declaration - imports a module, a partition or a header unit (C++20)
+    /// e.g.: import std; import :part; import <vector>;
+    ImportDecl,
+
+    /// Export declaration - exports one declaration or a block of them (C++20)
+    /// e.g.: export int f(); export { int g(); }
+    ExportDecl,
+
+    /// Module name - dotted name of a module (C++20)
+    /// e.g.: a.b in export module a.b;
+    ModuleName,
+
+    /// Module partition - partition after the module name (C++20)
+    /// e.g.: :part in export module a.b:part;
+    ModulePartition,
     // ========== Statements ==========
     /// Compound statement - block of statements in braces
     /// e.g.: { statement1; statement2; }
@@ -151,6 +199,10 @@ pub enum CppSyntaxKind {
     /// e.g.: for (auto& item : container) statement
     RangeForStat,
 
+    /// Init-statement of an if, switch (C++17) or range-based for (C++20)
+    /// e.g.: if (auto it = m.find(k); it != m.end()) statement
+    InitStat,
+
     /// break statement - exit loop or switch
     BreakStat,
 
@@ -161,6 +213,10 @@ pub enum CppSyntaxKind {
     /// e.g.: return expression;
     ReturnStat,
 
+    /// co_return statement - return from a coroutine (C++20)
+    /// e.g.: co_return expression;
+    CoReturnStat,
+
     /// goto statement - unconditional jump
     /// e.g.: goto label;
     GotoStat,
@@ -237,6 +293,26 @@ pub enum CppSyntaxKind {
     /// e.g.: typeid(int), typeid(expression)
     TypeidExpr,
 
+    /// alignof expression - get alignment of type (C++11)
+    /// e.g.: alignof(int)
+    AlignofExpr,
+
+    /// noexcept operator - check whether an expression can throw (C++11)
+    /// e.g.: noexcept(f())
+    NoexceptExpr,
+
+    /// throw expression - throw exception inside an expression
+    /// e.g.: ok ? value : throw std::runtime_error("bad")
+    ThrowExpr,
+
+    /// co_await expression - suspend a coroutine until the awaited value is ready (C++20)
+    /// e.g.: co_await socket.read()
+    CoAwaitExpr,
+
+    /// co_yield expression - suspend a coroutine and hand out a value (C++20)
+    /// e.g.: co_yield value
+    CoYieldExpr,
+
     /// new expression - dynamic memory allocation
     /// e.g.: new int, new int[10], new MyClass()
     NewExpr,
@@ -253,6 +329,34 @@ pub enum CppSyntaxKind {
     /// e.g.: [capture](params) -> return_type { body }
     LambdaExpr,
 
+    /// Lambda capture list
+    /// e.g.: [=, &x, y = 1]
+    CaptureList,
+
+    /// Single lambda capture
+    /// e.g.: &x, this, *this, y = std::move(v), ...args
+    Capture,
+
+    /// Requires expression (C++20)
+    /// e.g.: requires (T a) { a + a; typename T::type; }
+    RequiresExpr,
+
+    /// Simple requirement - an expression that must be valid
+    /// e.g.: a + a;
+    SimpleRequirement,
+
+    /// Type requirement - a type that must be valid
+    /// e.g.: typename T::value_type;
+    TypeRequirement,
+
+    /// Compound requirement - an expression with noexcept and return type constraints
+    /// e.g.: { a + a } noexcept -> std::same_as<T>;
+    CompoundRequirement,
+
+    /// Nested requirement - a constraint checked inside a requires expression
+    /// e.g.: requires Copyable<T>;
+    NestedRequirement,
+
     /// Initializer list expression (C++11)
     /// e.g.: {1, 2, 3}, {.x = 1, .y = 2}
     InitListExpr,
@@ -293,6 +397,10 @@ pub enum CppSyntaxKind {
     /// e.g.: const int, volatile double
     QualifiedType,
 
+    /// Named type - class, enum or typedef name, optionally qualified
+    /// e.g.: MyClass, std::string, struct Point
+    NamedType,
+
     /// Template type - template instantiation
     /// e.g.: std::vector<int>, MyTemplate<T>
     TemplateType,
@@ -376,6 +484,22 @@ pub enum CppSyntaxKind {
     /// #line directive - line number control
     /// e.g.: #line 100 "file.cpp"
     LineDirective,
+
+    /// Any other directive, including a lone #
+    /// e.g.: #ident "v1"
+    UnknownDirective,
+
+    /// Macro parameter list - parameters of a function-like macro
+    /// e.g.: (a, b, ...)
+    MacroParameterList,
+
+    /// Macro parameter
+    /// e.g.: a, ..., args...
+    MacroParameter,
+
+    /// Macro body - replacement list of a macro definition
+    /// e.g.: ((a) > (b) ? (a) : (b))
+    MacroBody,
     // ========== Specifiers and Qualifiers ==========
     /// Access specifier - class member access control
     /// e.g.: public:
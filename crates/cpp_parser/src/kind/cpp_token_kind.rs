@@ -16,8 +16,12 @@ pub enum CppTokenKind {
     // ========== Keywords ==========
 
     // Basic keywords
+    /// asm - inline assembly declaration
+    AsmKeyword,
     /// auto - automatic type deduction (C++11)
     AutoKeyword,
+    /// bool - boolean type
+    BoolKeyword,
     /// break - break out of loop or switch
     BreakKeyword,
     /// case - switch branch
@@ -30,6 +34,8 @@ pub enum CppTokenKind {
     ClassKeyword,
     /// const - const qualifier
     ConstKeyword,
+    /// const_cast - add or remove cv-qualifiers
+    ConstCastKeyword,
     /// continue - continue to next loop iteration
     ContinueKeyword,
     /// default - default branch in switch
@@ -40,6 +46,8 @@ pub enum CppTokenKind {
     DoKeyword,
     /// double - double precision floating point
     DoubleKeyword,
+    /// dynamic_cast - checked polymorphic cast
+    DynamicCastKeyword,
     /// else - else branch
     ElseKeyword,
     /// enum - enumeration
@@ -52,6 +60,8 @@ pub enum CppTokenKind {
     FloatKeyword,
     /// for - for loop
     ForKeyword,
+    /// friend - friend declaration
+    FriendKeyword,
     /// goto - unconditional jump
     GotoKeyword,
     /// if - conditional statement
@@ -72,6 +82,10 @@ pub enum CppTokenKind {
     ProtectedKeyword,
     /// public - public access
     PublicKeyword,
+    /// register - storage hint, reserved but unused since C++17
+    RegisterKeyword,
+    /// reinterpret_cast - reinterpret the bits of a value
+    ReinterpretCastKeyword,
     /// return - function return
     ReturnKeyword,
     /// short - short integer type
@@ -82,6 +96,8 @@ pub enum CppTokenKind {
     SizeofKeyword,
     /// static - static storage
     StaticKeyword,
+    /// static_cast - checked conversion
+    StaticCastKeyword,
     /// struct - structure
     StructKeyword,
     /// switch - multi-branch selection
@@ -112,6 +128,8 @@ pub enum CppTokenKind {
     VoidKeyword,
     /// volatile - volatile qualifier
     VolatileKeyword,
+    /// wchar_t - wide character type
+    WcharKeyword,
     /// while - while loop
     WhileKeyword,
 
@@ -120,6 +138,10 @@ pub enum CppTokenKind {
     AlignasKeyword,
     /// alignof - alignment query (C++11)
     AlignofKeyword,
+    /// char16_t - UTF-16 character type (C++11)
+    Char16Keyword,
+    /// char32_t - UTF-32 character type (C++11)
+    Char32Keyword,
     /// constexpr - constant expression (C++11)
     ConstexprKeyword,
     /// decltype - type deduction (C++11)
@@ -144,8 +166,14 @@ pub enum CppTokenKind {
     ThreadLocalKeyword,
 
     // C++20 keywords
+    /// char8_t - UTF-8 character type (C++20)
+    Char8Keyword,
     /// concept - concept (C++20)
     ConceptKeyword,
+    /// consteval - immediate function (C++20)
+    ConstevalKeyword,
+    /// constinit - constant initialization (C++20)
+    ConstinitKeyword,
     /// requires - constraint (C++20)
     RequiresKeyword,
     /// co_await - coroutine await (C++20)
@@ -314,6 +342,11 @@ pub enum CppTokenKind {
     Hash,
     /// ## preprocessor token concatenation
     HashHash,
+    /// Header name of an #include directive or __has_include
+    /// e.g.: <vector>, "config.h"
+    HeaderName,
+    /// Free-form message of an #error or #warning directive
+    DirectiveText,
 
     // ========== Whitespace and Comments ==========
     /// Whitespace character (space, tab, etc.)
@@ -328,6 +361,9 @@ pub enum CppTokenKind {
     /// Block comment /* ... */
     BlockComment,
 
+    /// Lines skipped by conditional compilation, e.g. the body of `#if 0`
+    InactiveCode,
+
     // ========== Special Tokens ==========
     /// End of file
     Eof,
@@ -339,28 +375,47 @@ pub enum CppTokenKind {
     Error,
 }
 
+impl CppTokenKind {
+    /// Whitespace, newlines, comments and the code of inactive preprocessor branches
+    pub fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            CppTokenKind::LineComment
+                | CppTokenKind::BlockComment
+                | CppTokenKind::Newline
+                | CppTokenKind::Whitespace
+                | CppTokenKind::InactiveCode
+        )
+    }
+}
+
 impl fmt::Display for CppTokenKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             // 关键字
+            Self::AsmKeyword => write!(f, "asm"),
             Self::AutoKeyword => write!(f, "auto"),
+            Self::BoolKeyword => write!(f, "bool"),
             Self::BreakKeyword => write!(f, "break"),
             Self::CaseKeyword => write!(f, "case"),
             Self::CatchKeyword => write!(f, "catch"),
             Self::CharKeyword => write!(f, "char"),
             Self::ClassKeyword => write!(f, "class"),
             Self::ConstKeyword => write!(f, "const"),
+            Self::ConstCastKeyword => write!(f, "const_cast"),
             Self::ContinueKeyword => write!(f, "continue"),
             Self::DefaultKeyword => write!(f, "default"),
             Self::DeleteKeyword => write!(f, "delete"),
             Self::DoKeyword => write!(f, "do"),
             Self::DoubleKeyword => write!(f, "double"),
+            Self::DynamicCastKeyword => write!(f, "dynamic_cast"),
             Self::ElseKeyword => write!(f, "else"),
             Self::EnumKeyword => write!(f, "enum"),
             Self::ExternKeyword => write!(f, "extern"),
             Self::FalseKeyword => write!(f, "false"),
             Self::FloatKeyword => write!(f, "float"),
             Self::ForKeyword => write!(f, "for"),
+            Self::FriendKeyword => write!(f, "friend"),
             Self::GotoKeyword => write!(f, "goto"),
             Self::IfKeyword => write!(f, "if"),
             Self::InlineKeyword => write!(f, "inline"),
@@ -371,11 +426,14 @@ impl fmt::Display for CppTokenKind {
             Self::PrivateKeyword => write!(f, "private"),
             Self::ProtectedKeyword => write!(f, "protected"),
             Self::PublicKeyword => write!(f, "public"),
+            Self::RegisterKeyword => write!(f, "register"),
+            Self::ReinterpretCastKeyword => write!(f, "reinterpret_cast"),
             Self::ReturnKeyword => write!(f, "return"),
             Self::ShortKeyword => write!(f, "short"),
             Self::SignedKeyword => write!(f, "signed"),
             Self::SizeofKeyword => write!(f, "sizeof"),
             Self::StaticKeyword => write!(f, "static"),
+            Self::StaticCastKeyword => write!(f, "static_cast"),
             Self::StructKeyword => write!(f, "struct"),
             Self::SwitchKeyword => write!(f, "switch"),
             Self::ThisKeyword => write!(f, "this"),
@@ -391,11 +449,14 @@ impl fmt::Display for CppTokenKind {
             Self::VirtualKeyword => write!(f, "virtual"),
             Self::VoidKeyword => write!(f, "void"),
             Self::VolatileKeyword => write!(f, "volatile"),
+            Self::WcharKeyword => write!(f, "wchar_t"),
             Self::WhileKeyword => write!(f, "while"),
 
             // C++11及后续标准关键字
             Self::AlignasKeyword => write!(f, "alignas"),
             Self::AlignofKeyword => write!(f, "alignof"),
+            Self::Char16Keyword => write!(f, "char16_t"),
+            Self::Char32Keyword => write!(f, "char32_t"),
             Self::ConstexprKeyword => write!(f, "constexpr"),
             Self::DecltypeKeyword => write!(f, "decltype"),
             Self::ExplicitKeyword => write!(f, "explicit"),
@@ -409,7 +470,10 @@ impl fmt::Display for CppTokenKind {
             Self::ThreadLocalKeyword => write!(f, "thread_local"),
 
             // C++20关键字
+            Self::Char8Keyword => write!(f, "char8_t"),
             Self::ConceptKeyword => write!(f, "concept"),
+            Self::ConstevalKeyword => write!(f, "consteval"),
+            Self::ConstinitKeyword => write!(f, "constinit"),
             Self::RequiresKeyword => write!(f, "requires"),
             Self::CoAwaitKeyword => write!(f, "co_await"),
             Self::CoReturnKeyword => write!(f, "co_return"),
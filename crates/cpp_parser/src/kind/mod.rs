@@ -3,8 +3,10 @@ mod cpp_operator_kind;
 mod cpp_syntax_kind;
 mod cpp_token_kind;
 
-pub use cpp_language_level::CppLanguageLevel;
-pub use cpp_operator_kind::{BinaryOperator, UnaryOperator, UNARY_PRECEDENCE};
+pub use cpp_language_level::{CppFeature, CppLanguageLevel};
+pub use cpp_operator_kind::{
+    BinaryOperator, OperatorPrecedence, UnaryOperator, BINARY_PRECEDENCE, UNARY_PRECEDENCE,
+};
 pub use cpp_syntax_kind::CppSyntaxKind;
 pub use cpp_token_kind::CppTokenKind;
 
@@ -63,9 +65,9 @@ impl CppKind {
 
     pub fn from_raw(raw: u16) -> CppKind {
         if raw & 0x8000 != 0 {
-            CppKind::Syntax(unsafe { std::mem::transmute(raw & 0x7FFF) })
+            CppKind::Syntax(unsafe { std::mem::transmute::<u16, CppSyntaxKind>(raw & 0x7FFF) })
         } else {
-            CppKind::Token(unsafe { std::mem::transmute(raw) })
+            CppKind::Token(unsafe { std::mem::transmute::<u16, CppTokenKind>(raw) })
         }
     }
 }
@@ -94,3 +96,70 @@ impl From<BinaryOperator> for CppOpKind {
         CppOpKind::Binary(op)
     }
 }
+
+impl CppOpKind {
+    /// Map a token in prefix position to its unary operator
+    pub fn to_unary_operator(kind: CppTokenKind) -> UnaryOperator {
+        match kind {
+            CppTokenKind::LogicalNot => UnaryOperator::Not,
+            CppTokenKind::Tilde => UnaryOperator::BitNot,
+            CppTokenKind::Plus => UnaryOperator::Plus,
+            CppTokenKind::Minus => UnaryOperator::Minus,
+            CppTokenKind::PlusPlus => UnaryOperator::PreIncrement,
+            CppTokenKind::MinusMinus => UnaryOperator::PreDecrement,
+            CppTokenKind::Star => UnaryOperator::Deref,
+            CppTokenKind::Ampersand => UnaryOperator::AddressOf,
+            _ => UnaryOperator::Nop,
+        }
+    }
+
+    /// Map a token in postfix position to its unary operator
+    pub fn to_postfix_operator(kind: CppTokenKind) -> UnaryOperator {
+        match kind {
+            CppTokenKind::PlusPlus => UnaryOperator::PostIncrement,
+            CppTokenKind::MinusMinus => UnaryOperator::PostDecrement,
+            _ => UnaryOperator::Nop,
+        }
+    }
+
+    /// Map a token in infix position to its binary operator
+    pub fn to_binary_operator(kind: CppTokenKind) -> BinaryOperator {
+        match kind {
+            CppTokenKind::Plus => BinaryOperator::Add,
+            CppTokenKind::Minus => BinaryOperator::Sub,
+            CppTokenKind::Star => BinaryOperator::Mul,
+            CppTokenKind::Slash => BinaryOperator::Div,
+            CppTokenKind::Percent => BinaryOperator::Mod,
+            CppTokenKind::Assign => BinaryOperator::Assign,
+            CppTokenKind::PlusAssign => BinaryOperator::AddAssign,
+            CppTokenKind::MinusAssign => BinaryOperator::SubAssign,
+            CppTokenKind::StarAssign => BinaryOperator::MulAssign,
+            CppTokenKind::SlashAssign => BinaryOperator::DivAssign,
+            CppTokenKind::PercentAssign => BinaryOperator::ModAssign,
+            CppTokenKind::Ampersand => BinaryOperator::BitAnd,
+            CppTokenKind::Pipe => BinaryOperator::BitOr,
+            CppTokenKind::Caret => BinaryOperator::BitXor,
+            CppTokenKind::AmpersandAssign => BinaryOperator::BitAndAssign,
+            CppTokenKind::PipeAssign => BinaryOperator::BitOrAssign,
+            CppTokenKind::CaretAssign => BinaryOperator::BitXorAssign,
+            CppTokenKind::LeftShift => BinaryOperator::Shl,
+            CppTokenKind::RightShift => BinaryOperator::Shr,
+            CppTokenKind::LeftShiftAssign => BinaryOperator::ShlAssign,
+            CppTokenKind::RightShiftAssign => BinaryOperator::ShrAssign,
+            CppTokenKind::LogicalAnd => BinaryOperator::LogicalAnd,
+            CppTokenKind::LogicalOr => BinaryOperator::LogicalOr,
+            CppTokenKind::Equal => BinaryOperator::Eq,
+            CppTokenKind::NotEqual => BinaryOperator::Neq,
+            CppTokenKind::Less => BinaryOperator::Lt,
+            CppTokenKind::LessEqual => BinaryOperator::Le,
+            CppTokenKind::Greater => BinaryOperator::Gt,
+            CppTokenKind::GreaterEqual => BinaryOperator::Ge,
+            CppTokenKind::Spaceship => BinaryOperator::Spaceship,
+            CppTokenKind::DotStar => BinaryOperator::MemberPointerAccess,
+            CppTokenKind::ArrowStar => BinaryOperator::PtrMemberPointerAccess,
+            CppTokenKind::Comma => BinaryOperator::Comma,
+            CppTokenKind::Question => BinaryOperator::Conditional,
+            _ => BinaryOperator::Nop,
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{kind::CppTokenKind, parser_error::CppParseError, text::Reader};
+use std::borrow::Cow;
+
+use crate::{
+    kind::{CppFeature, CppLanguageLevel, CppTokenKind},
+    parser_error::CppParseError,
+    text::{remove_line_splices, Reader, SourceRange},
+};
 
 use super::{is_name_continue, is_name_start, lexer_config::LexerConfig, token_data::CppTokenData};
 
@@ -6,6 +12,26 @@ pub struct CppLexer<'a> {
     reader: Reader<'a>,
     lexer_config: LexerConfig,
     errors: &'a mut Vec<CppParseError>,
+    directive: DirectiveState,
+}
+
+/// Position of the lexer inside a preprocessor directive line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveState {
+    /// Not inside a directive
+    None,
+    /// After the `#` that starts a line, the directive name follows
+    Name,
+    /// After #include, a header name may follow
+    HeaderName,
+    /// After __has_include, a header name follows the `(`
+    HasInclude,
+    /// After #error or #warning, the rest of the line is a message
+    Message,
+    /// Any other part of the directive
+    Body,
+    /// After `export` at the start of a line, `import` may follow
+    Export,
 }
 
 impl CppLexer<'_> {
@@ -18,6 +44,7 @@ impl CppLexer<'_> {
             reader: Reader::new(text),
             lexer_config,
             errors,
+            directive: DirectiveState::None,
         }
     }
 
@@ -25,39 +52,91 @@ impl CppLexer<'_> {
         let mut tokens = vec![];
 
         while !self.reader.is_eof() {
+            let line_start = self.reader.is_start_of_line();
             let kind = self.lex();
             if kind == CppTokenKind::Eof {
                 break;
             }
 
+            self.update_directive_state(kind, line_start);
             tokens.push(CppTokenData::new(kind, self.reader.saved_range()));
         }
 
         tokens
     }
 
-    /// Convert identifier to keyword token if it matches a C++ keyword
+    /// Track the directive line the last token belongs to, a directive starts with a `#` at
+    /// the start of a line and ends with the line
+    fn update_directive_state(&mut self, kind: CppTokenKind, line_start: bool) {
+        self.directive = match (self.directive, kind) {
+            (_, CppTokenKind::Newline) => DirectiveState::None,
+            (
+                state,
+                CppTokenKind::Whitespace | CppTokenKind::LineComment | CppTokenKind::BlockComment,
+            ) => state,
+            (DirectiveState::None, CppTokenKind::Hash) if line_start => DirectiveState::Name,
+            // `import <vector>;` of a module unit has a header name like `#include`
+            (DirectiveState::None, CppTokenKind::ExportKeyword) if line_start => {
+                DirectiveState::Export
+            }
+            (DirectiveState::None | DirectiveState::Export, CppTokenKind::Identifier)
+                if (line_start || self.directive == DirectiveState::Export)
+                    && self.reader.current_saved_text() == "import" =>
+            {
+                DirectiveState::HeaderName
+            }
+            (DirectiveState::None | DirectiveState::Export, _) => DirectiveState::None,
+            (DirectiveState::Name, _) => match self.reader.current_saved_text() {
+                "include" | "include_next" | "import" => DirectiveState::HeaderName,
+                "error" | "warning" => DirectiveState::Message,
+                _ => DirectiveState::Body,
+            },
+            (DirectiveState::Body, CppTokenKind::Identifier)
+                if matches!(
+                    self.reader.current_saved_text(),
+                    "__has_include" | "__has_include_next"
+                ) =>
+            {
+                DirectiveState::HasInclude
+            }
+            (DirectiveState::HasInclude, CppTokenKind::LeftParen) => DirectiveState::HeaderName,
+            _ => DirectiveState::Body,
+        };
+    }
+
+    /// Convert identifier to keyword token if it matches a C++ keyword of the configured
+    /// language level.
+    ///
+    /// Only `char8_t`, a type of its own since C++20, and `register`, an identifier since
+    /// C++17, depend on the level. Every other keyword is kept at every level so that the
+    /// grammar can report its use below the level that introduced it.
     fn name_to_kind(&self, name: &str) -> CppTokenKind {
+        let level = self.lexer_config.language_level;
         match name {
             // Basic keywords
+            "asm" => CppTokenKind::AsmKeyword,
             "auto" => CppTokenKind::AutoKeyword,
+            "bool" => CppTokenKind::BoolKeyword,
             "break" => CppTokenKind::BreakKeyword,
             "case" => CppTokenKind::CaseKeyword,
             "catch" => CppTokenKind::CatchKeyword,
             "char" => CppTokenKind::CharKeyword,
             "class" => CppTokenKind::ClassKeyword,
             "const" => CppTokenKind::ConstKeyword,
+            "const_cast" => CppTokenKind::ConstCastKeyword,
             "continue" => CppTokenKind::ContinueKeyword,
             "default" => CppTokenKind::DefaultKeyword,
             "delete" => CppTokenKind::DeleteKeyword,
             "do" => CppTokenKind::DoKeyword,
             "double" => CppTokenKind::DoubleKeyword,
+            "dynamic_cast" => CppTokenKind::DynamicCastKeyword,
             "else" => CppTokenKind::ElseKeyword,
             "enum" => CppTokenKind::EnumKeyword,
             "extern" => CppTokenKind::ExternKeyword,
             "false" => CppTokenKind::FalseKeyword,
             "float" => CppTokenKind::FloatKeyword,
             "for" => CppTokenKind::ForKeyword,
+            "friend" => CppTokenKind::FriendKeyword,
             "goto" => CppTokenKind::GotoKeyword,
             "if" => CppTokenKind::IfKeyword,
             "inline" => CppTokenKind::InlineKeyword,
@@ -68,11 +147,15 @@ impl CppLexer<'_> {
             "private" => CppTokenKind::PrivateKeyword,
             "protected" => CppTokenKind::ProtectedKeyword,
             "public" => CppTokenKind::PublicKeyword,
+            // Removed in C++17, the name stays reserved but is otherwise an identifier
+            "register" if level < CppLanguageLevel::Cpp17 => CppTokenKind::RegisterKeyword,
+            "reinterpret_cast" => CppTokenKind::ReinterpretCastKeyword,
             "return" => CppTokenKind::ReturnKeyword,
             "short" => CppTokenKind::ShortKeyword,
             "signed" => CppTokenKind::SignedKeyword,
             "sizeof" => CppTokenKind::SizeofKeyword,
             "static" => CppTokenKind::StaticKeyword,
+            "static_cast" => CppTokenKind::StaticCastKeyword,
             "struct" => CppTokenKind::StructKeyword,
             "switch" => CppTokenKind::SwitchKeyword,
             "this" => CppTokenKind::ThisKeyword,
@@ -88,11 +171,14 @@ impl CppLexer<'_> {
             "virtual" => CppTokenKind::VirtualKeyword,
             "void" => CppTokenKind::VoidKeyword,
             "volatile" => CppTokenKind::VolatileKeyword,
+            "wchar_t" => CppTokenKind::WcharKeyword,
             "while" => CppTokenKind::WhileKeyword,
             
             // C++11 and later keywords
             "alignas" => CppTokenKind::AlignasKeyword,
             "alignof" => CppTokenKind::AlignofKeyword,
+            "char16_t" => CppTokenKind::Char16Keyword,
+            "char32_t" => CppTokenKind::Char32Keyword,
             "constexpr" => CppTokenKind::ConstexprKeyword,
             "decltype" => CppTokenKind::DecltypeKeyword,
             "explicit" => CppTokenKind::ExplicitKeyword,
@@ -106,11 +192,28 @@ impl CppLexer<'_> {
             "thread_local" => CppTokenKind::ThreadLocalKeyword,
             
             // C++20 keywords
+            "char8_t" if level >= CppLanguageLevel::Cpp20 => CppTokenKind::Char8Keyword,
             "concept" => CppTokenKind::ConceptKeyword,
+            "consteval" => CppTokenKind::ConstevalKeyword,
+            "constinit" => CppTokenKind::ConstinitKeyword,
             "requires" => CppTokenKind::RequiresKeyword,
             "co_await" => CppTokenKind::CoAwaitKeyword,
             "co_return" => CppTokenKind::CoReturnKeyword,
             "co_yield" => CppTokenKind::CoYieldKeyword,
+
+            // Alternative tokens, MSVC only knows them with /permissive- or <ciso646>
+            _ if level == CppLanguageLevel::MsvcCpp => CppTokenKind::Identifier,
+            "and" => CppTokenKind::LogicalAnd,
+            "and_eq" => CppTokenKind::AmpersandAssign,
+            "bitand" => CppTokenKind::Ampersand,
+            "bitor" => CppTokenKind::Pipe,
+            "compl" => CppTokenKind::Tilde,
+            "not" => CppTokenKind::LogicalNot,
+            "not_eq" => CppTokenKind::NotEqual,
+            "or" => CppTokenKind::LogicalOr,
+            "or_eq" => CppTokenKind::PipeAssign,
+            "xor" => CppTokenKind::Caret,
+            "xor_eq" => CppTokenKind::CaretAssign,
             
             // Not a keyword, return as identifier
             _ => CppTokenKind::Identifier,
@@ -121,10 +224,24 @@ impl CppLexer<'_> {
     fn lex(&mut self) -> CppTokenKind {
         self.reader.reset_buff();
 
+        match (self.directive, self.reader.current_char()) {
+            (DirectiveState::HeaderName, '<' | '"') => return self.lex_header_name(),
+            (DirectiveState::Message, ch) if !matches!(ch, ' ' | '\t' | '\n' | '\r') => {
+                self.reader.eat_while(|ch| ch != '\n' && ch != '\r');
+                return CppTokenKind::DirectiveText;
+            }
+            _ => {}
+        }
+
         match self.reader.current_char() {
             // Whitespace
             '\n' | '\r' => self.lex_newline(),
             ' ' | '\t' => self.lex_whitespace(),
+            // A UTF-8 byte order mark is kept as trivia so that offsets stay intact
+            '\u{FEFF}' if self.reader.saved_range().start_offset == 0 => {
+                self.reader.bump();
+                CppTokenKind::Whitespace
+            }
             
             // Single character tokens
             '(' => {
@@ -372,7 +489,7 @@ impl CppLexer<'_> {
                     '0'..='9' => {
                         // Decimal number starting with '.'
                         // We need to restart number parsing from the '.'
-                        return self.lex_number();
+                        self.lex_number()
                     }
                     _ => CppTokenKind::Dot,
                 }
@@ -388,18 +505,44 @@ impl CppLexer<'_> {
             }
             
             // String literals
-            '"' => self.lex_string_literal(),
-            '\'' => self.lex_char_literal(),
+            '"' => {
+                let kind = self.lex_string_literal();
+                self.lex_literal_suffix(kind)
+            }
+            '\'' => {
+                let kind = self.lex_char_literal();
+                self.lex_literal_suffix(kind)
+            }
             
             // Numbers
             '0'..='9' => self.lex_number(),
             
             // Identifiers and keywords
-            ch if is_name_start(ch) => {
-                self.reader.bump();
-                self.reader.eat_while(is_name_continue);
-                let name = self.reader.current_saved_text();
-                self.name_to_kind(name)
+            ch if is_name_start(ch) || self.is_ucn_start() => {
+                self.lex_name();
+                let text = self.reader.current_saved_text();
+                let name = if text.contains('\\') {
+                    Cow::Owned(remove_line_splices(text))
+                } else {
+                    Cow::Borrowed(text)
+                };
+                match (name.as_ref(), self.reader.current_char()) {
+                    // Encoding prefixes: u8"..", u"..", U"..", L"..", u8'.', u'.', U'.', L'.'
+                    ("u8" | "u" | "U" | "L", '"') => {
+                        let kind = self.lex_string_literal();
+                        self.lex_literal_suffix(kind)
+                    }
+                    ("u8" | "u" | "U" | "L", '\'') => {
+                        let kind = self.lex_char_literal();
+                        self.lex_literal_suffix(kind)
+                    }
+                    // Raw strings: R"delim(...)delim", optionally with an encoding prefix
+                    ("R" | "u8R" | "uR" | "UR" | "LR", '"') => {
+                        let kind = self.lex_raw_string_literal();
+                        self.lex_literal_suffix(kind)
+                    }
+                    _ => self.name_to_kind(&name),
+                }
             }
             
             // End of file
@@ -413,6 +556,76 @@ impl CppLexer<'_> {
         }
     }
 
+    /// Whether the reader is at a universal character name like \u00e9 or \U0001F600
+    fn is_ucn_start(&mut self) -> bool {
+        self.reader.current_char() == '\\' && matches!(self.reader.next_char(), 'u' | 'U')
+    }
+
+    /// Lex an identifier, universal character names are part of it
+    fn lex_name(&mut self) {
+        loop {
+            if is_name_continue(self.reader.current_char()) {
+                self.reader.bump();
+            } else if self.is_ucn_start() {
+                self.lex_ucn();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Lex a universal character name \uXXXX or \UXXXXXXXX
+    fn lex_ucn(&mut self) {
+        let start = self.reader.current_char_range().start_offset;
+        self.reader.bump(); // consume backslash
+        let expected = if self.reader.current_char() == 'u' { 4 } else { 8 };
+        self.reader.bump();
+
+        let mut value = 0;
+        let mut count = 0;
+        while count < expected {
+            let Some(digit) = self.reader.current_char().to_digit(16) else {
+                break;
+            };
+            value = value * 16 + digit;
+            count += 1;
+            self.reader.bump();
+        }
+
+        let end = self.reader.current_char_range().start_offset;
+        let message = if count < expected {
+            "incomplete universal character name"
+        } else if char::from_u32(value).is_none_or(|ch| ch.is_ascii()) {
+            "invalid universal character name"
+        } else {
+            return;
+        };
+        self.errors.push(CppParseError::syntax_error_from(
+            message,
+            SourceRange::new(start, end - start),
+        ));
+    }
+
+    /// Lex the header name <...> or "..." of an #include directive
+    fn lex_header_name(&mut self) -> CppTokenKind {
+        let close = if self.reader.current_char() == '<' {
+            '>'
+        } else {
+            '"'
+        };
+        self.reader.bump();
+        self.reader.eat_while(|ch| ch != close && ch != '\n' && ch != '\r');
+        if self.reader.current_char() == close {
+            self.reader.bump();
+        } else {
+            self.errors.push(CppParseError::syntax_error_from(
+                "unterminated header name",
+                self.reader.saved_range(),
+            ));
+        }
+        CppTokenKind::HeaderName
+    }
+
     /// Lex whitespace characters
     fn lex_whitespace(&mut self) -> CppTokenKind {
         self.reader.eat_while(|ch| ch == ' ' || ch == '\t');
@@ -512,6 +725,65 @@ impl CppLexer<'_> {
         CppTokenKind::StringLiteral
     }
 
+    /// Lex raw string literal R"delim(...)delim", the reader is at the opening quote
+    fn lex_raw_string_literal(&mut self) -> CppTokenKind {
+        self.reader.bump(); // consume opening quote
+
+        let mut delimiter = String::new();
+        while !self.reader.is_eof() {
+            match self.reader.current_char() {
+                '(' | ')' | '\\' | '"' | ' ' | '\t' | '\n' | '\r' => break,
+                ch => {
+                    delimiter.push(ch);
+                    self.reader.bump();
+                }
+            }
+        }
+
+        if self.reader.current_char() != '(' || delimiter.len() > 16 {
+            self.errors.push(CppParseError::syntax_error_from(
+                "invalid raw string delimiter",
+                self.reader.saved_range(),
+            ));
+            // Recover like an ordinary string up to the end of the line
+            self.reader.eat_while(|ch| ch != '"' && ch != '\n' && ch != '\r');
+            if self.reader.current_char() == '"' {
+                self.reader.bump();
+            }
+            return CppTokenKind::StringLiteral;
+        }
+        self.reader.bump(); // consume '('
+
+        // The content may span lines and contain anything but `)delim"`
+        while !self.reader.is_eof() {
+            if self.reader.current_char() != ')' {
+                self.reader.bump();
+                continue;
+            }
+
+            self.reader.bump();
+            // A delimiter never contains ')', so on mismatch scanning resumes at the mismatch
+            let matched = delimiter.chars().all(|ch| {
+                if self.reader.current_char() == ch {
+                    self.reader.bump();
+                    true
+                } else {
+                    false
+                }
+            });
+            if matched && self.reader.current_char() == '"' {
+                self.reader.bump(); // consume closing quote
+                return CppTokenKind::StringLiteral;
+            }
+        }
+
+        self.errors.push(CppParseError::syntax_error_from(
+            "unterminated raw string literal",
+            self.reader.saved_range(),
+        ));
+        CppTokenKind::StringLiteral
+    }
+
     /// Lex character literal '...'
     fn lex_char_literal(&mut self) -> CppTokenKind {
         self.reader.bump(); // consume opening quote
@@ -550,138 +822,215 @@ impl CppLexer<'_> {
         CppTokenKind::CharLiteral
     }
 
-    /// Lex numeric literals (integers, floats, hex, binary, etc.)
-    fn lex_number(&mut self) -> CppTokenKind {
-        enum NumberState {
-            Int,
-            Float,
-            Hex,
-            HexFloat,
-            Binary,
-            WithExponent,
+    /// Lex a user-defined literal suffix directly following a literal, e.g. "abc"sv or 10_km
+    fn lex_literal_suffix(&mut self, kind: CppTokenKind) -> CppTokenKind {
+        if is_name_start(self.reader.current_char()) {
+            self.reader.eat_while(is_name_continue);
+            CppTokenKind::UserDefinedLiteral
+        } else {
+            kind
         }
+    }
 
-        let mut state = NumberState::Int;
-        let first = self.reader.current_char();
-        
-        // Handle special number prefixes
-        if first == '0' {
+    /// Lex numeric literals (integers, floats, hex, binary, etc.) including their suffix
+    fn lex_number(&mut self) -> CppTokenKind {
+        // Entered with the '.' already consumed for literals like .5
+        let mut is_float = self.reader.current_saved_text() == ".";
+        let mut radix = 10;
+        if !is_float && self.reader.current_char() == '0' {
             self.reader.bump();
             match self.reader.current_char() {
                 'x' | 'X' => {
                     self.reader.bump();
-                    state = NumberState::Hex;
+                    radix = 16;
                 }
                 'b' | 'B' => {
+                    if !self
+                        .lexer_config
+                        .language_level
+                        .supports_feature(CppFeature::BinaryLiterals)
+                    {
+                        self.errors.push(CppParseError::language_level_error_from(
+                            "binary literals require C++14",
+                            SourceRange::new(self.reader.current_char_range().start_offset - 1, 2),
+                        ));
+                    }
                     self.reader.bump();
-                    state = NumberState::Binary;
-                }
-                '0'..='7' => {
-                    // Octal number (continue as normal integer)
-                    state = NumberState::Int;
-                }
-                '.' => {
-                    state = NumberState::Float;
-                }
-                _ => {
-                    // Just a zero
+                    radix = 2;
                 }
+                _ => radix = 8,
             }
-        } else if first == '.' {
-            // Float starting with decimal point
+        }
+
+        let mut invalid_digit = None;
+        // The leading 0 of an octal literal is one of its digits: 0'777
+        let leading_digits = usize::from(radix == 8);
+        let mut digit_count = self.lex_digits(radix, leading_digits, &mut invalid_digit);
+        // A hexadecimal float may start with its fraction: 0x.8p1
+        let is_hex_fraction = radix == 16 && self.reader.current_char() == '.';
+        if matches!(radix, 2 | 16) && digit_count == 0 && !is_hex_fraction {
+            let name = if radix == 16 { "hexadecimal" } else { "binary" };
+            self.errors.push(CppParseError::syntax_error_from(
+                &format!("expected {} digits", name),
+                self.reader.current_char_range(),
+            ));
+            return CppTokenKind::IntegerLiteral;
+        }
+
+        // Fraction
+        if radix != 2 && !is_float && self.reader.current_char() == '.' {
             self.reader.bump();
-            state = NumberState::Float;
-        } else {
-            // Regular decimal number
+            is_float = true;
+            digit_count += self.lex_digits(radix, 0, &mut invalid_digit);
+            if digit_count == 0 {
+                self.errors.push(CppParseError::syntax_error_from(
+                    "expected hexadecimal digits",
+                    self.reader.current_char_range(),
+                ));
+            }
+        }
+
+        // Exponent, required for hexadecimal floats
+        let has_exponent = match (radix, self.reader.current_char()) {
+            (8 | 10, 'e' | 'E') | (16, 'p' | 'P') => true,
+            (16, _) if is_float => {
+                self.errors.push(CppParseError::syntax_error_from(
+                    "hexadecimal floating literal requires an exponent",
+                    self.reader.current_char_range(),
+                ));
+                false
+            }
+            _ => false,
+        };
+        if has_exponent {
             self.reader.bump();
+            if matches!(self.reader.current_char(), '+' | '-') {
+                self.reader.bump();
+            }
+            if self.lex_digits(10, 0, &mut None) == 0 {
+                self.errors.push(CppParseError::syntax_error_from(
+                    "expected exponent digits",
+                    self.reader.current_char_range(),
+                ));
+            }
+            is_float = true;
+        }
+
+        if radix == 8 && is_float {
+            // 089.5 and 09e1 are valid decimal floating literals
+            invalid_digit = None;
+        }
+        if let Some((ch, range)) = invalid_digit {
+            let name = if radix == 8 { "octal" } else { "binary" };
+            self.errors.push(CppParseError::syntax_error_from(
+                &format!("invalid digit '{}' in {} literal", ch, name),
+                range,
+            ));
         }
 
-        // Continue reading digits based on state
+        let kind = if is_float {
+            CppTokenKind::FloatingLiteral
+        } else {
+            CppTokenKind::IntegerLiteral
+        };
+        self.lex_number_suffix(kind)
+    }
+
+    /// Lex a run of digits of the given radix with optional digit separators, returns the
+    /// number of digits counting the `leading_digits` already consumed. Octal and binary
+    /// runs accept any decimal digit and remember the first one that does not belong to the
+    /// radix.
+    fn lex_digits(
+        &mut self,
+        radix: u32,
+        leading_digits: usize,
+        invalid_digit: &mut Option<(char, SourceRange)>,
+    ) -> usize {
+        let scan_radix = if radix == 16 { 16 } else { 10 };
+        let mut count = leading_digits;
         loop {
-            if self.reader.is_eof() {
-                break;
-            }
-            
             let ch = self.reader.current_char();
-            let should_continue = match (&state, ch) {
-                (NumberState::Int, '0'..='9') => true,
-                (NumberState::Int, '.') => {
-                    state = NumberState::Float;
-                    true
-                }
-                (NumberState::Int, 'e' | 'E') => {
-                    self.reader.bump();
-                    if matches!(self.reader.current_char(), '+' | '-') {
-                        self.reader.bump();
-                    }
-                    state = NumberState::WithExponent;
-                    continue; // Don't bump again
-                }
-                (NumberState::Float, '0'..='9') => true,
-                (NumberState::Float, 'e' | 'E') => {
-                    self.reader.bump();
-                    if matches!(self.reader.current_char(), '+' | '-') {
-                        self.reader.bump();
-                    }
-                    state = NumberState::WithExponent;
-                    continue;
-                }
-                (NumberState::Hex, '0'..='9' | 'a'..='f' | 'A'..='F') => true,
-                (NumberState::Hex, '.') => {
-                    state = NumberState::HexFloat;
-                    true
-                }
-                (NumberState::Hex, 'p' | 'P') => {
-                    self.reader.bump();
-                    if matches!(self.reader.current_char(), '+' | '-') {
-                        self.reader.bump();
-                    }
-                    state = NumberState::WithExponent;
-                    continue;
+            if ch.is_digit(scan_radix) {
+                if !ch.is_digit(radix) && invalid_digit.is_none() {
+                    *invalid_digit = Some((ch, self.reader.current_char_range()));
                 }
-                (NumberState::HexFloat, '0'..='9' | 'a'..='f' | 'A'..='F') => true,
-                (NumberState::HexFloat, 'p' | 'P') => {
-                    self.reader.bump();
-                    if matches!(self.reader.current_char(), '+' | '-') {
-                        self.reader.bump();
-                    }
-                    state = NumberState::WithExponent;
-                    continue;
+                count += 1;
+                self.reader.bump();
+            } else if ch == '\'' && count > 0 && self.reader.next_char().is_digit(scan_radix) {
+                if !self
+                    .lexer_config
+                    .language_level
+                    .supports_feature(CppFeature::DigitSeparators)
+                {
+                    self.errors.push(CppParseError::language_level_error_from(
+                        "digit separators require C++14",
+                        self.reader.current_char_range(),
+                    ));
                 }
-                (NumberState::Binary, '0' | '1') => true,
-                (NumberState::WithExponent, '0'..='9') => true,
-                _ => false,
-            };
-
-            if should_continue {
                 self.reader.bump();
             } else {
-                break;
+                return count;
             }
         }
+    }
 
-        // Handle suffixes
-        if !self.reader.is_eof() {
-            match state {
-                NumberState::Int | NumberState::Hex | NumberState::Binary => {
-                    // Integer suffixes: u, U, l, L, ul, UL, etc.
-                    self.reader.eat_while(|c| matches!(c, 'u' | 'U' | 'l' | 'L'));
-                }
-                NumberState::Float | NumberState::HexFloat | NumberState::WithExponent => {
-                    // Float suffixes: f, F, l, L
-                    if matches!(self.reader.current_char(), 'f' | 'F' | 'l' | 'L') {
-                        self.reader.bump();
-                    }
-                }
-            }
+    /// Lex the suffix of a numeric literal, anything that is neither a standard suffix nor
+    /// a user-defined literal suffix is reported
+    fn lex_number_suffix(&mut self, kind: CppTokenKind) -> CppTokenKind {
+        let start = self.reader.current_char_range().start_offset;
+        let prefix_len = self.reader.current_saved_text().len();
+        if self.reader.eat_while(is_name_continue) == 0 {
+            return kind;
         }
 
-        // Return appropriate token type
-        match state {
-            NumberState::Float | NumberState::HexFloat | NumberState::WithExponent => {
-                CppTokenKind::FloatingLiteral
+        let suffix = &self.reader.current_saved_text()[prefix_len..];
+        let range = SourceRange::new(start, suffix.len());
+        let is_standard = if kind == CppTokenKind::FloatingLiteral {
+            matches!(
+                suffix,
+                "f" | "F" | "l" | "L" | "f16" | "F16" | "f32" | "F32" | "f64" | "F64" | "f128"
+                    | "F128" | "bf16" | "BF16"
+            )
+        } else {
+            let rest = suffix
+                .strip_prefix(['u', 'U'])
+                .or_else(|| suffix.strip_suffix(['u', 'U']))
+                .unwrap_or(suffix);
+            if matches!(rest, "z" | "Z")
+                && !self
+                    .lexer_config
+                    .language_level
+                    .supports_feature(CppFeature::SizeTLiterals)
+            {
+                self.errors.push(CppParseError::language_level_error_from(
+                    "size_t literal suffix requires C++23",
+                    range,
+                ));
             }
-            _ => CppTokenKind::IntegerLiteral,
+            matches!(rest, "" | "l" | "L" | "ll" | "LL" | "z" | "Z")
+        };
+        if is_standard {
+            return kind;
+        }
+
+        // Suffixes without an underscore are reserved for the standard library
+        let is_user_defined = suffix.starts_with('_')
+            || matches!(
+                suffix,
+                "h" | "min" | "s" | "ms" | "us" | "ns" | "d" | "y" | "i" | "if" | "il"
+            );
+        if !is_user_defined {
+            let name = if kind == CppTokenKind::FloatingLiteral {
+                "floating"
+            } else {
+                "integer"
+            };
+            self.errors.push(CppParseError::syntax_error_from(
+                &format!("invalid suffix '{}' on {} literal", suffix, name),
+                range,
+            ));
+            return kind;
         }
+        CppTokenKind::UserDefinedLiteral
     }
 }
@@ -1,16 +1,20 @@
+mod compile_db;
 mod grammar;
 mod kind;
 mod lexer;
 mod parser;
 mod parser_error;
-// mod syntax;
+mod preprocessor;
+mod syntax;
 mod text;
 
+pub use compile_db::*;
 pub use kind::*;
-// pub use parser::{LuaParser, ParserConfig, SpecialFunction};
-// pub use parser_error::{LuaParseError, LuaParseErrorKind};
-// pub use syntax::*;
-// pub use text::LineIndex;
+pub use parser::{CppParser, ParserConfig};
+pub use parser_error::{CppParseError, CppParseErrorKind};
+pub use preprocessor::*;
+pub use syntax::*;
+pub use text::LineIndex;
 
 #[macro_use]
 extern crate rust_i18n;
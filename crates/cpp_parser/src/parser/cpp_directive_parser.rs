@@ -0,0 +1,106 @@
+use crate::{
+    grammar::parse_directive, kind::CppTokenKind, lexer::CppTokenData, parser_error::CppParseError,
+    text::SourceRange,
+};
+
+use super::{CppParser, MarkEvent, MarkerEventContainer};
+
+/// Parses a single preprocessor directive line into the events of the C++ parser. The
+/// tokens run from the `#` up to, but not including, the newline; `Eof` marks the end of
+/// the line.
+pub struct CppDirectiveParser<'a, 'b> {
+    cpp_parser: &'a mut CppParser<'b>,
+    tokens: Vec<CppTokenData>,
+    token_index: usize,
+    current_token: CppTokenKind,
+}
+
+impl MarkerEventContainer for CppDirectiveParser<'_, '_> {
+    fn get_mark_level(&self) -> usize {
+        self.cpp_parser.get_mark_level()
+    }
+
+    fn incr_mark_level(&mut self) {
+        self.cpp_parser.incr_mark_level()
+    }
+
+    fn decr_mark_level(&mut self) {
+        self.cpp_parser.decr_mark_level()
+    }
+
+    fn get_events(&mut self) -> &mut Vec<MarkEvent> {
+        self.cpp_parser.get_events()
+    }
+}
+
+impl CppDirectiveParser<'_, '_> {
+    pub fn parse(cpp_parser: &mut CppParser<'_>, tokens: Vec<CppTokenData>) {
+        let mut parser = CppDirectiveParser {
+            current_token: tokens.first().map_or(CppTokenKind::Eof, |token| token.kind),
+            cpp_parser,
+            tokens,
+            token_index: 0,
+        };
+
+        parse_directive(&mut parser);
+    }
+
+    pub fn current_token(&self) -> CppTokenKind {
+        self.current_token
+    }
+
+    pub fn current_token_range(&self) -> SourceRange {
+        match self.tokens.get(self.token_index) {
+            Some(token) => token.range,
+            None => self.tokens.last().map_or(SourceRange::EMPTY, |token| {
+                SourceRange::new(token.range.end_offset(), 0)
+            }),
+        }
+    }
+
+    pub fn current_token_text(&self) -> &str {
+        let range = self.current_token_range();
+        &self.cpp_parser.origin_text()[range.start_offset..range.end_offset()]
+    }
+
+    /// Whether the current token directly follows the previous one, a `(` right after the
+    /// name of a `#define` starts the parameter list of a function-like macro
+    pub fn is_adjacent_to_previous(&self) -> bool {
+        self.token_index > 0
+            && self.token_index < self.tokens.len()
+            && !matches!(
+                self.tokens[self.token_index - 1].kind,
+                CppTokenKind::Whitespace | CppTokenKind::LineComment | CppTokenKind::BlockComment
+            )
+    }
+
+    pub fn bump(&mut self) {
+        if let Some(token) = self.tokens.get(self.token_index) {
+            let (kind, range) = (token.kind, token.range);
+            self.get_events().push(MarkEvent::EatToken { kind, range });
+        }
+
+        self.token_index += 1;
+        // Whitespace and comments stay inside the directive node
+        while let Some(token) = self.tokens.get(self.token_index) {
+            if !matches!(
+                token.kind,
+                CppTokenKind::Whitespace | CppTokenKind::LineComment | CppTokenKind::BlockComment
+            ) {
+                break;
+            }
+            let (kind, range) = (token.kind, token.range);
+            self.get_events().push(MarkEvent::EatToken { kind, range });
+            self.token_index += 1;
+        }
+
+        self.current_token = self
+            .tokens
+            .get(self.token_index)
+            .map_or(CppTokenKind::Eof, |token| token.kind);
+    }
+
+    pub fn push_error(&mut self, err: CppParseError) {
+        self.cpp_parser.push_error(err);
+    }
+}
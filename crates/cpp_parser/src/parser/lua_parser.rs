@@ -1,15 +1,19 @@
+use rowan::TextRange;
+
 use crate::{
-    // grammar::parse_chunk,
-    kind::CppTokenKind,
+    grammar::parse_cpp_unit,
+    kind::{CppFeature, CppSyntaxKind, CppTokenKind},
     lexer::{CppLexer, CppTokenData},
     parser_error::CppParseError,
+    preprocessor::CppPreprocessor,
     text::SourceRange,
-    // LuaSyntaxTree, LuaTreeBuilder,
+    CppSyntaxTree, CppTreeBuilder,
 };
 
 use super::{
+    cpp_directive_parser::CppDirectiveParser,
     // lua_doc_parser::LuaDocParser,
-    marker::{MarkEvent, MarkerEventContainer},
+    marker::{MarkEvent, Marker, MarkerEventContainer},
     parser_config::ParserConfig,
 };
 
@@ -21,10 +25,27 @@ pub struct CppParser<'a> {
     token_index: usize,
     current_token: CppTokenKind,
     mark_level: usize,
+    in_template_args: bool,
+    /// Tokens split by `split_greater_token`, with their original kind
+    split_tokens: Vec<(usize, CppTokenKind)>,
+    /// Trivia and directives in front of the current token that are not in the events yet,
+    /// they go to the node that starts or the token that is eaten next
+    pending_trivia: (usize, usize),
     pub parse_config: ParserConfig<'a>,
     pub(crate) errors: &'a mut Vec<CppParseError>,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct CppParserState {
+    token_index: usize,
+    current_token: CppTokenKind,
+    event_count: usize,
+    error_count: usize,
+    mark_level: usize,
+    split_count: usize,
+    pending_trivia: (usize, usize),
+}
+
 impl MarkerEventContainer for CppParser<'_> {
     fn get_mark_level(&self) -> usize {
         self.mark_level
@@ -41,52 +62,83 @@ impl MarkerEventContainer for CppParser<'_> {
     fn get_events(&mut self) -> &mut Vec<MarkEvent> {
         &mut self.events
     }
+
+    fn mark(&mut self, kind: CppSyntaxKind) -> Marker {
+        self.flush_trivia();
+        let position = self.events.len();
+        self.events.push(MarkEvent::NodeStart { kind, parent: 0 });
+        self.incr_mark_level();
+        Marker::new(position)
+    }
 }
 
 impl<'a> CppParser<'a> {
-    #[allow(unused)]
-    // pub fn parse(text: &'a str, config: ParserConfig) -> CppSyntaxTree {
-    //     let mut errors: Vec<CppParseError> = Vec::new();
-    //     let tokens = {
-    //         let mut lexer = CppLexer::new(text, config.lexer_config(), &mut errors);
-    //         lexer.tokenize()
-    //     };
-
-    //     let mut parser = CppParser {
-    //         text,
-    //         events: Vec::new(),
-    //         tokens,
-    //         token_index: 0,
-    //         current_token: CppTokenKind::None,
-    //         parse_config: config,
-    //         mark_level: 0,
-    //         errors: &mut errors,
-    //     };
-
-    //     parse_chunk(&mut parser);
-    //     let errors = parser.get_errors();
-    //     let root = {
-    //         let mut builder = CppTreeBuilder::new(
-    //             parser.origin_text(),
-    //             parser.events,
-    //             parser.parse_config.node_cache(),
-    //         );
-    //         builder.build();
-    //         builder.finish()
-    //     };
-    //     CppSyntaxTree::new(root, errors)
-    // }
+    pub fn parse(text: &'a str, mut config: ParserConfig<'a>) -> CppSyntaxTree {
+        let mut errors: Vec<CppParseError> = Vec::new();
+        let tokens = {
+            let mut lexer = CppLexer::new(text, config.lexer_config(), &mut errors);
+            lexer.tokenize()
+        };
+
+        // Branches that conditional compilation skips are not parsed
+        let inactive_ranges = {
+            let mut preprocessor = CppPreprocessor::new("", config.level);
+            *preprocessor.get_macros_mut() = config.predefined_macros.clone();
+            if let Some(vfs) = config.vfs.take() {
+                preprocessor.set_include_resolver(vfs, config.include_resolver.clone());
+            }
+            let file_path = config.file_path.clone().unwrap_or_default();
+            let result =
+                preprocessor.run_main_file(&file_path, &config.forced_includes, text, &tokens);
+            errors.retain(|error| {
+                !result
+                    .inactive_ranges
+                    .iter()
+                    .any(|range| range.contains_range(error.range))
+            });
+            errors.extend(result.errors);
+            result.inactive_ranges
+        };
+        let tokens = merge_inactive_tokens(tokens, &inactive_ranges);
+
+        let mut parser = CppParser {
+            text,
+            events: Vec::new(),
+            tokens,
+            token_index: 0,
+            current_token: CppTokenKind::None,
+            parse_config: config,
+            mark_level: 0,
+            in_template_args: false,
+            split_tokens: Vec::new(),
+            pending_trivia: (0, 0),
+            errors: &mut errors,
+        };
+
+        parse_cpp_unit(&mut parser);
+        let errors = parser.get_errors();
+        let root = {
+            let mut builder = CppTreeBuilder::new(
+                parser.origin_text(),
+                parser.events,
+                parser.parse_config.node_cache(),
+            );
+            builder.build();
+            builder.finish()
+        };
+        CppSyntaxTree::new(root, errors, inactive_ranges)
+    }
 
     pub fn init(&mut self) {
-        if self.tokens.is_empty() {
-            self.current_token = CppTokenKind::Eof;
-        } else {
-            self.current_token = self.tokens[0].kind;
-        }
+        let mut next_index = 0;
+        self.skip_trivia(&mut next_index);
+        self.pending_trivia = (0, next_index);
+        self.token_index = next_index;
 
-        if is_trivia_kind(self.current_token) {
-            self.bump();
-        }
+        self.current_token = match self.tokens.get(next_index) {
+            Some(token) => token.kind,
+            None => CppTokenKind::Eof,
+        };
     }
 
     pub fn is_eof(&self) -> bool {
@@ -117,13 +169,13 @@ impl<'a> CppParser<'a> {
         self.tokens[self.token_index].range
     }
 
-    #[allow(unused)]
     pub fn current_token_text(&self) -> &str {
         let range = &self.tokens[self.token_index].range;
         &self.text[range.start_offset..range.end_offset()]
     }
 
     pub fn bump(&mut self) {
+        self.flush_trivia();
         if !is_invalid_kind(self.current_token) && self.token_index < self.tokens.len() {
             let token = &self.tokens[self.token_index];
             self.events.push(MarkEvent::EatToken {
@@ -134,7 +186,7 @@ impl<'a> CppParser<'a> {
 
         let mut next_index = self.token_index + 1;
         self.skip_trivia(&mut next_index);
-        self.parse_trivia_tokens(next_index);
+        self.pending_trivia = (self.token_index + 1, next_index);
         self.token_index = next_index;
 
         if self.token_index >= self.tokens.len() {
@@ -156,28 +208,187 @@ impl<'a> CppParser<'a> {
         }
     }
 
-    fn skip_trivia(&self, index: &mut usize) {
-        if index >= &mut self.tokens.len() {
-            return;
+    /// Kind of the n-th non-trivia token after the current one, `peek_token_at(0)` is the current token
+    pub fn peek_token_at(&self, n: usize) -> CppTokenKind {
+        if n == 0 {
+            return self.current_token;
+        }
+
+        let mut index = self.token_index;
+        for _ in 0..n {
+            index += 1;
+            self.skip_trivia(&mut index);
+            if index >= self.tokens.len() {
+                return CppTokenKind::Eof;
+            }
+        }
+
+        self.tokens[index].kind
+    }
+
+    /// Whether the current token is the first one on its line, `module` and `import` only
+    /// start a module declaration there
+    pub fn is_at_line_start(&self) -> bool {
+        self.token_index < self.tokens.len() && self.is_line_start(self.token_index)
+    }
+
+    /// Text of the n-th non-trivia token after the current one, empty past the end
+    pub fn peek_token_text_at(&self, n: usize) -> &str {
+        let mut index = self.token_index;
+        for _ in 0..n {
+            index += 1;
+            self.skip_trivia(&mut index);
+        }
+
+        match self.tokens.get(index) {
+            Some(token) => &self.text[token.range.start_offset..token.range.end_offset()],
+            None => "",
+        }
+    }
+
+    /// Whether the configured language level or extensions have `feature`
+    pub fn supports_feature(&self, feature: CppFeature) -> bool {
+        match feature {
+            CppFeature::GnuExtensions if self.parse_config.gnu_extensions => true,
+            CppFeature::MsvcExtensions if self.parse_config.msvc_extensions => true,
+            _ => self.parse_config.level.supports_feature(feature),
+        }
+    }
+
+    /// Report `feature` at `range` when the configured language level does not have it,
+    /// the grammar of the feature is parsed anyway
+    pub fn check_feature(&mut self, feature: CppFeature, message: &str, range: SourceRange) {
+        if !self.supports_feature(feature) {
+            self.push_error(CppParseError::language_level_error_from(message, range));
         }
+    }
+
+    /// Inside a template argument list a `>` closes the list instead of being an operator
+    pub fn is_in_template_args(&self) -> bool {
+        self.in_template_args
+    }
+
+    /// Returns the previous state so that it can be restored by the caller
+    pub fn set_in_template_args(&mut self, value: bool) -> bool {
+        std::mem::replace(&mut self.in_template_args, value)
+    }
 
-        let mut kind = self.tokens[*index].kind;
-        while is_trivia_kind(kind) {
-            *index += 1;
-            if *index >= self.tokens.len() {
+    /// Remember the current position so that a construct can be parsed tentatively
+    pub fn save_state(&self) -> CppParserState {
+        CppParserState {
+            token_index: self.token_index,
+            current_token: self.current_token,
+            event_count: self.events.len(),
+            error_count: self.errors.len(),
+            mark_level: self.mark_level,
+            split_count: self.split_tokens.len(),
+            pending_trivia: self.pending_trivia,
+        }
+    }
+
+    /// Rewind to a saved position, dropping every node, token and error produced after it
+    pub fn restore_state(&mut self, state: CppParserState) {
+        self.token_index = state.token_index;
+        self.current_token = state.current_token;
+        self.events.truncate(state.event_count);
+        self.errors.truncate(state.error_count);
+        self.mark_level = state.mark_level;
+        self.pending_trivia = state.pending_trivia;
+        while self.split_tokens.len() > state.split_count {
+            let Some((index, kind)) = self.split_tokens.pop() else {
+                break;
+            };
+            let rest = self.tokens.remove(index + 1);
+            let token = &mut self.tokens[index];
+            token.kind = kind;
+            token.range.length += rest.range.length;
+        }
+    }
+
+    /// Split the first `>` off a `>>`, `>>=` or `>=` token so that it can close a template
+    /// argument list: `std::vector<std::vector<int>>`. Does nothing for other tokens.
+    pub fn split_greater_token(&mut self) {
+        let rest_kind = match self.current_token {
+            CppTokenKind::RightShift => CppTokenKind::Greater,
+            CppTokenKind::RightShiftAssign => CppTokenKind::GreaterEqual,
+            CppTokenKind::GreaterEqual => CppTokenKind::Assign,
+            _ => return,
+        };
+
+        let index = self.token_index;
+        let range = self.tokens[index].range;
+        self.tokens[index] = CppTokenData::new(
+            CppTokenKind::Greater,
+            SourceRange::new(range.start_offset, 1),
+        );
+        self.tokens.insert(
+            index + 1,
+            CppTokenData::new(
+                rest_kind,
+                SourceRange::new(range.start_offset + 1, range.length - 1),
+            ),
+        );
+        self.split_tokens.push((index, self.current_token));
+        self.current_token = CppTokenKind::Greater;
+    }
+
+    /// Skip whitespace, comments and preprocessor directive lines
+    fn skip_trivia(&self, index: &mut usize) {
+        while *index < self.tokens.len() {
+            if self.tokens[*index].kind.is_trivia() {
+                *index += 1;
+            } else if let Some(end) = self.directive_end(*index) {
+                *index = end;
+            } else {
                 break;
             }
-            kind = self.tokens[*index].kind;
+        }
+    }
+
+    /// If a directive starts at `index`, i.e. a `#` that only has whitespace in front of it
+    /// on its line, the index of the newline that ends it
+    fn directive_end(&self, index: usize) -> Option<usize> {
+        if self.tokens[index].kind != CppTokenKind::Hash || !self.is_line_start(index) {
+            return None;
+        }
+
+        let end = self.tokens[index..]
+            .iter()
+            .position(|token| token.kind == CppTokenKind::Newline)
+            .map_or(self.tokens.len(), |offset| index + offset);
+        Some(end)
+    }
+
+    /// Whether only whitespace is in front of the token at `index` on its line
+    fn is_line_start(&self, index: usize) -> bool {
+        self.tokens[..index]
+            .iter()
+            .rev()
+            .find(|token| token.kind != CppTokenKind::Whitespace)
+            .is_none_or(|token| {
+                // Inactive code always ends with its line
+                matches!(
+                    token.kind,
+                    CppTokenKind::Newline | CppTokenKind::InactiveCode
+                )
+            })
+    }
+
+    /// Put the pending trivia in front of the current token into the events
+    pub fn flush_trivia(&mut self) {
+        let (start, end) = std::mem::take(&mut self.pending_trivia);
+        if start < end {
+            self.parse_trivia_tokens(start, end);
         }
     }
 
     // Analyze consecutive whitespace/comments
     // At this point, comments may be in the wrong parent node, adjustments will be made in the subsequent treeBuilder
-    fn parse_trivia_tokens(&mut self, next_index: usize) {
+    fn parse_trivia_tokens(&mut self, start: usize, next_index: usize) {
         let mut line_count = 0;
-        let start = self.token_index;
         let mut doc_tokens: Vec<CppTokenData> = Vec::new();
-        for i in start..next_index {
+        let mut i = start;
+        while i < next_index {
             let token = &self.tokens[i];
             match token.kind {
                 CppTokenKind::LineComment | CppTokenKind::BlockComment => {
@@ -229,6 +440,15 @@ impl<'a> CppParser<'a> {
                         }
                     }
                 }
+                CppTokenKind::InactiveCode => {
+                    let (kind, range) = (token.kind, token.range);
+                    if !doc_tokens.is_empty() {
+                        self.parse_comments(&doc_tokens);
+                        doc_tokens.clear();
+                    }
+                    line_count = 0;
+                    self.events.push(MarkEvent::EatToken { kind, range });
+                }
                 CppTokenKind::Whitespace => {
                     if doc_tokens.is_empty() {
                         self.events.push(MarkEvent::EatToken {
@@ -244,8 +464,16 @@ impl<'a> CppParser<'a> {
                         self.parse_comments(&doc_tokens);
                         doc_tokens.clear();
                     }
+
+                    if let Some(end) = self.directive_end(i) {
+                        let tokens = self.tokens[i..end].to_vec();
+                        CppDirectiveParser::parse(self, tokens);
+                        i = end;
+                        continue;
+                    }
                 }
             }
+            i += 1;
         }
 
         if !doc_tokens.is_empty() {
@@ -253,25 +481,9 @@ impl<'a> CppParser<'a> {
         }
     }
 
-    fn parse_comments(&mut self, comment_tokens: &Vec<CppTokenData>) {
-        let mut trivia_token_start = comment_tokens.len();
-        // Reverse iterate over comment_tokens, removing whitespace and end-of-line tokens
-        for i in (0..comment_tokens.len()).rev() {
-            if matches!(
-                comment_tokens[i].kind,
-                CppTokenKind::Newline | CppTokenKind::Whitespace
-            ) {
-                trivia_token_start = i;
-            } else {
-                break;
-            }
-        }
-
-        let tokens = &comment_tokens[..trivia_token_start];
-        // LuaDocParser::parse(self, tokens);
-
-        for i in trivia_token_start..comment_tokens.len() {
-            let token = &comment_tokens[i];
+    // Doc comments are not parsed yet, so the comment group is kept in the tree as plain trivia
+    fn parse_comments(&mut self, comment_tokens: &[CppTokenData]) {
+        for token in comment_tokens {
             self.events.push(MarkEvent::EatToken {
                 kind: token.kind,
                 range: token.range,
@@ -292,14 +504,40 @@ impl<'a> CppParser<'a> {
     }
 }
 
-fn is_trivia_kind(kind: CppTokenKind) -> bool {
-    matches!(
-        kind,
-        CppTokenKind::LineComment
-            | CppTokenKind::BlockComment
-            | CppTokenKind::Newline
-            | CppTokenKind::Whitespace // | CppTokenKind::
-    )
+/// Replace the tokens of every inactive range with a single `InactiveCode` token
+fn merge_inactive_tokens(
+    tokens: Vec<CppTokenData>,
+    inactive_ranges: &[TextRange],
+) -> Vec<CppTokenData> {
+    if inactive_ranges.is_empty() {
+        return tokens;
+    }
+
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut ranges = inactive_ranges.iter().peekable();
+    for token in tokens {
+        let start = token.range.start_offset;
+        while ranges
+            .peek()
+            .is_some_and(|range| usize::from(range.end()) <= start)
+        {
+            ranges.next();
+        }
+
+        match ranges.peek() {
+            Some(range) if usize::from(range.start()) <= start => {
+                if usize::from(range.start()) == start {
+                    result.push(CppTokenData::new(
+                        CppTokenKind::InactiveCode,
+                        SourceRange::new(start, usize::from(range.len())),
+                    ));
+                }
+            }
+            _ => result.push(token),
+        }
+    }
+
+    result
 }
 
 fn is_invalid_kind(kind: CppTokenKind) -> bool {
@@ -310,90 +548,145 @@ fn is_invalid_kind(kind: CppTokenKind) -> bool {
             | CppTokenKind::BlockComment
             | CppTokenKind::Newline
             | CppTokenKind::Whitespace
+            | CppTokenKind::InactiveCode
     )
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use crate::{
-//         kind::CppTokenKind, lexer::LuaLexer, parser::ParserConfig, parser_error::LuaParseError,
-//         LuaParser,
-//     };
-
-//     #[allow(unused)]
-//     fn new_parser<'a>(
-//         text: &'a str,
-//         config: ParserConfig<'a>,
-//         errors: &'a mut Vec<LuaParseError>,
-//         show_tokens: bool,
-//     ) -> LuaParser<'a> {
-//         let tokens = {
-//             let mut lexer = LuaLexer::new(text, config.lexer_config(), errors);
-//             lexer.tokenize()
-//         };
-
-//         if show_tokens {
-//             println!("tokens: ");
-//             for t in &tokens {
-//                 println!("{:?}", t);
-//             }
-//         }
-
-//         let mut parser = LuaParser {
-//             text,
-//             events: Vec::new(),
-//             tokens,
-//             token_index: 0,
-//             current_token: CppTokenKind::None,
-//             parse_config: config,
-//             mark_level: 0,
-//             errors,
-//         };
-//         parser.init();
-
-//         parser
-//     }
-
-//     #[test]
-//     fn test_parse_and_ast() {
-//         let lua_code = r#"
-//             function foo(a, b)
-//                 return a + b
-//             end
-//         "#;
-
-//         let tree = LuaParser::parse(lua_code, ParserConfig::default());
-//         println!("{:#?}", tree.get_red_root());
-//     }
-
-//     #[test]
-//     fn test_parse_and_ast_with_error() {
-//         let lua_code = r#"
-//             function foo(a, b)
-//                 return a + b
-//         "#;
-
-//         let tree = LuaParser::parse(lua_code, ParserConfig::default());
-//         println!("{:#?}", tree.get_red_root());
-//     }
-
-//     #[test]
-//     fn test_parse_comment() {
-//         let lua_code = r#"
-//             -- comment
-//             local t
-//             -- inline comment
-//         "#;
-
-//         let tree = LuaParser::parse(lua_code, ParserConfig::default());
-//         println!("{:#?}", tree.get_red_root());
-//     }
-
-//     #[test]
-//     fn test_parse_empty_file() {
-//         let lua_code = r#""#;
-
-//         let tree = LuaParser::parse(lua_code, ParserConfig::default());
-//         println!("{:#?}", tree.get_red_root());
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use crate::{
+        kind::{CppSyntaxKind, CppTokenKind},
+        parser::ParserConfig,
+        CppParser, CppSyntaxTree,
+    };
+
+    fn node_kinds(tree: &CppSyntaxTree) -> Vec<CppSyntaxKind> {
+        tree.get_red_root()
+            .descendants()
+            .map(|node| node.kind().into())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_and_ast() {
+        let code = r#"
+            int add(int a, int b) {
+                return a + b;
+            }
+        "#;
+
+        let tree = CppParser::parse(code, ParserConfig::default());
+        assert!(tree.get_errors().is_empty(), "{:?}", tree.get_errors());
+        assert_eq!(tree.get_red_root().text().to_string(), code);
+        use CppSyntaxKind::*;
+        assert_eq!(
+            node_kinds(&tree),
+            vec![
+                TranslationUnit,
+                FunctionDef,
+                BuiltinType,
+                ParameterList,
+                Parameter,
+                BuiltinType,
+                Parameter,
+                BuiltinType,
+                CompoundStat,
+                ReturnStat,
+                BinaryExpr,
+                IdentifierExpr,
+                IdentifierExpr
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_and_ast_with_error() {
+        let code = r#"
+            int add(int a, int b) {
+                return a + b;
+        "#;
+
+        let tree = CppParser::parse(code, ParserConfig::default());
+        assert!(tree.has_syntax_errors());
+        assert_eq!(tree.get_red_root().text().to_string(), code);
+    }
+
+    #[test]
+    fn test_parse_comment() {
+        let code = r#"
+            // comment
+            int t;
+            /* block comment */
+        "#;
+
+        let tree = CppParser::parse(code, ParserConfig::default());
+        assert_eq!(tree.get_red_root().text().to_string(), code);
+        assert_eq!(
+            node_kinds(&tree),
+            vec![
+                CppSyntaxKind::TranslationUnit,
+                CppSyntaxKind::VariableDecl,
+                CppSyntaxKind::BuiltinType
+            ]
+        );
+        // Comments stay tokens of the unit, outside of the declaration
+        let comments: Vec<_> = tree
+            .get_red_root()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|token| {
+                matches!(
+                    token.kind().into(),
+                    CppTokenKind::LineComment | CppTokenKind::BlockComment
+                )
+            })
+            .map(|token| token.text().to_string())
+            .collect();
+        assert_eq!(comments, vec!["// comment", "/* block comment */"]);
+    }
+
+    #[test]
+    fn test_parse_empty_file() {
+        let code = r#""#;
+
+        let tree = CppParser::parse(code, ParserConfig::default());
+        assert_eq!(
+            format!("{:?}", tree.get_red_root()),
+            "Syntax(TranslationUnit)@0..0"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_node_cache() {
+        let mut cache = rowan::NodeCache::default();
+        let code = "int a;\nint b;\n";
+        let config = ParserConfig::new(crate::CppLanguageLevel::Cpp17, Some(&mut cache));
+        let tree = CppParser::parse(code, config);
+        assert_eq!(tree.get_red_root().text().to_string(), code);
+    }
+
+    #[test]
+    fn test_parse_with_includes() {
+        let mut vfs = crate::CppMemoryVfs::new();
+        vfs.add_file("/p/force.h", "#define FORCED 1\n");
+        vfs.add_file("/p/a.h", "#define A 1\n");
+        let code = "#include \"a.h\"\n#if A && FORCED && __has_include(\"a.h\")\nint x;\n#else\nint y;\n#endif\n";
+        let mut config = ParserConfig::default();
+        config.file_path = Some("/p/main.cpp".into());
+        config.forced_includes = vec!["/p/force.h".into()];
+        config.vfs = Some(Box::new(vfs));
+        let tree = CppParser::parse(code, config);
+        assert!(tree.get_errors().is_empty(), "{:?}", tree.get_errors());
+        let inactive_texts = |tree: &CppSyntaxTree| -> Vec<&str> {
+            tree.get_inactive_ranges()
+                .iter()
+                .map(|range| &code[usize::from(range.start())..usize::from(range.end())])
+                .collect()
+        };
+        assert_eq!(inactive_texts(&tree), vec!["int y;\n"]);
+
+        // Without a vfs the include is not followed
+        let tree = CppParser::parse(code, ParserConfig::default());
+        assert_eq!(inactive_texts(&tree), vec!["int x;\n"]);
+    }
+}
@@ -90,7 +90,6 @@ impl Marker {
         }
     }
 
-    #[allow(unused)]
     pub fn undo<P: MarkerEventContainer>(self, p: &mut P) {
         match &mut p.get_events()[self.position] {
             MarkEvent::NodeStart { kind, .. } => {
@@ -98,11 +97,13 @@ impl Marker {
             }
             _ => unreachable!(),
         }
+        p.decr_mark_level();
     }
 }
 
 pub(crate) struct CompleteMarker {
     start: usize,
+    #[allow(unused)]
     pub kind: CppSyntaxKind,
 }
 
@@ -117,6 +118,21 @@ impl CompleteMarker {
         m
     }
 
+    /// Let the node start where `first` starts, the complete nodes from `first` on become its
+    /// first children: the attributes of `[[nodiscard]] int f();` belong to the declaration
+    pub fn extend_to<P: MarkerEventContainer>(
+        self,
+        p: &mut P,
+        first: &CompleteMarker,
+    ) -> CompleteMarker {
+        match &mut p.get_events()[first.start] {
+            MarkEvent::NodeStart { parent, .. } => *parent = self.start,
+            _ => unreachable!(),
+        }
+        self
+    }
+
+    #[allow(unused)]
     pub fn empty() -> Self {
         CompleteMarker {
             start: 0,
@@ -1,8 +1,10 @@
+mod cpp_directive_parser;
 mod lua_doc_parser;
 mod lua_parser;
 mod marker;
 mod parser_config;
 
+pub use cpp_directive_parser::CppDirectiveParser;
 // pub use lua_doc_parser::LuaDocParser;
 pub use lua_parser::CppParser;
 #[allow(unused)]
@@ -1,9 +1,30 @@
+use std::path::PathBuf;
+
 use rowan::NodeCache;
 
-use crate::{kind::CppLanguageLevel, lexer::LexerConfig};
+use crate::{
+    kind::CppLanguageLevel,
+    lexer::LexerConfig,
+    preprocessor::{CppIncludeResolver, CppMacroTable, CppVfs},
+};
 
 pub struct ParserConfig<'cache> {
     pub level: CppLanguageLevel,
+    /// GNU extensions on top of `level`, `-std=gnu++17` is C++17 with them
+    pub gnu_extensions: bool,
+    /// MSVC extensions on top of `level`, for commands of `cl.exe`
+    pub msvc_extensions: bool,
+    /// Macros defined before the first line, they decide which conditional branches are active
+    pub predefined_macros: CppMacroTable,
+    /// Where the headers of `#include` are searched
+    pub include_resolver: CppIncludeResolver,
+    /// Headers included before the first line, `-include` of the compiler
+    pub forced_includes: Vec<PathBuf>,
+    /// The parsed file, quoted includes are looked up next to it first
+    pub file_path: Option<PathBuf>,
+    /// Where included headers are read from, without it `#include` is not followed and
+    /// `__has_include` is false
+    pub vfs: Option<Box<dyn CppVfs>>,
     lexer_config: LexerConfig,
     node_cache: Option<&'cache mut NodeCache>,
 }
@@ -12,6 +33,13 @@ impl<'cache> ParserConfig<'cache> {
     pub fn new(level: CppLanguageLevel, node_cache: Option<&'cache mut NodeCache>) -> Self {
         Self {
             level,
+            gnu_extensions: false,
+            msvc_extensions: false,
+            predefined_macros: CppMacroTable::new(),
+            include_resolver: CppIncludeResolver::new(),
+            forced_includes: Vec::new(),
+            file_path: None,
+            vfs: None,
             lexer_config: LexerConfig {
                 language_level: level,
             },
@@ -32,6 +60,13 @@ impl Default for ParserConfig<'_> {
     fn default() -> Self {
         Self {
             level: CppLanguageLevel::Cpp23,
+            gnu_extensions: false,
+            msvc_extensions: false,
+            predefined_macros: CppMacroTable::new(),
+            include_resolver: CppIncludeResolver::new(),
+            forced_includes: Vec::new(),
+            file_path: None,
+            vfs: None,
             lexer_config: LexerConfig {
                 language_level: CppLanguageLevel::Cpp23,
             },
@@ -3,20 +3,23 @@ use rowan::TextRange;
 use crate::text::SourceRange;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum LuaParseErrorKind {
+pub enum CppParseErrorKind {
     SyntaxError,
     DocError,
+    PreprocessorError,
+    /// Code that needs a newer language level than the configured one, it is parsed anyway
+    LanguageLevelError,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CppParseError {
-    pub kind: LuaParseErrorKind,
+    pub kind: CppParseErrorKind,
     pub message: String,
     pub range: TextRange,
 }
 
 impl CppParseError {
-    pub fn new(kind: LuaParseErrorKind, message: &str, range: TextRange) -> Self {
+    pub fn new(kind: CppParseErrorKind, message: &str, range: TextRange) -> Self {
         CppParseError {
             kind,
             message: message.to_string(),
@@ -26,7 +29,15 @@ impl CppParseError {
 
     pub fn syntax_error_from(message: &str, range: SourceRange) -> Self {
         CppParseError {
-            kind: LuaParseErrorKind::SyntaxError,
+            kind: CppParseErrorKind::SyntaxError,
+            message: message.to_string(),
+            range: range.into(),
+        }
+    }
+
+    pub fn language_level_error_from(message: &str, range: SourceRange) -> Self {
+        CppParseError {
+            kind: CppParseErrorKind::LanguageLevelError,
             message: message.to_string(),
             range: range.into(),
         }
@@ -34,7 +45,7 @@ impl CppParseError {
 
     pub fn doc_error_from(message: &str, range: SourceRange) -> Self {
         CppParseError {
-            kind: LuaParseErrorKind::DocError,
+            kind: CppParseErrorKind::DocError,
             message: message.to_string(),
             range: range.into(),
         }
@@ -0,0 +1,363 @@
+use rowan::TextRange;
+
+use crate::{
+    kind::CppTokenKind,
+    parser_error::{CppParseError, CppParseErrorKind},
+};
+
+use super::{CppMacroTable, CppPpToken};
+
+/// Answers `__has_include`, gets the header name without delimiters and whether it was
+/// written with `<...>`
+pub type CppHasInclude = dyn Fn(&str, bool) -> bool;
+
+/// Replace the operators that must see their operands before macro expansion: `defined`,
+/// `__has_include` and the `__has_*` feature checks
+pub(crate) fn replace_condition_operators(
+    tokens: &[CppPpToken],
+    macros: &CppMacroTable,
+    has_include: &dyn Fn(&str, bool, bool) -> bool,
+    errors: &mut Vec<CppParseError>,
+) -> Vec<CppPpToken> {
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        let operator = token.text.as_str();
+        let is_operator = token.kind == CppTokenKind::Identifier
+            && (operator == "defined" || operator.starts_with("__has_"));
+        if !is_operator {
+            output.push(token.clone());
+            i += 1;
+            continue;
+        }
+
+        // The operand, with or without parentheses for `defined`
+        let parenthesized = tokens
+            .get(i + 1)
+            .is_some_and(|next| next.kind == CppTokenKind::LeftParen);
+        let (operand, end) = if parenthesized {
+            match tokens[i + 2..]
+                .iter()
+                .position(|token| token.kind == CppTokenKind::RightParen)
+            {
+                Some(offset) => (&tokens[i + 2..i + 2 + offset], i + 3 + offset),
+                None => {
+                    push_error(
+                        errors,
+                        &t!("missing ')' after '%{name}'", name = operator),
+                        token.spelling_range,
+                    );
+                    return Vec::new();
+                }
+            }
+        } else if operator == "defined" {
+            (&tokens[i + 1..(i + 2).min(tokens.len())], i + 2)
+        } else {
+            // Some other name that happens to start with __has_
+            output.push(token.clone());
+            i += 1;
+            continue;
+        };
+
+        let value = match operator {
+            "defined" => match operand {
+                [name] if name.is_name() => macros.is_defined(&name.text) as i64,
+                _ => {
+                    push_error(
+                        errors,
+                        &t!("macro name missing after 'defined'"),
+                        token.spelling_range,
+                    );
+                    return Vec::new();
+                }
+            },
+            "__has_include" | "__has_include_next" => {
+                let header: String = operand.iter().map(|token| token.text.as_str()).collect();
+                let is_angled = header.starts_with('<');
+                let path = header
+                    .trim_start_matches(['<', '"'])
+                    .trim_end_matches(['>', '"']);
+                has_include(path, is_angled, operator == "__has_include_next") as i64
+            }
+            "__has_cpp_attribute" => {
+                let name: String = operand.iter().map(|token| token.text.as_str()).collect();
+                has_cpp_attribute(&name)
+            }
+            // __has_builtin, __has_feature, __has_attribute and friends of the compilers,
+            // none of their extensions are known here
+            _ => 0,
+        };
+
+        let range = token.spelling_range.cover(tokens[end - 1].spelling_range);
+        let mut result = CppPpToken::new(
+            CppTokenKind::IntegerLiteral,
+            &value.to_string(),
+            range,
+            token.has_leading_space,
+        );
+        result.expansion_range = token.expansion_range.cover(tokens[end - 1].expansion_range);
+        output.push(result);
+        i = end;
+    }
+
+    output
+}
+
+/// The value of `__has_cpp_attribute` for the standard attributes, the date the attribute
+/// was added or last changed
+fn has_cpp_attribute(name: &str) -> i64 {
+    match name {
+        "assume" => 202207,
+        "carries_dependency" => 200809,
+        "deprecated" => 201309,
+        "fallthrough" => 201603,
+        "likely" | "unlikely" => 201803,
+        "maybe_unused" => 201603,
+        "no_unique_address" => 201803,
+        "nodiscard" => 201907,
+        "noreturn" => 200809,
+        _ => 0,
+    }
+}
+
+/// Evaluate the integer constant expression of a condition after macro expansion
+pub(crate) fn evaluate_condition(tokens: &[CppPpToken]) -> Result<i64, CppParseError> {
+    let mut evaluator = ConditionEvaluator { tokens, index: 0 };
+    let value = evaluator.conditional()?;
+    if let Some(token) = evaluator.current() {
+        return Err(evaluator.error(
+            &t!(
+                "token '%{token}' is not valid in a preprocessor expression",
+                token = token.text
+            ),
+            token.spelling_range,
+        ));
+    }
+    Ok(value)
+}
+
+struct ConditionEvaluator<'a> {
+    tokens: &'a [CppPpToken],
+    index: usize,
+}
+
+impl ConditionEvaluator<'_> {
+    fn current(&self) -> Option<&CppPpToken> {
+        self.tokens.get(self.index)
+    }
+
+    fn current_kind(&self) -> CppTokenKind {
+        self.current().map_or(CppTokenKind::Eof, |token| token.kind)
+    }
+
+    fn current_range(&self) -> TextRange {
+        match self.current() {
+            Some(token) => token.spelling_range,
+            None => self.tokens.last().map_or(TextRange::default(), |token| {
+                TextRange::empty(token.spelling_range.end())
+            }),
+        }
+    }
+
+    fn error(&self, message: &str, range: TextRange) -> CppParseError {
+        CppParseError::new(CppParseErrorKind::PreprocessorError, message, range)
+    }
+
+    fn conditional(&mut self) -> Result<i64, CppParseError> {
+        let condition = self.binary(0)?;
+        if self.current_kind() != CppTokenKind::Question {
+            return Ok(condition);
+        }
+
+        self.index += 1;
+        let then_value = self.conditional()?;
+        if self.current_kind() != CppTokenKind::Colon {
+            return Err(self.error(
+                &t!("expected ':' in conditional expression"),
+                self.current_range(),
+            ));
+        }
+        self.index += 1;
+        let else_value = self.conditional()?;
+        Ok(if condition != 0 {
+            then_value
+        } else {
+            else_value
+        })
+    }
+
+    fn binary(&mut self, min_precedence: u8) -> Result<i64, CppParseError> {
+        let mut lhs = self.unary()?;
+        loop {
+            let operator = self.current_kind();
+            let Some(precedence) = binary_precedence(operator) else {
+                break;
+            };
+            if precedence < min_precedence {
+                break;
+            }
+
+            let range = self.current_range();
+            self.index += 1;
+            let rhs = self.binary(precedence + 1)?;
+            lhs = match operator {
+                CppTokenKind::LogicalOr => (lhs != 0 || rhs != 0) as i64,
+                CppTokenKind::LogicalAnd => (lhs != 0 && rhs != 0) as i64,
+                CppTokenKind::Pipe => lhs | rhs,
+                CppTokenKind::Caret => lhs ^ rhs,
+                CppTokenKind::Ampersand => lhs & rhs,
+                CppTokenKind::Equal => (lhs == rhs) as i64,
+                CppTokenKind::NotEqual => (lhs != rhs) as i64,
+                CppTokenKind::Less => (lhs < rhs) as i64,
+                CppTokenKind::Greater => (lhs > rhs) as i64,
+                CppTokenKind::LessEqual => (lhs <= rhs) as i64,
+                CppTokenKind::GreaterEqual => (lhs >= rhs) as i64,
+                CppTokenKind::LeftShift => lhs.wrapping_shl(rhs as u32),
+                CppTokenKind::RightShift => lhs.wrapping_shr(rhs as u32),
+                CppTokenKind::Plus => lhs.wrapping_add(rhs),
+                CppTokenKind::Minus => lhs.wrapping_sub(rhs),
+                CppTokenKind::Star => lhs.wrapping_mul(rhs),
+                CppTokenKind::Slash | CppTokenKind::Percent => {
+                    if rhs == 0 {
+                        return Err(
+                            self.error(&t!("division by zero in preprocessor expression"), range)
+                        );
+                    }
+                    if operator == CppTokenKind::Slash {
+                        lhs.wrapping_div(rhs)
+                    } else {
+                        lhs.wrapping_rem(rhs)
+                    }
+                }
+                _ => unreachable!(),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<i64, CppParseError> {
+        let Some(token) = self.current() else {
+            return Err(self.error(&t!("expected value in expression"), self.current_range()));
+        };
+
+        let token = token.clone();
+        self.index += 1;
+        match token.kind {
+            CppTokenKind::Plus => self.unary(),
+            CppTokenKind::Minus => Ok(self.unary()?.wrapping_neg()),
+            CppTokenKind::Tilde => Ok(!self.unary()?),
+            CppTokenKind::LogicalNot => Ok((self.unary()? == 0) as i64),
+            CppTokenKind::LeftParen => {
+                let value = self.conditional()?;
+                if self.current_kind() != CppTokenKind::RightParen {
+                    return Err(self.error(&t!("missing ')' in expression"), self.current_range()));
+                }
+                self.index += 1;
+                Ok(value)
+            }
+            CppTokenKind::IntegerLiteral => parse_integer(&token.text).ok_or_else(|| {
+                self.error(
+                    &t!("invalid integer literal in preprocessor expression"),
+                    token.spelling_range,
+                )
+            }),
+            CppTokenKind::CharLiteral => parse_char(&token.text).ok_or_else(|| {
+                self.error(
+                    &t!("invalid character literal in preprocessor expression"),
+                    token.spelling_range,
+                )
+            }),
+            // Names left after macro expansion are 0, except `true`
+            _ if token.is_name() => Ok((token.text == "true") as i64),
+            _ => Err(self.error(
+                &t!(
+                    "token '%{token}' is not valid in a preprocessor expression",
+                    token = token.text
+                ),
+                token.spelling_range,
+            )),
+        }
+    }
+}
+
+fn binary_precedence(kind: CppTokenKind) -> Option<u8> {
+    let precedence = match kind {
+        CppTokenKind::LogicalOr => 1,
+        CppTokenKind::LogicalAnd => 2,
+        CppTokenKind::Pipe => 3,
+        CppTokenKind::Caret => 4,
+        CppTokenKind::Ampersand => 5,
+        CppTokenKind::Equal | CppTokenKind::NotEqual => 6,
+        CppTokenKind::Less
+        | CppTokenKind::Greater
+        | CppTokenKind::LessEqual
+        | CppTokenKind::GreaterEqual => 7,
+        CppTokenKind::LeftShift | CppTokenKind::RightShift => 8,
+        CppTokenKind::Plus | CppTokenKind::Minus => 9,
+        CppTokenKind::Star | CppTokenKind::Slash | CppTokenKind::Percent => 10,
+        _ => return None,
+    };
+    Some(precedence)
+}
+
+/// Value of an integer literal with its separators and suffixes
+fn parse_integer(text: &str) -> Option<i64> {
+    let digits: String = text.chars().filter(|ch| *ch != '\'').collect();
+    let lower = digits.to_ascii_lowercase();
+    let (radix, digits) = if let Some(rest) = lower.strip_prefix("0x") {
+        (16, rest)
+    } else if let Some(rest) = lower.strip_prefix("0b") {
+        (2, rest)
+    } else if lower.len() > 1 && lower.starts_with('0') {
+        (8, &lower[1..])
+    } else {
+        (10, lower.as_str())
+    };
+
+    let end = digits
+        .find(|ch: char| !ch.is_digit(radix))
+        .unwrap_or(digits.len());
+    if !digits[end..]
+        .chars()
+        .all(|ch| matches!(ch, 'u' | 'l' | 'z'))
+    {
+        return None;
+    }
+    u64::from_str_radix(&digits[..end], radix)
+        .ok()
+        .map(|value| value as i64)
+}
+
+/// Value of a character literal, only single characters and simple escapes
+fn parse_char(text: &str) -> Option<i64> {
+    let start = text.find('\'')?;
+    let content = text[start + 1..].strip_suffix('\'')?;
+    let mut chars = content.chars();
+    let value = match chars.next()? {
+        '\\' => match chars.next()? {
+            'n' => '\n' as i64,
+            't' => '\t' as i64,
+            'r' => '\r' as i64,
+            '0' => 0,
+            'a' => 7,
+            'b' => 8,
+            'f' => 12,
+            'v' => 11,
+            ch => ch as i64,
+        },
+        ch => ch as i64,
+    };
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(value)
+}
+
+fn push_error(errors: &mut Vec<CppParseError>, message: &str, range: TextRange) {
+    errors.push(CppParseError::new(
+        CppParseErrorKind::PreprocessorError,
+        message,
+        range,
+    ));
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use rowan::TextRange;
+
+use crate::{
+    kind::CppTokenKind,
+    lexer::{CppLexer, LexerConfig},
+    parser_error::{CppParseError, CppParseErrorKind},
+};
+
+use super::CppPpToken;
+
+/// Names the preprocessor expands by itself
+pub const BUILTIN_MACROS: [&str; 4] = ["__LINE__", "__FILE__", "__COUNTER__", "__cplusplus"];
+
+/// A macro defined by `#define`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CppMacro {
+    name: String,
+    /// `None` for object-like macros, the variadic parameter of `(a, ...)` is `__VA_ARGS__`
+    params: Option<Vec<String>>,
+    is_variadic: bool,
+    body: Vec<CppPpToken>,
+    name_range: TextRange,
+}
+
+impl CppMacro {
+    /// Build a macro from the tokens after `#define`. A malformed definition gives `None`,
+    /// the directive grammar already reports those.
+    pub(crate) fn from_define(
+        tokens: &[CppPpToken],
+        errors: &mut Vec<CppParseError>,
+    ) -> Option<CppMacro> {
+        let name = tokens.first().filter(|token| token.is_name())?;
+        let mut index = 1;
+
+        let mut params = None;
+        let mut is_variadic = false;
+        if tokens
+            .get(index)
+            .is_some_and(|token| token.kind == CppTokenKind::LeftParen && !token.has_leading_space)
+        {
+            index += 1;
+            let mut names = Vec::new();
+            loop {
+                let token = tokens.get(index)?;
+                match token.kind {
+                    CppTokenKind::RightParen if names.is_empty() => break,
+                    CppTokenKind::Ellipsis => {
+                        names.push("__VA_ARGS__".to_string());
+                        is_variadic = true;
+                        index += 1;
+                    }
+                    _ if token.is_name() => {
+                        names.push(token.text.clone());
+                        index += 1;
+                        // GNU named variadic parameter: args...
+                        if tokens
+                            .get(index)
+                            .is_some_and(|token| token.kind == CppTokenKind::Ellipsis)
+                        {
+                            is_variadic = true;
+                            index += 1;
+                        }
+                    }
+                    _ => return None,
+                }
+
+                match tokens.get(index)?.kind {
+                    CppTokenKind::Comma if !is_variadic => index += 1,
+                    CppTokenKind::RightParen => break,
+                    _ => return None,
+                }
+            }
+            index += 1;
+            params = Some(names);
+        }
+
+        let cpp_macro = CppMacro {
+            name: name.text.clone(),
+            params,
+            is_variadic,
+            body: tokens[index..].to_vec(),
+            name_range: name.spelling_range,
+        };
+        cpp_macro.check_body(errors);
+        Some(cpp_macro)
+    }
+
+    fn check_body(&self, errors: &mut Vec<CppParseError>) {
+        let body = &self.body;
+        for (i, token) in body.iter().enumerate() {
+            match token.kind {
+                CppTokenKind::HashHash if i == 0 || i + 1 == body.len() => {
+                    errors.push(CppParseError::new(
+                        CppParseErrorKind::PreprocessorError,
+                        &t!("'##' cannot appear at either end of a macro expansion"),
+                        token.spelling_range,
+                    ));
+                }
+                CppTokenKind::Hash if self.is_function_like() => {
+                    let operand = body.get(i + 1);
+                    if !operand.is_some_and(|operand| {
+                        self.param_index(operand).is_some() || operand.text == "__VA_OPT__"
+                    }) {
+                        errors.push(CppParseError::new(
+                            CppParseErrorKind::PreprocessorError,
+                            &t!("'#' is not followed by a macro parameter"),
+                            token.spelling_range,
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_name_range(&self) -> TextRange {
+        self.name_range
+    }
+
+    pub fn get_params(&self) -> Option<&[String]> {
+        self.params.as_deref()
+    }
+
+    pub fn is_function_like(&self) -> bool {
+        self.params.is_some()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+
+    pub fn get_body(&self) -> &[CppPpToken] {
+        &self.body
+    }
+
+    pub(crate) fn param_index(&self, token: &CppPpToken) -> Option<usize> {
+        if !token.is_name() {
+            return None;
+        }
+        self.params
+            .as_ref()?
+            .iter()
+            .position(|param| *param == token.text)
+    }
+
+    pub(crate) fn is_variadic_param(&self, index: usize) -> bool {
+        self.is_variadic
+            && self
+                .params
+                .as_ref()
+                .is_some_and(|params| index + 1 == params.len())
+    }
+}
+
+/// The macros defined at some point of the translation unit
+#[derive(Debug, Clone, Default)]
+pub struct CppMacroTable {
+    macros: HashMap<String, CppMacro>,
+}
+
+impl CppMacroTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define a macro from the text that follows `#define`, e.g. `VERSION 2` or
+    /// `MAX(a, b) ((a) > (b) ? (a) : (b))`. Such macros are not written in any file, their
+    /// tokens have empty ranges. Returns false for a malformed definition.
+    pub fn define_text(&mut self, definition: &str) -> bool {
+        let mut errors = Vec::new();
+        let tokens = CppLexer::new(definition, LexerConfig::default(), &mut errors).tokenize();
+        let mut pp_tokens = Vec::new();
+        let mut has_leading_space = false;
+        for token in &tokens {
+            if token.kind.is_trivia() {
+                has_leading_space = true;
+                continue;
+            }
+            let mut pp_token = CppPpToken::from_token_data(token, definition, has_leading_space);
+            pp_token.spelling_range = TextRange::default();
+            pp_token.expansion_range = TextRange::default();
+            pp_tokens.push(pp_token);
+            has_leading_space = false;
+        }
+
+        match CppMacro::from_define(&pp_tokens, &mut errors) {
+            Some(cpp_macro) if errors.is_empty() => {
+                self.define(cpp_macro);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the definition that was replaced, if any
+    pub fn define(&mut self, cpp_macro: CppMacro) -> Option<CppMacro> {
+        self.macros.insert(cpp_macro.name.clone(), cpp_macro)
+    }
+
+    pub fn undef(&mut self, name: &str) -> Option<CppMacro> {
+        self.macros.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CppMacro> {
+        self.macros.get(name)
+    }
+
+    /// Builtin macros like `__LINE__` count as defined
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.macros.contains_key(name) || BUILTIN_MACROS.contains(&name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CppMacro> {
+        self.macros.values()
+    }
+}
@@ -0,0 +1,648 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use rowan::TextRange;
+
+use crate::{
+    kind::{CppLanguageLevel, CppTokenKind},
+    lexer::{CppLexer, CppTokenData, LexerConfig},
+    parser_error::{CppParseError, CppParseErrorKind},
+    text::LineIndex,
+};
+
+use super::{
+    condition::{evaluate_condition, replace_condition_operators},
+    CppHasInclude, CppIncludeEdge, CppIncludeGraph, CppIncludeLocation, CppIncludeResolver,
+    CppMacro, CppMacroExpander, CppMacroTable, CppPpToken, CppVfs,
+};
+
+/// Deeper includes are most likely a header that includes itself without a guard
+const MAX_INCLUDE_DEPTH: usize = 200;
+
+/// The tokens of a file after preprocessing
+#[derive(Debug, Clone)]
+pub struct CppPreprocessResult {
+    pub tokens: Vec<CppPpToken>,
+    /// Lines skipped by conditional compilation, from the line after the directive up to the
+    /// line of the directive that ends the branch
+    pub inactive_ranges: Vec<TextRange>,
+    pub errors: Vec<CppParseError>,
+}
+
+/// Runs the directives of a file and expands its macros. The macro table lives on across
+/// files, so headers can be preprocessed before the file that includes them.
+pub struct CppPreprocessor {
+    file_name: String,
+    lexer_config: LexerConfig,
+    macros: CppMacroTable,
+    counter: usize,
+    has_include: Box<CppHasInclude>,
+    includes: Option<IncludeContext>,
+}
+
+/// What the preprocessor needs to follow `#include`
+struct IncludeContext {
+    vfs: Box<dyn CppVfs>,
+    resolver: CppIncludeResolver,
+    /// The file being preprocessed and the files that include it
+    stack: Vec<CppIncludeLocation>,
+    pragma_once: HashSet<PathBuf>,
+    /// The guard macro of headers that are wrapped in `#ifndef GUARD`
+    guards: HashMap<PathBuf, String>,
+    graph: CppIncludeGraph,
+}
+
+impl IncludeContext {
+    fn resolve(
+        &self,
+        header: &str,
+        is_angled: bool,
+        is_include_next: bool,
+        file_name: &str,
+    ) -> Option<CppIncludeLocation> {
+        let includer = match self.stack.last() {
+            Some(includer) => includer.clone(),
+            None => CppIncludeLocation::main_file(Path::new(file_name)),
+        };
+        if is_include_next {
+            self.resolver
+                .resolve_next(&*self.vfs, header, is_angled, &includer)
+        } else {
+            self.resolver
+                .resolve(&*self.vfs, header, is_angled, &includer)
+        }
+    }
+}
+
+/// An open `#if` and its branches
+struct Conditional {
+    /// The `#if` itself, for unterminated conditionals
+    range: TextRange,
+    /// Whether the code around the conditional is active
+    parent_active: bool,
+    active: bool,
+    /// Whether one of the branches was taken already
+    taken: bool,
+    has_else: bool,
+}
+
+/// A line of significant tokens, `start` and `end` include the newline and the trivia
+struct LogicalLine {
+    start: usize,
+    end: usize,
+    tokens: Vec<CppPpToken>,
+}
+
+impl CppPreprocessor {
+    pub fn new(file_name: &str, level: CppLanguageLevel) -> Self {
+        CppPreprocessor {
+            file_name: file_name.to_string(),
+            lexer_config: LexerConfig {
+                language_level: level,
+            },
+            macros: CppMacroTable::new(),
+            counter: 0,
+            has_include: Box::new(|_, _| false),
+            includes: None,
+        }
+    }
+
+    pub fn get_macros(&self) -> &CppMacroTable {
+        &self.macros
+    }
+
+    pub fn get_macros_mut(&mut self) -> &mut CppMacroTable {
+        &mut self.macros
+    }
+
+    /// Decides `__has_include` when there is no include resolver, by default no header exists
+    pub fn set_has_include(&mut self, has_include: Box<CppHasInclude>) {
+        self.has_include = has_include;
+    }
+
+    /// Follow `#include` into the files of `vfs`. The macros of a header are defined where it
+    /// is included, its tokens and errors are not part of the result.
+    pub fn set_include_resolver(&mut self, vfs: Box<dyn CppVfs>, resolver: CppIncludeResolver) {
+        self.includes = Some(IncludeContext {
+            vfs,
+            resolver,
+            stack: Vec::new(),
+            pragma_once: HashSet::new(),
+            guards: HashMap::new(),
+            graph: CppIncludeGraph::default(),
+        });
+    }
+
+    /// The includes of the last file given to `preprocess_file`
+    pub fn get_include_graph(&self) -> Option<&CppIncludeGraph> {
+        self.includes.as_ref().map(|context| &context.graph)
+    }
+
+    pub fn preprocess(&mut self, text: &str) -> CppPreprocessResult {
+        // The parser already reports the errors of the lexer
+        let mut lex_errors = Vec::new();
+        let tokens = CppLexer::new(text, self.lexer_config, &mut lex_errors).tokenize();
+        self.run(text, &tokens, true)
+    }
+
+    /// Preprocess the main file of a translation unit, quoted includes are looked up next to
+    /// `path`
+    pub fn preprocess_file(&mut self, path: &Path, text: &str) -> CppPreprocessResult {
+        self.start_main_file(path);
+        let result = self.preprocess(text);
+        self.finish_main_file();
+        result
+    }
+
+    /// Run the directives of the main file `path` after its forced includes, only the
+    /// inactive ranges and the errors come out. This is what the parser needs.
+    pub(crate) fn run_main_file(
+        &mut self,
+        path: &Path,
+        forced_includes: &[PathBuf],
+        text: &str,
+        tokens: &[CppTokenData],
+    ) -> CppPreprocessResult {
+        self.start_main_file(path);
+        let mut errors = Vec::new();
+        for header in forced_includes {
+            self.include_forced(header, &mut errors);
+        }
+        let mut result = self.run(text, tokens, false);
+        self.finish_main_file();
+
+        errors.append(&mut result.errors);
+        result.errors = errors;
+        result
+    }
+
+    fn start_main_file(&mut self, path: &Path) {
+        self.file_name = path.display().to_string();
+        if let Some(context) = &mut self.includes {
+            let main_file = CppIncludeLocation::main_file(path);
+            context.graph = CppIncludeGraph::new(&main_file.path);
+            context.stack = vec![main_file];
+            context.pragma_once.clear();
+            context.guards.clear();
+        }
+    }
+
+    fn finish_main_file(&mut self) {
+        if let Some(context) = &mut self.includes {
+            context.stack.clear();
+        }
+    }
+
+    /// Run the directives over the tokens of `text`. Without `expand_text` only the
+    /// directives are looked at and no tokens come out, which is enough to know the inactive
+    /// ranges.
+    pub(crate) fn run(
+        &mut self,
+        text: &str,
+        tokens: &[CppTokenData],
+        expand_text: bool,
+    ) -> CppPreprocessResult {
+        self.run_lines(text, logical_lines(tokens, text), expand_text)
+    }
+
+    fn run_lines(
+        &mut self,
+        text: &str,
+        lines: Vec<LogicalLine>,
+        expand_text: bool,
+    ) -> CppPreprocessResult {
+        let line_index = LineIndex::parse(text);
+        let mut errors = Vec::new();
+        let mut output = Vec::new();
+        let mut conditionals: Vec<Conditional> = Vec::new();
+        let mut inactive_start: Option<usize> = None;
+        let mut inactive_ranges = Vec::new();
+        let mut add_inactive_range = |start: usize, end: usize| {
+            if start < end {
+                inactive_ranges.push(TextRange::new((start as u32).into(), (end as u32).into()));
+            }
+        };
+
+        // Text lines are expanded together, the arguments of a macro may span lines
+        let mut text_tokens = Vec::new();
+        for line in lines {
+            let active = conditionals.last().is_none_or(|it| it.active);
+            if line.tokens[0].kind != CppTokenKind::Hash {
+                if active && expand_text {
+                    text_tokens.extend(line.tokens);
+                }
+                continue;
+            }
+
+            let tokens = &line.tokens;
+            let name = tokens.get(1).map_or("", |token| token.text.as_str());
+            let range = match tokens.get(1) {
+                Some(name) => tokens[0].spelling_range.cover(name.spelling_range),
+                None => tokens[0].spelling_range,
+            };
+            let args = tokens.get(2..).unwrap_or_default();
+            match name {
+                "if" | "ifdef" | "ifndef" => {
+                    let value = active && self.evaluate(name, args, &line_index, &mut errors);
+                    conditionals.push(Conditional {
+                        range,
+                        parent_active: active,
+                        active: value,
+                        taken: value,
+                        has_else: false,
+                    });
+                    if active && !value {
+                        inactive_start = Some(line.end);
+                    }
+                }
+                "elif" | "elifdef" | "elifndef" | "else" => {
+                    let Some(conditional) = conditionals.last_mut() else {
+                        push_error(&mut errors, &t!("#%{name} without #if", name = name), range);
+                        continue;
+                    };
+                    if !conditional.parent_active {
+                        continue;
+                    }
+                    if conditional.has_else {
+                        push_error(&mut errors, &t!("#%{name} after #else", name = name), range);
+                    }
+                    if let Some(start) = inactive_start.take() {
+                        add_inactive_range(start, line.start);
+                    }
+
+                    let value = !conditional.taken
+                        && (name == "else" || self.evaluate(name, args, &line_index, &mut errors));
+                    conditional.active = value;
+                    conditional.taken |= value;
+                    conditional.has_else |= name == "else";
+                    if !value {
+                        inactive_start = Some(line.end);
+                    }
+                }
+                "endif" => {
+                    let Some(conditional) = conditionals.pop() else {
+                        push_error(&mut errors, &t!("#endif without #if"), range);
+                        continue;
+                    };
+                    if conditional.parent_active
+                        && let Some(start) = inactive_start.take()
+                    {
+                        add_inactive_range(start, line.start);
+                    }
+                }
+                _ if active => {
+                    let text_tokens = std::mem::take(&mut text_tokens);
+                    output.extend(self.expand(text_tokens, &line_index, &mut errors));
+                    self.run_directive(tokens, &line_index, &mut errors);
+                }
+                _ => {}
+            }
+        }
+        output.extend(self.expand(text_tokens, &line_index, &mut errors));
+
+        for conditional in &conditionals {
+            push_error(
+                &mut errors,
+                &t!("unterminated conditional directive"),
+                conditional.range,
+            );
+        }
+        if let Some(start) = inactive_start {
+            add_inactive_range(start, text.len());
+        }
+
+        CppPreprocessResult {
+            tokens: output,
+            inactive_ranges,
+            errors,
+        }
+    }
+
+    /// Evaluate the condition of an `#if`, `#ifdef` or one of their `#elif` forms
+    fn evaluate(
+        &mut self,
+        directive: &str,
+        args: &[CppPpToken],
+        line_index: &LineIndex,
+        errors: &mut Vec<CppParseError>,
+    ) -> bool {
+        let is_defined = || {
+            args.first()
+                .is_some_and(|name| name.is_name() && self.macros.is_defined(&name.text))
+        };
+        match directive {
+            "ifdef" | "elifdef" => is_defined(),
+            "ifndef" | "elifndef" => !is_defined(),
+            // The directive grammar reports a missing expression
+            _ if args.is_empty() => false,
+            _ => {
+                let has_include =
+                    |header: &str, is_angled: bool, is_include_next: bool| match &self.includes {
+                        Some(context) => context
+                            .resolve(header, is_angled, is_include_next, &self.file_name)
+                            .is_some(),
+                        None => (self.has_include)(header, is_angled),
+                    };
+                let tokens = replace_condition_operators(args, &self.macros, &has_include, errors);
+                if tokens.is_empty() {
+                    return false;
+                }
+                let tokens = self.expand(tokens, line_index, errors);
+                match evaluate_condition(&tokens) {
+                    Ok(value) => value != 0,
+                    Err(err) => {
+                        errors.push(err);
+                        false
+                    }
+                }
+            }
+        }
+    }
+
+    fn expand(
+        &mut self,
+        tokens: Vec<CppPpToken>,
+        line_index: &LineIndex,
+        errors: &mut Vec<CppParseError>,
+    ) -> Vec<CppPpToken> {
+        if tokens.is_empty() {
+            return tokens;
+        }
+
+        CppMacroExpander::new(
+            &self.macros,
+            &self.file_name,
+            line_index,
+            &mut self.counter,
+            self.lexer_config,
+            errors,
+        )
+        .expand(tokens)
+    }
+
+    /// `tokens` are the whole directive, `#` included
+    fn run_directive(
+        &mut self,
+        tokens: &[CppPpToken],
+        line_index: &LineIndex,
+        errors: &mut Vec<CppParseError>,
+    ) {
+        let Some(name) = tokens.get(1) else {
+            return;
+        };
+
+        match name.text.as_str() {
+            "define" => {
+                if let Some(cpp_macro) = CppMacro::from_define(&tokens[2..], errors) {
+                    self.macros.define(cpp_macro);
+                }
+            }
+            "undef" => {
+                if let Some(name) = tokens.get(2) {
+                    self.macros.undef(&name.text);
+                }
+            }
+            "include" | "include_next" => self.include(tokens, line_index, errors),
+            "pragma" => {
+                if tokens.get(2).is_some_and(|token| token.text == "once")
+                    && let Some(context) = &mut self.includes
+                    && let Some(file) = context.stack.last()
+                {
+                    context.pragma_once.insert(file.path.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Run the directives of an included file, unless `#pragma once` or its include guard
+    /// keep it out
+    fn include(
+        &mut self,
+        tokens: &[CppPpToken],
+        line_index: &LineIndex,
+        errors: &mut Vec<CppParseError>,
+    ) {
+        if self.includes.is_none() {
+            return;
+        }
+        let range = tokens[0]
+            .spelling_range
+            .cover(tokens[tokens.len() - 1].spelling_range);
+        let Some((header, is_angled)) = self.header_name(&tokens[2..], line_index, errors) else {
+            return;
+        };
+        let is_include_next = tokens[1].text == "include_next";
+        self.include_header(&header, is_angled, is_include_next, range, errors);
+    }
+
+    /// `-include path`: the header is read as if the first line of the main file included it
+    fn include_forced(&mut self, path: &Path, errors: &mut Vec<CppParseError>) {
+        let header = path.display().to_string();
+        self.include_header(&header, false, false, TextRange::default(), errors);
+    }
+
+    /// Resolve `header`, record it in the include graph and run its directives
+    fn include_header(
+        &mut self,
+        header: &str,
+        is_angled: bool,
+        is_include_next: bool,
+        range: TextRange,
+        errors: &mut Vec<CppParseError>,
+    ) {
+        let Some(context) = &mut self.includes else {
+            return;
+        };
+        let location = context.resolve(header, is_angled, is_include_next, &self.file_name);
+        let includer = match context.stack.last() {
+            Some(includer) => includer.path.clone(),
+            None => CppIncludeLocation::main_file(Path::new(&self.file_name)).path,
+        };
+        context.graph.add_edge(CppIncludeEdge {
+            includer,
+            header: header.to_string(),
+            is_angled,
+            is_include_next,
+            range,
+            resolved: location.as_ref().map(|location| location.path.clone()),
+        });
+
+        let Some(location) = location else {
+            push_error(
+                errors,
+                &t!("'%{header}' file not found", header = header),
+                range,
+            );
+            return;
+        };
+        if context.pragma_once.contains(&location.path) {
+            return;
+        }
+        if let Some(guard) = context.guards.get(&location.path)
+            && self.macros.is_defined(guard)
+        {
+            return;
+        }
+        if context.stack.len() >= MAX_INCLUDE_DEPTH {
+            push_error(errors, &t!("#include nested too deeply"), range);
+            return;
+        }
+        let Some(text) = context.vfs.read_file(&location.path) else {
+            push_error(
+                errors,
+                &t!("'%{header}' file not found", header = header),
+                range,
+            );
+            return;
+        };
+
+        let mut lex_errors = Vec::new();
+        let header_tokens = CppLexer::new(&text, self.lexer_config, &mut lex_errors).tokenize();
+        let lines = logical_lines(&header_tokens, &text);
+        let guard = include_guard(&lines);
+        context.stack.push(location.clone());
+        let file_name = std::mem::replace(&mut self.file_name, location.path.display().to_string());
+        self.run_lines(&text, lines, false);
+        self.file_name = file_name;
+
+        if let Some(context) = &mut self.includes {
+            context.stack.pop();
+            if let Some(guard) = guard {
+                context.guards.insert(location.path, guard);
+            }
+        }
+    }
+
+    /// The header of an `#include` without delimiters and whether it is `<...>`. A computed
+    /// include like `#include HEADER` is expanded first.
+    fn header_name(
+        &mut self,
+        tokens: &[CppPpToken],
+        line_index: &LineIndex,
+        errors: &mut Vec<CppParseError>,
+    ) -> Option<(String, bool)> {
+        let first = tokens.first()?;
+        if first.kind == CppTokenKind::HeaderName {
+            let text = &first.text;
+            let header = text[1..].trim_end_matches(['>', '"']);
+            return Some((header.to_string(), text.starts_with('<')));
+        }
+
+        let expanded = self.expand(tokens.to_vec(), line_index, errors);
+        match expanded.first() {
+            Some(token) if token.kind == CppTokenKind::StringLiteral && expanded.len() == 1 => {
+                let header = token.text.strip_prefix('"')?.strip_suffix('"')?;
+                Some((header.to_string(), false))
+            }
+            Some(token) if token.kind == CppTokenKind::Less => {
+                let end = expanded
+                    .iter()
+                    .position(|token| token.kind == CppTokenKind::Greater)?;
+                let mut header = String::new();
+                for token in &expanded[1..end] {
+                    if token.has_leading_space && !header.is_empty() {
+                        header.push(' ');
+                    }
+                    header.push_str(&token.text);
+                }
+                Some((header, true))
+            }
+            _ => {
+                push_error(
+                    errors,
+                    &t!("expected \"FILENAME\" or <FILENAME>"),
+                    first.spelling_range,
+                );
+                None
+            }
+        }
+    }
+}
+
+fn push_error(errors: &mut Vec<CppParseError>, message: &str, range: TextRange) {
+    errors.push(CppParseError::new(
+        CppParseErrorKind::PreprocessorError,
+        message,
+        range,
+    ));
+}
+
+/// The macro of an include guard, when the whole file is inside `#ifndef GUARD` or
+/// `#if !defined(GUARD)` without an `#else`
+fn include_guard(lines: &[LogicalLine]) -> Option<String> {
+    let first = &lines.first()?.tokens;
+    let texts: Vec<&str> = first.iter().map(|token| token.text.as_str()).collect();
+    let guard = match texts.as_slice() {
+        ["#", "ifndef", guard]
+        | ["#", "if", "!", "defined", guard]
+        | ["#", "if", "!", "defined", "(", guard, ")"] => guard.to_string(),
+        _ => return None,
+    };
+
+    let mut depth = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if line.tokens[0].kind != CppTokenKind::Hash {
+            continue;
+        }
+        match line.tokens.get(1).map(|token| token.text.as_str()) {
+            Some("if" | "ifdef" | "ifndef") => depth += 1,
+            Some("elif" | "elifdef" | "elifndef" | "else") if depth == 1 => return None,
+            Some("endif") => {
+                depth -= 1;
+                if depth == 0 {
+                    return (i + 1 == lines.len()).then_some(guard);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split the significant tokens into logical lines, continued lines are already joined by
+/// the lexer
+fn logical_lines(tokens: &[CppTokenData], text: &str) -> Vec<LogicalLine> {
+    let mut lines = Vec::new();
+    let mut line = LogicalLine {
+        start: 0,
+        end: 0,
+        tokens: Vec::new(),
+    };
+    let mut has_leading_space = false;
+    for token in tokens {
+        match token.kind {
+            CppTokenKind::Newline => {
+                let end = token.range.end_offset();
+                if line.tokens.is_empty() {
+                    line.start = end;
+                } else {
+                    line.end = end;
+                    let next = LogicalLine {
+                        start: end,
+                        end: 0,
+                        tokens: Vec::new(),
+                    };
+                    lines.push(std::mem::replace(&mut line, next));
+                }
+                has_leading_space = true;
+            }
+            CppTokenKind::Whitespace | CppTokenKind::LineComment | CppTokenKind::BlockComment => {
+                has_leading_space = true;
+            }
+            _ => {
+                line.tokens
+                    .push(CppPpToken::from_token_data(token, text, has_leading_space));
+                has_leading_space = false;
+            }
+        }
+    }
+    if !line.tokens.is_empty() {
+        line.end = text.len();
+        lines.push(line);
+    }
+
+    lines
+}
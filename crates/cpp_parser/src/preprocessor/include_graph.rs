@@ -0,0 +1,111 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    path::{Path, PathBuf},
+};
+
+use rowan::TextRange;
+
+/// An `#include` seen while preprocessing a translation unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CppIncludeEdge {
+    /// The file that has the directive
+    pub includer: PathBuf,
+    /// The header without its delimiters
+    pub header: String,
+    pub is_angled: bool,
+    pub is_include_next: bool,
+    /// The directive, in the includer
+    pub range: TextRange,
+    /// `None` when no search path has the header
+    pub resolved: Option<PathBuf>,
+}
+
+/// Who includes whom in a translation unit. Only directives in active code are part of it,
+/// a header that is included twice has its own includes once.
+#[derive(Debug, Clone, Default)]
+pub struct CppIncludeGraph {
+    main_file: PathBuf,
+    edges: Vec<CppIncludeEdge>,
+}
+
+impl CppIncludeGraph {
+    pub fn new(main_file: &Path) -> Self {
+        CppIncludeGraph {
+            main_file: main_file.to_path_buf(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub(crate) fn add_edge(&mut self, edge: CppIncludeEdge) {
+        // A header without guard may be walked twice, its directives stay the same
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    pub fn get_main_file(&self) -> &Path {
+        &self.main_file
+    }
+
+    /// Every include in the order the preprocessor reached them
+    pub fn get_edges(&self) -> &[CppIncludeEdge] {
+        &self.edges
+    }
+
+    /// The includes written in `file`
+    pub fn get_includes<'a>(&'a self, file: &'a Path) -> impl Iterator<Item = &'a CppIncludeEdge> {
+        self.edges.iter().filter(move |edge| edge.includer == file)
+    }
+
+    /// The files that include `file` directly
+    pub fn get_includers(&self, file: &Path) -> Vec<&Path> {
+        let mut includers = Vec::new();
+        for edge in &self.edges {
+            let includer = edge.includer.as_path();
+            if edge.resolved.as_deref() == Some(file) && !includers.contains(&includer) {
+                includers.push(includer);
+            }
+        }
+        includers
+    }
+
+    /// The files `file` includes directly or through other headers, nearest first
+    pub fn get_transitive_includes(&self, file: &Path) -> Vec<PathBuf> {
+        self.closure(file, |edge| {
+            Some((edge.includer.as_path(), edge.resolved.as_deref()?))
+        })
+    }
+
+    /// The files that include `file` directly or through other headers, nearest first
+    pub fn get_transitive_includers(&self, file: &Path) -> Vec<PathBuf> {
+        self.closure(file, |edge| {
+            Some((edge.resolved.as_deref()?, edge.includer.as_path()))
+        })
+    }
+
+    /// Includes that no search path has
+    pub fn get_unresolved(&self) -> impl Iterator<Item = &CppIncludeEdge> {
+        self.edges.iter().filter(|edge| edge.resolved.is_none())
+    }
+
+    /// Breadth first walk over the edges, `direction` gives the `(from, to)` of an edge
+    fn closure<'a>(
+        &'a self,
+        file: &Path,
+        direction: impl Fn(&'a CppIncludeEdge) -> Option<(&'a Path, &'a Path)>,
+    ) -> Vec<PathBuf> {
+        let mut visited: HashSet<&Path> = HashSet::new();
+        let mut result = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(file);
+        while let Some(current) = queue.pop_front() {
+            for (from, to) in self.edges.iter().filter_map(&direction) {
+                if from == current && to != file && visited.insert(to) {
+                    result.push(to.to_path_buf());
+                    queue.push_back(to);
+                }
+            }
+        }
+        result
+    }
+}
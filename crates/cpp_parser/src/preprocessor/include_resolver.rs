@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+
+use super::{vfs::normalize_path, CppVfs};
+
+/// Where an included file was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CppIncludeLocation {
+    pub path: PathBuf,
+    /// The index of the search path that has the file, `None` for the main file and for
+    /// files found next to their includer. `#include_next` continues after this path.
+    pub search_index: Option<usize>,
+    /// Found in a `-isystem` path, or next to a file that was
+    pub is_system: bool,
+}
+
+impl CppIncludeLocation {
+    pub fn main_file(path: &Path) -> Self {
+        CppIncludeLocation {
+            path: normalize_path(path),
+            search_index: None,
+            is_system: false,
+        }
+    }
+}
+
+/// Maps the header of an `#include` to a file. Quoted headers are first looked up next to
+/// the includer, then in the quote, include, system and framework paths in that order.
+/// Angled headers skip the directory of the includer and the quote paths.
+#[derive(Debug, Clone, Default)]
+pub struct CppIncludeResolver {
+    /// `-iquote`
+    pub quote_paths: Vec<PathBuf>,
+    /// `-I`
+    pub include_paths: Vec<PathBuf>,
+    /// `-isystem` and the builtin paths of the compiler
+    pub system_paths: Vec<PathBuf>,
+    /// `-F`, `<Foo/Bar.h>` is `Foo.framework/Headers/Bar.h` in one of them
+    pub framework_paths: Vec<PathBuf>,
+}
+
+impl CppIncludeResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Find `header` for an `#include` in `includer`, `header` has no delimiters
+    pub fn resolve(
+        &self,
+        vfs: &dyn CppVfs,
+        header: &str,
+        is_angled: bool,
+        includer: &CppIncludeLocation,
+    ) -> Option<CppIncludeLocation> {
+        if Path::new(header).is_absolute() {
+            return vfs.is_file(Path::new(header)).then(|| CppIncludeLocation {
+                path: normalize_path(Path::new(header)),
+                search_index: None,
+                is_system: false,
+            });
+        }
+
+        if !is_angled && let Some(dir) = includer.path.parent() {
+            let path = normalize_path(&dir.join(header));
+            if vfs.is_file(&path) {
+                return Some(CppIncludeLocation {
+                    path,
+                    search_index: None,
+                    is_system: includer.is_system,
+                });
+            }
+        }
+
+        let start = if is_angled { self.quote_paths.len() } else { 0 };
+        self.search(vfs, header, start)
+    }
+
+    /// Find `header` for an `#include_next` in `includer`, the search starts after the
+    /// path that has the includer. Without such a path every search path is looked at.
+    pub fn resolve_next(
+        &self,
+        vfs: &dyn CppVfs,
+        header: &str,
+        is_angled: bool,
+        includer: &CppIncludeLocation,
+    ) -> Option<CppIncludeLocation> {
+        match includer.search_index {
+            Some(index) => self.search(vfs, header, index + 1),
+            None if Path::new(header).is_absolute() => {
+                self.resolve(vfs, header, is_angled, includer)
+            }
+            None => {
+                let start = if is_angled { self.quote_paths.len() } else { 0 };
+                self.search(vfs, header, start)
+            }
+        }
+    }
+
+    fn search(&self, vfs: &dyn CppVfs, header: &str, start: usize) -> Option<CppIncludeLocation> {
+        let system_start = self.quote_paths.len() + self.include_paths.len();
+        let framework_start = system_start + self.system_paths.len();
+        let search_paths = self
+            .quote_paths
+            .iter()
+            .chain(&self.include_paths)
+            .chain(&self.system_paths)
+            .chain(&self.framework_paths);
+
+        for (index, dir) in search_paths.enumerate().skip(start) {
+            let found = if index >= framework_start {
+                find_in_framework(vfs, dir, header)
+            } else {
+                let path = normalize_path(&dir.join(header));
+                vfs.is_file(&path).then_some(path)
+            };
+            if let Some(path) = found {
+                return Some(CppIncludeLocation {
+                    path,
+                    search_index: Some(index),
+                    is_system: (system_start..framework_start).contains(&index),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// `Foo/Bar.h` is `Foo.framework/Headers/Bar.h` or `Foo.framework/PrivateHeaders/Bar.h`
+fn find_in_framework(vfs: &dyn CppVfs, dir: &Path, header: &str) -> Option<PathBuf> {
+    let (framework, rest) = header.split_once('/')?;
+    let framework_dir = dir.join(format!("{}.framework", framework));
+    ["Headers", "PrivateHeaders"]
+        .iter()
+        .map(|headers| normalize_path(&framework_dir.join(headers).join(rest)))
+        .find(|path| vfs.is_file(path))
+}
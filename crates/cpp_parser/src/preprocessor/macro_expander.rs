@@ -0,0 +1,468 @@
+use std::collections::VecDeque;
+
+use rowan::TextRange;
+
+use crate::{
+    kind::{CppLanguageLevel, CppTokenKind},
+    lexer::{CppLexer, LexerConfig},
+    parser_error::{CppParseError, CppParseErrorKind},
+    text::LineIndex,
+};
+
+use super::{CppMacro, CppMacroTable, CppPpToken};
+
+/// Expands the macros of a token sequence. Every token carries the set of macros it came
+/// from (its hide set) and is never expanded by them again, which is what stops recursion
+/// during rescanning.
+pub struct CppMacroExpander<'a> {
+    macros: &'a CppMacroTable,
+    file_name: &'a str,
+    line_index: &'a LineIndex,
+    counter: &'a mut usize,
+    lexer_config: LexerConfig,
+    errors: &'a mut Vec<CppParseError>,
+}
+
+impl<'a> CppMacroExpander<'a> {
+    pub fn new(
+        macros: &'a CppMacroTable,
+        file_name: &'a str,
+        line_index: &'a LineIndex,
+        counter: &'a mut usize,
+        lexer_config: LexerConfig,
+        errors: &'a mut Vec<CppParseError>,
+    ) -> Self {
+        CppMacroExpander {
+            macros,
+            file_name,
+            line_index,
+            counter,
+            lexer_config,
+            errors,
+        }
+    }
+
+    pub fn expand(&mut self, tokens: Vec<CppPpToken>) -> Vec<CppPpToken> {
+        let mut input: VecDeque<CppPpToken> = tokens.into();
+        let mut output = Vec::new();
+
+        while let Some(token) = input.pop_front() {
+            if !token.is_name() || token.hide_set.contains(&token.text) {
+                output.push(token);
+                continue;
+            }
+
+            if let Some(builtin) = self.expand_builtin(&token) {
+                output.push(builtin);
+                continue;
+            }
+
+            let Some(cpp_macro) = self.macros.get(&token.text) else {
+                output.push(token);
+                continue;
+            };
+
+            let mut hide_set = token.hide_set.clone();
+            let (args, invocation) = if cpp_macro.is_function_like() {
+                if input
+                    .front()
+                    .is_none_or(|next| next.kind != CppTokenKind::LeftParen)
+                {
+                    // A function-like macro name without arguments is an ordinary name
+                    output.push(token);
+                    continue;
+                }
+
+                let Some((args, right_paren)) = self.collect_args(&token, cpp_macro, &mut input)
+                else {
+                    output.push(token);
+                    continue;
+                };
+                hide_set.retain(|name| right_paren.hide_set.contains(name));
+                let invocation = token.expansion_range.cover(right_paren.expansion_range);
+                (args, invocation)
+            } else {
+                (Vec::new(), token.expansion_range)
+            };
+            hide_set.push(cpp_macro.get_name().to_string());
+
+            let mut expanded_args = vec![None; args.len()];
+            let mut expanded =
+                self.substitute(cpp_macro, cpp_macro.get_body(), &args, &mut expanded_args);
+            for expanded_token in expanded.iter_mut() {
+                expanded_token.expansion_range = invocation;
+                expanded_token.hide_set.extend(hide_set.iter().cloned());
+            }
+            if let Some(first) = expanded.first_mut() {
+                first.has_leading_space = token.has_leading_space;
+            }
+
+            // The result is rescanned together with the rest of the input
+            for expanded_token in expanded.into_iter().rev() {
+                input.push_front(expanded_token);
+            }
+        }
+
+        output
+    }
+
+    fn expand_builtin(&mut self, token: &CppPpToken) -> Option<CppPpToken> {
+        let (kind, text) = match token.text.as_str() {
+            "__LINE__" => {
+                let line = self
+                    .line_index
+                    .get_line(token.expansion_range.start())
+                    .unwrap_or(0);
+                (CppTokenKind::IntegerLiteral, (line + 1).to_string())
+            }
+            "__FILE__" => (
+                CppTokenKind::StringLiteral,
+                format!("\"{}\"", escape_string(self.file_name)),
+            ),
+            "__COUNTER__" => {
+                let counter = *self.counter;
+                *self.counter += 1;
+                (CppTokenKind::IntegerLiteral, counter.to_string())
+            }
+            "__cplusplus" => {
+                let version = match self.lexer_config.language_level {
+                    CppLanguageLevel::Cpp11 => "201103L",
+                    CppLanguageLevel::Cpp14 => "201402L",
+                    CppLanguageLevel::Cpp17 => "201703L",
+                    CppLanguageLevel::Cpp20 => "202002L",
+                    _ => "202302L",
+                };
+                (CppTokenKind::IntegerLiteral, version.to_string())
+            }
+            _ => return None,
+        };
+
+        Some(CppPpToken {
+            kind,
+            text,
+            ..token.clone()
+        })
+    }
+
+    /// Collect the arguments of a function-like macro invocation, the input starts at its
+    /// `(`. Returns the arguments and the closing `)`, on errors the input is left unchanged.
+    fn collect_args(
+        &mut self,
+        name: &CppPpToken,
+        cpp_macro: &CppMacro,
+        input: &mut VecDeque<CppPpToken>,
+    ) -> Option<(Vec<Vec<CppPpToken>>, CppPpToken)> {
+        let param_count = cpp_macro.get_params().map_or(0, |params| params.len());
+        let mut args: Vec<Vec<CppPpToken>> = vec![Vec::new()];
+        let mut depth = 0;
+        let mut right_paren = None;
+
+        // Skip the `(`
+        let mut index = 1;
+        while let Some(token) = input.get(index) {
+            index += 1;
+            match token.kind {
+                CppTokenKind::LeftParen => depth += 1,
+                CppTokenKind::RightParen if depth == 0 => {
+                    right_paren = Some(token.clone());
+                    break;
+                }
+                CppTokenKind::RightParen => depth -= 1,
+                // Commas of the variadic argument are part of it
+                CppTokenKind::Comma
+                    if depth == 0 && !(cpp_macro.is_variadic() && args.len() == param_count) =>
+                {
+                    args.push(Vec::new());
+                    continue;
+                }
+                _ => {}
+            }
+            if let Some(arg) = args.last_mut() {
+                arg.push(token.clone());
+            }
+        }
+
+        let Some(right_paren) = right_paren else {
+            self.push_error(
+                &t!(
+                    "unterminated argument list invoking macro '%{name}'",
+                    name = cpp_macro.get_name()
+                ),
+                name.spelling_range,
+            );
+            return None;
+        };
+
+        if param_count == 0 && args.len() == 1 && args[0].is_empty() {
+            args.clear();
+        }
+        // The variadic argument may be left out entirely: F(a) for F(a, ...)
+        if cpp_macro.is_variadic() && args.len() + 1 == param_count {
+            args.push(Vec::new());
+        }
+        if args.len() != param_count {
+            let message = if args.len() > param_count {
+                t!(
+                    "too many arguments provided to function-like macro '%{name}'",
+                    name = cpp_macro.get_name()
+                )
+            } else {
+                t!(
+                    "too few arguments provided to function-like macro '%{name}'",
+                    name = cpp_macro.get_name()
+                )
+            };
+            self.push_error(
+                &message,
+                name.spelling_range.cover(right_paren.spelling_range),
+            );
+            return None;
+        }
+
+        input.drain(..index);
+        Some((args, right_paren))
+    }
+
+    /// Replace the parameters in `body` with the arguments, applying `#` and `##`.
+    /// `expanded_args` caches the macro-replaced arguments, each is expanded once.
+    fn substitute(
+        &mut self,
+        cpp_macro: &CppMacro,
+        body: &[CppPpToken],
+        args: &[Vec<CppPpToken>],
+        expanded_args: &mut [Option<Vec<CppPpToken>>],
+    ) -> Vec<CppPpToken> {
+        let mut output: Vec<CppPpToken> = Vec::new();
+        let mut i = 0;
+        while i < body.len() {
+            let token = &body[i];
+            let next = body.get(i + 1);
+
+            if token.kind == CppTokenKind::Hash
+                && cpp_macro.is_function_like()
+                && let Some(index) = next.and_then(|next| cpp_macro.param_index(next))
+            {
+                let param = &body[i + 1];
+                output.push(self.stringize(&args[index], token, param));
+                i += 2;
+                continue;
+            }
+
+            if token.kind == CppTokenKind::HashHash
+                && !output.is_empty()
+                && let Some(rhs) = next
+            {
+                let rhs_tokens = match cpp_macro.param_index(rhs) {
+                    Some(index) => {
+                        // GNU extension: `, ## __VA_ARGS__` drops the comma when there
+                        // are no variadic arguments
+                        if cpp_macro.is_variadic_param(index)
+                            && output
+                                .last()
+                                .is_some_and(|lhs| lhs.kind == CppTokenKind::Comma)
+                        {
+                            if args[index].is_empty() {
+                                output.pop();
+                            }
+                            output.extend(args[index].iter().cloned());
+                            i += 2;
+                            continue;
+                        }
+                        arg_or_placemarker(&args[index], rhs)
+                    }
+                    None => vec![rhs.clone()],
+                };
+
+                let mut rhs_tokens = rhs_tokens.into_iter();
+                if let (Some(lhs), Some(first)) = (output.pop(), rhs_tokens.next()) {
+                    self.paste(lhs, first, &mut output);
+                }
+                output.extend(rhs_tokens);
+                i += 2;
+                continue;
+            }
+
+            if let Some(index) = cpp_macro.param_index(token) {
+                if next.is_some_and(|next| next.kind == CppTokenKind::HashHash) {
+                    // Operands of `##` are not expanded
+                    output.extend(arg_or_placemarker(&args[index], token));
+                } else {
+                    let mut expanded = self.expand_arg(args, expanded_args, index).to_vec();
+                    if let Some(first) = expanded.first_mut() {
+                        first.has_leading_space = token.has_leading_space;
+                    }
+                    output.extend(expanded);
+                }
+                i += 1;
+                continue;
+            }
+
+            if token.text == "__VA_OPT__"
+                && cpp_macro.is_variadic()
+                && next.is_some_and(|next| next.kind == CppTokenKind::LeftParen)
+                && let Some(close) = find_closing_paren(body, i + 1)
+            {
+                // Decided on the replaced argument: with `#define EMPTY`, `F(EMPTY)` has none
+                let has_variadic_args = args.len().checked_sub(1).is_some_and(|index| {
+                    self.expand_arg(args, expanded_args, index)
+                        .iter()
+                        .any(|token| token.kind != CppTokenKind::None)
+                });
+                if has_variadic_args {
+                    output.extend(self.substitute(
+                        cpp_macro,
+                        &body[i + 2..close],
+                        args,
+                        expanded_args,
+                    ));
+                } else {
+                    output.push(placemarker(token));
+                }
+                i = close + 1;
+                continue;
+            }
+
+            output.push(token.clone());
+            i += 1;
+        }
+
+        output.retain(|token| token.kind != CppTokenKind::None);
+        output
+    }
+
+    /// The fully macro-replaced argument `index`
+    fn expand_arg<'b>(
+        &mut self,
+        args: &[Vec<CppPpToken>],
+        expanded_args: &'b mut [Option<Vec<CppPpToken>>],
+        index: usize,
+    ) -> &'b [CppPpToken] {
+        expanded_args[index].get_or_insert_with(|| self.expand(args[index].clone()))
+    }
+
+    fn stringize(&self, arg: &[CppPpToken], hash: &CppPpToken, param: &CppPpToken) -> CppPpToken {
+        let mut text = String::from("\"");
+        for (i, token) in arg.iter().enumerate() {
+            if i > 0 && token.has_leading_space {
+                text.push(' ');
+            }
+            if matches!(
+                token.kind,
+                CppTokenKind::StringLiteral
+                    | CppTokenKind::CharLiteral
+                    | CppTokenKind::UserDefinedLiteral
+            ) {
+                text.push_str(&escape_string(&token.text));
+            } else {
+                text.push_str(&token.text);
+            }
+        }
+        text.push('"');
+
+        let range = hash.spelling_range.cover(param.spelling_range);
+        CppPpToken::new(
+            CppTokenKind::StringLiteral,
+            &text,
+            range,
+            hash.has_leading_space,
+        )
+    }
+
+    /// Paste two tokens with `##`, the result has to be a single token
+    fn paste(&mut self, lhs: CppPpToken, rhs: CppPpToken, output: &mut Vec<CppPpToken>) {
+        if rhs.kind == CppTokenKind::None {
+            output.push(lhs);
+            return;
+        }
+        if lhs.kind == CppTokenKind::None {
+            output.push(rhs);
+            return;
+        }
+
+        let text = format!("{}{}", lhs.text, rhs.text);
+        let mut errors = Vec::new();
+        let tokens = CppLexer::new(&text, self.lexer_config, &mut errors).tokenize();
+        match tokens.as_slice() {
+            [token]
+                if errors.is_empty()
+                    && !matches!(
+                        token.kind,
+                        CppTokenKind::LineComment | CppTokenKind::BlockComment
+                    ) =>
+            {
+                output.push(CppPpToken {
+                    kind: token.kind,
+                    text,
+                    ..lhs
+                });
+            }
+            _ => {
+                self.push_error(
+                    &t!(
+                        "pasting \"%{lhs}\" and \"%{rhs}\" does not give a valid preprocessing token",
+                        lhs = lhs.text,
+                        rhs = rhs.text
+                    ),
+                    lhs.spelling_range,
+                );
+                output.push(lhs);
+                output.push(rhs);
+            }
+        }
+    }
+
+    fn push_error(&mut self, message: &str, range: TextRange) {
+        self.errors.push(CppParseError::new(
+            CppParseErrorKind::PreprocessorError,
+            message,
+            range,
+        ));
+    }
+}
+
+/// Stands in for an empty argument next to `##` until the pasting is done
+fn placemarker(token: &CppPpToken) -> CppPpToken {
+    CppPpToken::new(
+        CppTokenKind::None,
+        "",
+        TextRange::empty(token.spelling_range.start()),
+        token.has_leading_space,
+    )
+}
+
+fn arg_or_placemarker(arg: &[CppPpToken], param: &CppPpToken) -> Vec<CppPpToken> {
+    if arg.is_empty() {
+        vec![placemarker(param)]
+    } else {
+        arg.to_vec()
+    }
+}
+
+/// Index of the `)` that closes the `(` at `open`
+fn find_closing_paren(tokens: &[CppPpToken], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            CppTokenKind::LeftParen => depth += 1,
+            CppTokenKind::RightParen => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn escape_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch == '"' || ch == '\\' {
+            result.push('\\');
+        }
+        result.push(ch);
+    }
+    result
+}
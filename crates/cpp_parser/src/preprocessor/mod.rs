@@ -0,0 +1,19 @@
+mod condition;
+mod cpp_macro;
+mod cpp_preprocessor;
+mod include_graph;
+mod include_resolver;
+mod macro_expander;
+mod pp_token;
+mod test;
+mod vfs;
+
+pub use condition::CppHasInclude;
+pub use cpp_macro::{CppMacro, CppMacroTable, BUILTIN_MACROS};
+pub use cpp_preprocessor::{CppPreprocessResult, CppPreprocessor};
+pub use include_graph::{CppIncludeEdge, CppIncludeGraph};
+pub use include_resolver::{CppIncludeLocation, CppIncludeResolver};
+pub use macro_expander::CppMacroExpander;
+pub use pp_token::CppPpToken;
+pub(crate) use vfs::normalize_path;
+pub use vfs::{CppMemoryVfs, CppRealVfs, CppVfs};
@@ -0,0 +1,74 @@
+use rowan::TextRange;
+
+use crate::{kind::CppTokenKind, lexer::CppTokenData, text::remove_line_splices};
+
+/// A token on its way through the preprocessor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CppPpToken {
+    pub kind: CppTokenKind,
+    pub text: String,
+    /// Where the characters of the token are written, inside the macro body for a token that
+    /// comes from a macro
+    pub spelling_range: TextRange,
+    /// Where the token ends up in the file, the outermost macro invocation for a token that
+    /// comes from a macro
+    pub expansion_range: TextRange,
+    /// Whitespace in front of the token, `#` keeps it when it turns an argument into a string
+    pub has_leading_space: bool,
+    /// Names of the macros that must not expand this token again
+    pub(crate) hide_set: Vec<String>,
+}
+
+impl CppPpToken {
+    pub fn new(kind: CppTokenKind, text: &str, range: TextRange, has_leading_space: bool) -> Self {
+        CppPpToken {
+            kind,
+            text: text.to_string(),
+            spelling_range: range,
+            expansion_range: range,
+            has_leading_space,
+            hide_set: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_token_data(
+        token: &CppTokenData,
+        source: &str,
+        has_leading_space: bool,
+    ) -> Self {
+        let range = token.range;
+        let text = remove_line_splices(&source[range.start_offset..range.end_offset()]);
+        CppPpToken::new(token.kind, &text, range.into(), has_leading_space)
+    }
+
+    /// Tokens written in the file itself are spelled where they expand
+    pub fn is_from_macro(&self) -> bool {
+        self.spelling_range != self.expansion_range
+    }
+
+    /// Macro names are identifiers, keywords included: `#define private public`. Alternative
+    /// tokens like `and` are operators.
+    pub fn is_name(&self) -> bool {
+        self.text
+            .starts_with(|ch: char| ch.is_alphabetic() || ch == '_')
+            && !matches!(
+                self.kind,
+                CppTokenKind::StringLiteral
+                    | CppTokenKind::CharLiteral
+                    | CppTokenKind::UserDefinedLiteral
+                    | CppTokenKind::HeaderName
+                    | CppTokenKind::DirectiveText
+                    | CppTokenKind::LogicalAnd
+                    | CppTokenKind::LogicalOr
+                    | CppTokenKind::LogicalNot
+                    | CppTokenKind::Ampersand
+                    | CppTokenKind::Pipe
+                    | CppTokenKind::Caret
+                    | CppTokenKind::Tilde
+                    | CppTokenKind::NotEqual
+                    | CppTokenKind::AmpersandAssign
+                    | CppTokenKind::PipeAssign
+                    | CppTokenKind::CaretAssign
+            )
+    }
+}
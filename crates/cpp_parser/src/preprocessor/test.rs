@@ -0,0 +1,564 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use rowan::TextRange;
+
+    use crate::{
+        CppIncludeLocation, CppIncludeResolver, CppLanguageLevel, CppMemoryVfs, CppPpToken,
+        CppPreprocessResult, CppPreprocessor,
+    };
+
+    fn preprocess(code: &str) -> CppPreprocessResult {
+        let mut preprocessor = CppPreprocessor::new("test.cpp", CppLanguageLevel::Cpp23);
+        preprocessor.preprocess(code)
+    }
+
+    /// Preprocess `code` and join the resulting tokens, with a space where one was written
+    fn expand(code: &str) -> String {
+        let result = preprocess(code);
+        assert!(
+            result.errors.is_empty(),
+            "unexpected errors for `{}`: {:?}",
+            code,
+            result.errors
+        );
+        join_tokens(&result.tokens)
+    }
+
+    fn join_tokens(tokens: &[CppPpToken]) -> String {
+        let mut text = String::new();
+        for token in tokens {
+            if token.has_leading_space && !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(&token.text);
+        }
+        text
+    }
+
+    fn expand_errors(code: &str) -> Vec<String> {
+        preprocess(code)
+            .errors
+            .iter()
+            .map(|error| error.message.clone())
+            .collect()
+    }
+
+    fn range_text(code: &str, range: TextRange) -> &str {
+        &code[usize::from(range.start())..usize::from(range.end())]
+    }
+
+    #[test]
+    fn test_object_like_macros() {
+        assert_eq!(
+            expand("#define N 10\n#define M (N * 2)\nint a[M];\n#undef N\nint b = N;\n"),
+            "int a[(10 * 2)]; int b = N;"
+        );
+        assert_eq!(
+            expand("#define EMPTY\n#define private public\nclass A { private: EMPTY int x; };"),
+            "class A { public: int x; };"
+        );
+        // A macro is not expanded again inside its own expansion
+        assert_eq!(
+            expand("#define foo foo + 1\n#define a b\n#define b a\nfoo; a; b;"),
+            "foo + 1; a; b;"
+        );
+    }
+
+    #[test]
+    fn test_function_like_macros() {
+        assert_eq!(
+            expand("#define MAX(a, b) ((a) > (b) ? (a) : (b))\nint m = MAX(x, f(1, 2));"),
+            "int m = ((x) > (f(1, 2)) ? (x) : (f(1, 2)));"
+        );
+        assert_eq!(
+            expand("#define F() 1\n#define G(x) [x]\nF() G() G((a, b)) F + G"),
+            "1 [] [(a, b)] F + G"
+        );
+        // Arguments may span lines
+        assert_eq!(
+            expand("#define ADD(a, b) a + b\nint x = ADD(1,\n    2);"),
+            "int x = 1 + 2;"
+        );
+        // `(` of a function-like macro only counts right after the name in the definition
+        assert_eq!(expand("#define P (x) x\nP(1)"), "(x) x(1)");
+    }
+
+    #[test]
+    fn test_rescanning() {
+        // Example from the C standard, [cpp.scope]
+        let code = r#"#define x 3
+#define f(a) f(x * (a))
+#undef x
+#define x 2
+#define g f
+#define z z[0]
+#define h g(~
+#define m(a) a(w)
+#define w 0,1
+#define t(a) a
+#define p() int
+#define q(x) x
+#define r(x,y) x ## y
+#define str(x) # x
+f(y+1) + f(f(z)) % t(t(g)(0) + t)(1);
+g(x+(3,4)-w) | h 5) & m
+(f)^m(m);
+p() i[q()] = { q(1), r(2,3), r(4,), r(,5), r(,) };
+char c[2][6] = { str(hello), str() };
+"#;
+        let expected = [
+            "f(2 * (y+1)) + f(2 * (f(2 * (z[0])))) % f(2 * (0)) + t(1);",
+            "f(2 * (2+(3,4)-0,1)) | f(2 * (~ 5)) & f(2 * (0,1))^m(0,1);",
+            "int i[] = { 1, 23, 4, 5, };",
+            "char c[2][6] = { \"hello\", \"\" };",
+        ];
+        assert_eq!(expand(code), expected.join(" "));
+    }
+
+    #[test]
+    fn test_stringize_and_paste() {
+        assert_eq!(
+            expand(
+                "#define STR(x) #x\n#define XSTR(x) STR(x)\n#define V 1.2\nSTR(V) XSTR(V) STR( a  +  \"b\\n\" ) STR('\"')"
+            ),
+            r#""V" "1.2" "a + \"b\\n\"" "'\"'""#
+        );
+        assert_eq!(
+            expand(
+                "#define CAT(a, b) a ## b\n#define MEMBER(name) int m_##name;\nCAT(x, 1) CAT(<, <=) CAT(, y) MEMBER(count)"
+            ),
+            "x1 <<= y int m_count;"
+        );
+        assert_eq!(
+            expand_errors("#define CAT(a, b) a ## b\nCAT(+, -)"),
+            vec!["pasting \"+\" and \"-\" does not give a valid preprocessing token"]
+        );
+    }
+
+    #[test]
+    fn test_variadic_macros() {
+        let code = r#"#define LOG(fmt, ...) printf(fmt, __VA_ARGS__)
+#define GNU_LOG(fmt, args...) printf(fmt, ## args)
+#define OPT(fmt, ...) printf(fmt __VA_OPT__(,) __VA_ARGS__)
+#define COUNT(...) #__VA_ARGS__
+LOG("%d %d", a, b);
+GNU_LOG("x");
+GNU_LOG("%d", 1, 2);
+OPT("x");
+OPT("%d", 1);
+COUNT(a, b,c);
+"#;
+        let expected = [
+            r#"printf("%d %d", a, b);"#,
+            r#"printf("x");"#,
+            r#"printf("%d", 1, 2);"#,
+            r#"printf("x");"#,
+            r#"printf("%d", 1);"#,
+            r#""a, b,c";"#,
+        ];
+        assert_eq!(expand(code), expected.join(" "));
+
+        // __VA_OPT__ looks at the replaced variadic argument
+        assert_eq!(
+            expand("#define EMP\n#define F(...) f(0 __VA_OPT__(,) __VA_ARGS__)\nF(EMP) F(a)"),
+            "f(0) f(0, a)"
+        );
+    }
+
+    #[test]
+    fn test_builtin_macros() {
+        assert_eq!(
+            expand(
+                "int a = __LINE__;\n#define L __LINE__\nint b =\n L;\nconst char *f = __FILE__;"
+            ),
+            "int a = 1; int b = 4; const char *f = \"test.cpp\";"
+        );
+        assert_eq!(
+            expand(
+                "#define ID(x) id_##x\n#define UNIQUE(x) ID(x)\nUNIQUE(__COUNTER__) __COUNTER__"
+            ),
+            "id_0 1"
+        );
+    }
+
+    #[test]
+    fn test_source_locations() {
+        let code = "#define SQUARE(x) ((x) * (x))\nint v = SQUARE(n + 1);";
+        let tokens = preprocess(code).tokens;
+
+        let int = &tokens[0];
+        assert!(!int.is_from_macro());
+        assert_eq!(range_text(code, int.spelling_range), "int");
+
+        let invocation = "SQUARE(n + 1)";
+        let star = tokens.iter().find(|token| token.text == "*").unwrap();
+        assert!(star.is_from_macro());
+        assert_eq!(range_text(code, star.expansion_range), invocation);
+        // The `*` is spelled in the body of the macro
+        assert_eq!(
+            usize::from(star.spelling_range.start()),
+            code.find('*').unwrap()
+        );
+
+        let n = tokens.iter().find(|token| token.text == "n").unwrap();
+        assert_eq!(range_text(code, n.spelling_range), "n");
+        assert_eq!(range_text(code, n.expansion_range), invocation);
+
+        // Nested expansions point at the outermost invocation
+        let code = "#define ONE 1\n#define INC(x) (x + ONE)\nINC(2);";
+        let tokens = preprocess(code).tokens;
+        let one = tokens.iter().find(|token| token.text == "1").unwrap();
+        assert_eq!(range_text(code, one.spelling_range), "1");
+        assert_eq!(range_text(code, one.expansion_range), "INC(2)");
+    }
+
+    #[test]
+    fn test_macro_table() {
+        let mut preprocessor = CppPreprocessor::new("a.h", CppLanguageLevel::Cpp20);
+        preprocessor.preprocess("#define VERSION 3\n#define CALL(f, ...) f(__VA_ARGS__)\n");
+
+        let macros = preprocessor.get_macros();
+        let call = macros.get("CALL").unwrap();
+        assert!(call.is_function_like());
+        assert!(call.is_variadic());
+        assert_eq!(
+            call.get_params().unwrap(),
+            &["f".to_string(), "__VA_ARGS__".to_string()]
+        );
+        assert!(macros.is_defined("__LINE__"));
+        assert!(!macros.get("VERSION").unwrap().is_function_like());
+
+        // Macros stay defined for the next file
+        let result = preprocessor.preprocess("int v = VERSION;");
+        assert_eq!(join_tokens(&result.tokens), "int v = 3;");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            expand_errors("#define F(a, b) a\nF(1) F(1, 2, 3) F(1,"),
+            vec![
+                "too few arguments provided to function-like macro 'F'",
+                "too many arguments provided to function-like macro 'F'",
+                "unterminated argument list invoking macro 'F'",
+            ]
+        );
+        assert_eq!(
+            expand_errors("#define S(a) #b\n#define P(a) ## a\n"),
+            vec![
+                "'#' is not followed by a macro parameter",
+                "'##' cannot appear at either end of a macro expansion",
+            ]
+        );
+        // Invalid invocations are left as written
+        let result = preprocess("#define F(a) a\nF(1, 2);");
+        assert_eq!(join_tokens(&result.tokens), "F(1, 2);");
+    }
+
+    /// The text of every inactive range of `code`
+    fn inactive_texts(code: &str) -> Vec<String> {
+        let result = preprocess(code);
+        assert!(
+            result.errors.is_empty(),
+            "unexpected errors for `{}`: {:?}",
+            code,
+            result.errors
+        );
+        result
+            .inactive_ranges
+            .iter()
+            .map(|range| range_text(code, *range).to_string())
+            .collect()
+    }
+
+    /// Whether `condition` holds as the condition of an `#if`
+    fn condition_holds(condition: &str) -> bool {
+        let code = format!("#if {}\nyes\n#else\nno\n#endif\n", condition);
+        let result = preprocess(&code);
+        assert!(
+            result.errors.is_empty(),
+            "unexpected errors for `{}`: {:?}",
+            condition,
+            result.errors
+        );
+        join_tokens(&result.tokens) == "yes"
+    }
+
+    #[test]
+    fn test_conditions() {
+        assert!(condition_holds("1"));
+        assert!(!condition_holds("0"));
+        assert!(condition_holds("1 + 2 * 3 == 7 && (8 >> 2) == 2"));
+        assert!(condition_holds("-1 < 0 ? 0x10 == 16 : 0"));
+        assert!(condition_holds(
+            "0b101 == 5 && 017 == 15 && 1'000'000ull == 1000000"
+        ));
+        assert!(condition_holds("'a' == 97 && '\\n' == 10"));
+        assert!(condition_holds("!UNDEFINED && !defined UNDEFINED && true"));
+        assert!(condition_holds("not 0 and (1 or 0) and 6 bitand 3"));
+        assert!(condition_holds("__cplusplus >= 201703L"));
+        assert!(condition_holds("defined(__LINE__) && __LINE__ == 1"));
+        assert!(condition_holds("__has_cpp_attribute(nodiscard) >= 201603L"));
+        assert!(!condition_holds("__has_cpp_attribute(gnu::always_inline)"));
+        assert!(!condition_holds("__has_builtin(__builtin_expect)"));
+        assert!(!condition_holds("__has_include(<optional>)"));
+
+        assert!(condition_holds(
+            "7 % 4 == 3 && (1 | 2) == 3 && (6 ^ 3) == 5 && ~0 == -1"
+        ));
+        assert!(condition_holds("1 ? 2 : 3 ? 0 : 0"));
+    }
+
+    #[test]
+    fn test_conditions_with_macros() {
+        let code = r#"#define VERSION 3
+#define AT_LEAST(v) (VERSION >= (v))
+#if AT_LEAST(2) && defined(VERSION)
+two
+#endif
+#if AT_LEAST(4)
+four
+#elif VERSION == 3
+three
+#else
+other
+#endif
+#undef VERSION
+#ifdef VERSION
+defined
+#elifndef VERSION
+undefined
+#endif
+"#;
+        assert_eq!(expand(code), "two three undefined");
+    }
+
+    #[test]
+    fn test_has_include() {
+        let mut preprocessor = CppPreprocessor::new("test.cpp", CppLanguageLevel::Cpp20);
+        preprocessor.set_has_include(Box::new(|path, is_angled| {
+            (path == "optional" && is_angled) || path == "config.h"
+        }));
+        let code = "#if __has_include(<optional>) && __has_include(\"config.h\")\nyes\n#endif\n#if __has_include(<any>)\nno\n#endif\n";
+        let result = preprocessor.preprocess(code);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(join_tokens(&result.tokens), "yes");
+    }
+
+    #[test]
+    fn test_inactive_ranges() {
+        let code = "#ifdef _WIN32\nint win;\n#elif defined(__linux__)\nint linux;\n#else\nint other;\n#endif\n";
+        assert_eq!(inactive_texts(code), vec!["int win;\n", "int linux;\n"]);
+        assert_eq!(expand(code), "int other;");
+
+        // Nested conditionals of an inactive branch are part of its range
+        let code = "#if 0\n#if 1\nint a;\n#endif\n#define X\n#else\n  int b;\n#endif\n#ifdef X\nint c;\n#endif";
+        assert_eq!(
+            inactive_texts(code),
+            vec!["#if 1\nint a;\n#endif\n#define X\n", "int c;\n"]
+        );
+        assert_eq!(expand(code), "int b;");
+
+        // Only the first true branch is taken
+        let code = "#if 1\na\n#elif 1\nb\n#else\nc\n#endif\n";
+        assert_eq!(inactive_texts(code), vec!["b\n", "c\n"]);
+    }
+
+    #[test]
+    fn test_conditional_errors() {
+        assert_eq!(
+            expand_errors(
+                "#endif\n#else\n#if 1\n#else\n#else\n#endif\n#if 1 / 0\n#endif\n#if 1 +\n#endif\n#if (1\n#endif\n#if 1 2\n#endif\n#if defined\n#endif\n#ifdef X"
+            ),
+            vec![
+                "#endif without #if",
+                "#else without #if",
+                "#else after #else",
+                "division by zero in preprocessor expression",
+                "expected value in expression",
+                "missing ')' in expression",
+                "token '2' is not valid in a preprocessor expression",
+                "macro name missing after 'defined'",
+                "unterminated conditional directive",
+            ]
+        );
+    }
+
+    fn header_vfs() -> CppMemoryVfs {
+        let mut vfs = CppMemoryVfs::new();
+        vfs.add_file("project/src/main.cpp", "");
+        vfs.add_file("project/src/util.h", "");
+        vfs.add_file("project/src/config.h", "");
+        vfs.add_file("project/quote/config.h", "");
+        vfs.add_file("project/include/util.h", "");
+        vfs.add_file("project/include/vector", "");
+        vfs.add_file("sdk/include/vector", "");
+        vfs.add_file("sdk/include/sys/types.h", "");
+        vfs.add_file("frameworks/Cocoa.framework/Headers/Cocoa.h", "");
+        vfs
+    }
+
+    fn header_resolver() -> CppIncludeResolver {
+        CppIncludeResolver {
+            quote_paths: vec![PathBuf::from("project/quote")],
+            include_paths: vec![PathBuf::from("project/include")],
+            system_paths: vec![PathBuf::from("sdk/include")],
+            framework_paths: vec![PathBuf::from("frameworks")],
+        }
+    }
+
+    #[test]
+    fn test_include_resolver() {
+        let vfs = header_vfs();
+        let resolver = header_resolver();
+        let main = CppIncludeLocation::main_file(Path::new("project/src/main.cpp"));
+        let resolve = |header: &str, is_angled: bool| {
+            resolver
+                .resolve(&vfs, header, is_angled, &main)
+                .map(|location| (location.path, location.search_index, location.is_system))
+        };
+
+        // Quoted headers are found next to the includer first, angled ones are not
+        assert_eq!(
+            resolve("util.h", false),
+            Some((PathBuf::from("project/src/util.h"), None, false))
+        );
+        assert_eq!(
+            resolve("util.h", true),
+            Some((PathBuf::from("project/include/util.h"), Some(1), false))
+        );
+        assert_eq!(
+            resolve("../quote/config.h", false),
+            Some((PathBuf::from("project/quote/config.h"), None, false))
+        );
+        assert_eq!(
+            resolve("sys/types.h", true),
+            Some((PathBuf::from("sdk/include/sys/types.h"), Some(2), true))
+        );
+        assert_eq!(
+            resolve("Cocoa/Cocoa.h", true),
+            Some((
+                PathBuf::from("frameworks/Cocoa.framework/Headers/Cocoa.h"),
+                Some(3),
+                false
+            ))
+        );
+        assert_eq!(resolve("missing.h", false), None);
+
+        // `#include_next` continues after the path of the includer
+        let wrapper = resolver.resolve(&vfs, "vector", true, &main).unwrap();
+        assert_eq!(wrapper.path, PathBuf::from("project/include/vector"));
+        let next = resolver
+            .resolve_next(&vfs, "vector", true, &wrapper)
+            .unwrap();
+        assert_eq!(next.path, PathBuf::from("sdk/include/vector"));
+        assert_eq!(resolver.resolve_next(&vfs, "vector", true, &next), None);
+    }
+
+    #[test]
+    fn test_include_graph() {
+        let mut vfs = CppMemoryVfs::new();
+        vfs.add_file(
+            "src/a.h",
+            "#ifndef A_H\n#define A_H\n#include \"common.h\"\n#define HAS_A 1\n#endif\n",
+        );
+        vfs.add_file(
+            "src/b.h",
+            "#pragma once\n#include \"common.h\"\n#include <missing.h>\n#ifdef B_SEEN\n#define B_TWICE\n#endif\n#define B_SEEN\n",
+        );
+        vfs.add_file(
+            "src/common.h",
+            "#if !defined(COMMON_H)\n#define COMMON_H\n#endif\n",
+        );
+        vfs.add_file("include/lib/lib.h", "#include \"../../src/b.h\"\n");
+
+        let mut preprocessor = CppPreprocessor::new("main.cpp", CppLanguageLevel::Cpp20);
+        preprocessor.set_include_resolver(
+            Box::new(vfs),
+            CppIncludeResolver {
+                include_paths: vec![PathBuf::from("include")],
+                ..Default::default()
+            },
+        );
+        let code = "#include \"a.h\"\n#include \"b.h\"\n#define LIB <lib/lib.h>\n#include LIB\n#if HAS_A && defined(COMMON_H)\nint a;\n#endif\n#ifndef B_TWICE\nint once;\n#endif\n#if __has_include(\"b.h\") && !__has_include(<b.h>)\nint quoted;\n#endif\n";
+        let result = preprocessor.preprocess_file(Path::new("src/main.cpp"), code);
+        assert_eq!(join_tokens(&result.tokens), "int a; int once; int quoted;");
+        // Errors in headers belong to the headers
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        let graph = preprocessor.get_include_graph().unwrap();
+        let main = Path::new("src/main.cpp");
+        let includes: Vec<_> = graph
+            .get_includes(main)
+            .map(|edge| {
+                (
+                    edge.header.as_str(),
+                    edge.is_angled,
+                    range_text(code, edge.range),
+                )
+            })
+            .collect();
+        assert_eq!(
+            includes,
+            vec![
+                ("a.h", false, "#include \"a.h\""),
+                ("b.h", false, "#include \"b.h\""),
+                ("lib/lib.h", true, "#include LIB"),
+            ]
+        );
+        assert_eq!(
+            graph.get_includers(Path::new("src/b.h")),
+            vec![main, Path::new("include/lib/lib.h")]
+        );
+        assert_eq!(
+            graph.get_transitive_includes(main),
+            vec![
+                PathBuf::from("src/a.h"),
+                PathBuf::from("src/b.h"),
+                PathBuf::from("include/lib/lib.h"),
+                PathBuf::from("src/common.h"),
+            ]
+        );
+        assert_eq!(
+            graph.get_transitive_includers(Path::new("src/common.h")),
+            vec![
+                PathBuf::from("src/a.h"),
+                PathBuf::from("src/b.h"),
+                PathBuf::from("src/main.cpp"),
+                PathBuf::from("include/lib/lib.h"),
+            ]
+        );
+        let unresolved: Vec<_> = graph
+            .get_unresolved()
+            .map(|edge| (edge.includer.as_path(), edge.header.as_str()))
+            .collect();
+        assert_eq!(unresolved, vec![(Path::new("src/b.h"), "missing.h")]);
+    }
+
+    #[test]
+    fn test_recursive_include() {
+        let mut vfs = CppMemoryVfs::new();
+        vfs.add_file("self.h", "#include \"self.h\"\n");
+        let mut preprocessor = CppPreprocessor::new("main.cpp", CppLanguageLevel::Cpp20);
+        preprocessor.set_include_resolver(Box::new(vfs), CppIncludeResolver::new());
+        let result = preprocessor.preprocess_file(
+            Path::new("main.cpp"),
+            "#include \"self.h\"\n#include \"gone.h\"\nint x;",
+        );
+        assert_eq!(join_tokens(&result.tokens), "int x;");
+        let errors: Vec<_> = result
+            .errors
+            .iter()
+            .map(|error| error.message.as_str())
+            .collect();
+        assert_eq!(errors, vec!["'gone.h' file not found"]);
+        let graph = preprocessor.get_include_graph().unwrap();
+        assert_eq!(graph.get_edges().len(), 3);
+        assert_eq!(
+            graph.get_transitive_includes(Path::new("self.h")),
+            Vec::<PathBuf>::new()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+};
+
+/// The files the preprocessor can see, headers are looked up and read through it
+pub trait CppVfs {
+    fn is_file(&self, path: &Path) -> bool;
+
+    fn read_file(&self, path: &Path) -> Option<String>;
+}
+
+/// The files on disk
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CppRealVfs;
+
+impl CppVfs for CppRealVfs {
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn read_file(&self, path: &Path) -> Option<String> {
+        std::fs::read_to_string(path).ok()
+    }
+}
+
+/// Files kept in memory, for tests and for unsaved buffers
+#[derive(Debug, Clone, Default)]
+pub struct CppMemoryVfs {
+    files: HashMap<PathBuf, String>,
+}
+
+impl CppMemoryVfs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_file(&mut self, path: impl AsRef<Path>, text: &str) {
+        self.files
+            .insert(normalize_path(path.as_ref()), text.to_string());
+    }
+
+    pub fn remove_file(&mut self, path: impl AsRef<Path>) -> Option<String> {
+        self.files.remove(&normalize_path(path.as_ref()))
+    }
+}
+
+impl CppVfs for CppMemoryVfs {
+    fn is_file(&self, path: &Path) -> bool {
+        self.files.contains_key(&normalize_path(path))
+    }
+
+    fn read_file(&self, path: &Path) -> Option<String> {
+        self.files.get(&normalize_path(path)).cloned()
+    }
+}
+
+/// Remove `.` and `..` without looking at the file system, `a/b/../c` is `a/c`. Symbolic
+/// links are not followed, so the same header may show up under two paths.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                let can_pop = matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                );
+                if can_pop {
+                    normalized.pop();
+                } else if !normalized.has_root() {
+                    normalized.push("..");
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
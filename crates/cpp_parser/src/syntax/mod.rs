@@ -1,4 +1,4 @@
-mod comment_trait;
+// mod comment_trait;
 mod node;
 mod traits;
 mod tree;
@@ -10,20 +10,20 @@ use std::iter::successors;
 
 use rowan::{Language, TextRange, TextSize};
 
-use crate::kind::{LuaKind, CppSyntaxKind, CppTokenKind};
-pub use comment_trait::*;
+use crate::kind::{CppKind, CppSyntaxKind, CppTokenKind};
+// pub use comment_trait::*;
 pub use node::*;
 pub use traits::*;
-pub use tree::{LuaSyntaxTree, LuaTreeBuilder};
+pub use tree::{CppSyntaxTree, CppTreeBuilder};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct LuaLanguage;
+pub struct CppLanguage;
 
-impl Language for LuaLanguage {
-    type Kind = LuaKind;
+impl Language for CppLanguage {
+    type Kind = CppKind;
 
     fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
-        LuaKind::from_raw(raw.0)
+        CppKind::from_raw(raw.0)
     }
 
     fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
@@ -31,66 +31,66 @@ impl Language for LuaLanguage {
     }
 }
 
-pub type LuaSyntaxNode = rowan::SyntaxNode<LuaLanguage>;
-pub type LuaSyntaxToken = rowan::SyntaxToken<LuaLanguage>;
-pub type LuaSyntaxElement = rowan::NodeOrToken<LuaSyntaxNode, LuaSyntaxToken>;
-pub type LuaSyntaxElementChildren = rowan::SyntaxElementChildren<LuaLanguage>;
-pub type LuaSyntaxNodeChildren = rowan::SyntaxNodeChildren<LuaLanguage>;
-pub type LuaSyntaxNodePtr = rowan::ast::SyntaxNodePtr<LuaLanguage>;
+pub type CppSyntaxNode = rowan::SyntaxNode<CppLanguage>;
+pub type CppSyntaxToken = rowan::SyntaxToken<CppLanguage>;
+pub type CppSyntaxElement = rowan::NodeOrToken<CppSyntaxNode, CppSyntaxToken>;
+pub type CppSyntaxNodeChildren = rowan::SyntaxNodeChildren<CppLanguage>;
+pub type CppSyntaxElementChildren = rowan::SyntaxElementChildren<CppLanguage>;
+pub type CppSyntaxNodePtr = rowan::ast::SyntaxNodePtr<CppLanguage>;
 
 impl From<CppSyntaxKind> for rowan::SyntaxKind {
     fn from(kind: CppSyntaxKind) -> Self {
-        let lua_kind = LuaKind::from(kind);
-        rowan::SyntaxKind(lua_kind.get_raw())
+        let cpp_kind = CppKind::from(kind);
+        rowan::SyntaxKind(cpp_kind.get_raw())
     }
 }
 
 impl From<rowan::SyntaxKind> for CppSyntaxKind {
     fn from(kind: rowan::SyntaxKind) -> Self {
-        LuaKind::from_raw(kind.0).into()
+        CppKind::from_raw(kind.0).into()
     }
 }
 
 impl From<CppTokenKind> for rowan::SyntaxKind {
     fn from(kind: CppTokenKind) -> Self {
-        let lua_kind = LuaKind::from(kind);
-        rowan::SyntaxKind(lua_kind.get_raw())
+        let cpp_kind = CppKind::from(kind);
+        rowan::SyntaxKind(cpp_kind.get_raw())
     }
 }
 
 impl From<rowan::SyntaxKind> for CppTokenKind {
     fn from(kind: rowan::SyntaxKind) -> Self {
-        LuaKind::from_raw(kind.0).into()
+        CppKind::from_raw(kind.0).into()
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct LuaSyntaxId {
-    kind: LuaKind,
+pub struct CppSyntaxId {
+    kind: CppKind,
     range: TextRange,
 }
 
-impl LuaSyntaxId {
-    pub fn new(kind: LuaKind, range: TextRange) -> Self {
-        LuaSyntaxId { kind, range }
+impl CppSyntaxId {
+    pub fn new(kind: CppKind, range: TextRange) -> Self {
+        CppSyntaxId { kind, range }
     }
 
-    pub fn from_ptr(ptr: LuaSyntaxNodePtr) -> Self {
-        LuaSyntaxId {
+    pub fn from_ptr(ptr: CppSyntaxNodePtr) -> Self {
+        CppSyntaxId {
             kind: ptr.kind(),
             range: ptr.text_range(),
         }
     }
 
-    pub fn from_node(node: &LuaSyntaxNode) -> Self {
-        LuaSyntaxId {
+    pub fn from_node(node: &CppSyntaxNode) -> Self {
+        CppSyntaxId {
             kind: node.kind(),
             range: node.text_range(),
         }
     }
 
-    pub fn from_token(token: &LuaSyntaxToken) -> Self {
-        LuaSyntaxId {
+    pub fn from_token(token: &CppSyntaxToken) -> Self {
+        CppSyntaxId {
             kind: token.kind(),
             range: token.text_range(),
         }
@@ -116,7 +116,7 @@ impl LuaSyntaxId {
         self.range
     }
 
-    pub fn to_node(&self, tree: &LuaSyntaxTree) -> Option<LuaSyntaxNode> {
+    pub fn to_node(&self, tree: &CppSyntaxTree) -> Option<CppSyntaxNode> {
         let root = tree.get_red_root();
         if root.parent().is_some() {
             return None;
@@ -124,14 +124,14 @@ impl LuaSyntaxId {
         self.to_node_from_root(&root)
     }
 
-    pub fn to_node_from_root(&self, root: &LuaSyntaxNode) -> Option<LuaSyntaxNode> {
+    pub fn to_node_from_root(&self, root: &CppSyntaxNode) -> Option<CppSyntaxNode> {
         successors(Some(root.clone()), |node| {
             node.child_or_token_at_range(self.range)?.into_node()
         })
         .find(|it| it.text_range() == self.range && it.kind() == self.kind)
     }
 
-    pub fn to_token(&self, tree: &LuaSyntaxTree) -> Option<LuaSyntaxToken> {
+    pub fn to_token(&self, tree: &CppSyntaxTree) -> Option<CppSyntaxToken> {
         let root = tree.get_red_root();
         if root.parent().is_some() {
             return None;
@@ -139,7 +139,7 @@ impl LuaSyntaxId {
         self.to_token_from_root(&root)
     }
 
-    pub fn to_token_from_root(&self, root: &LuaSyntaxNode) -> Option<LuaSyntaxToken> {
+    pub fn to_token_from_root(&self, root: &CppSyntaxNode) -> Option<CppSyntaxToken> {
         let mut current_node = Some(root.clone());
         while let Some(node) = current_node {
             let node_or_token = node.child_or_token_at_range(self.range)?;
@@ -158,7 +158,7 @@ impl LuaSyntaxId {
         None
     }
 
-    pub fn to_node_at_range(root: &LuaSyntaxNode, range: TextRange) -> Option<LuaSyntaxNode> {
+    pub fn to_node_at_range(root: &CppSyntaxNode, range: TextRange) -> Option<CppSyntaxNode> {
         successors(Some(root.clone()), |node| {
             node.child_or_token_at_range(range)?.into_node()
         })
@@ -166,7 +166,7 @@ impl LuaSyntaxId {
     }
 }
 
-impl Serialize for LuaSyntaxId {
+impl Serialize for CppSyntaxId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -180,15 +180,15 @@ impl Serialize for LuaSyntaxId {
     }
 }
 
-impl<'de> Deserialize<'de> for LuaSyntaxId {
+impl<'de> Deserialize<'de> for CppSyntaxId {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct LuaSyntaxIdVisitor;
+        struct CppSyntaxIdVisitor;
 
-        impl Visitor<'_> for LuaSyntaxIdVisitor {
-            type Value = LuaSyntaxId;
+        impl Visitor<'_> for CppSyntaxIdVisitor {
+            type Value = CppSyntaxId;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("a string with format 'kind:range'")
@@ -211,13 +211,13 @@ impl<'de> Deserialize<'de> for LuaSyntaxId {
                 let start = TextSize::new(((range_combined >> 32) & 0xFFFFFFFF) as u32);
                 let end = TextSize::new((range_combined & 0xFFFFFFFF) as u32);
 
-                Ok(LuaSyntaxId {
-                    kind: LuaKind::from_raw(kind_raw),
+                Ok(CppSyntaxId {
+                    kind: CppKind::from_raw(kind_raw),
                     range: TextRange::new(start, end),
                 })
             }
         }
 
-        deserializer.deserialize_str(LuaSyntaxIdVisitor)
+        deserializer.deserialize_str(CppSyntaxIdVisitor)
     }
 }
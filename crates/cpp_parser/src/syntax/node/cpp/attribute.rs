@@ -0,0 +1,276 @@
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    syntax::{
+        node::CppGeneralToken,
+        traits::{CppAstChildren, CppAstNode, CppAstToken},
+    },
+    CppSyntaxNode, CppSyntaxToken,
+};
+
+use super::{
+    CppBaseSpecifier, CppClassDef, CppDecl, CppEnumDef, CppEnumerator, CppFieldDecl,
+    CppFunctionDecl, CppFunctionDef, CppNamespaceDecl, CppParameter, CppUsingDecl, CppVariableDecl,
+};
+
+/// How an attribute specifier is spelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CppAttributeListKind {
+    /// `[[nodiscard]]`
+    Standard,
+    /// `alignas(16)`
+    Alignas,
+    /// `__attribute__((unused))`
+    Gnu,
+    /// `__declspec(dllexport)`
+    Declspec,
+}
+
+/// Attribute specifier: `[[nodiscard, gnu::hot]]`, `alignas(T)`, `__attribute__((unused))` or
+/// `__declspec(dllexport)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppAttributeList {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppAttributeList {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::AttributeList
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppAttributeList {
+    pub fn get_list_kind(&self) -> CppAttributeListKind {
+        let first = self
+            .syntax()
+            .first_token()
+            .map(|it| it.text().to_string())
+            .unwrap_or_default();
+        match first.as_str() {
+            "alignas" => CppAttributeListKind::Alignas,
+            "__attribute__" => CppAttributeListKind::Gnu,
+            "__declspec" => CppAttributeListKind::Declspec,
+            _ => CppAttributeListKind::Standard,
+        }
+    }
+
+    pub fn get_attributes(&self) -> CppAstChildren<CppAttribute> {
+        self.children()
+    }
+
+    /// The namespace of `[[using gnu: hot, cold]]`
+    pub fn get_using_namespace_token(&self) -> Option<CppGeneralToken> {
+        if self.get_list_kind() != CppAttributeListKind::Standard {
+            return None;
+        }
+
+        self.token_by_kind(CppTokenKind::UsingKeyword)?;
+        self.token_by_kind(CppTokenKind::Identifier)
+    }
+}
+
+/// One attribute with its arguments: `nodiscard`, `gnu::aligned(8)`, `deprecated("msg")`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppAttribute {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppAttribute {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::Attribute
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppAttribute {
+    /// The words of `ns::name`, the arguments excluded
+    fn get_name_parts(&self) -> Vec<CppSyntaxToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .take_while(|it| it.kind() != CppTokenKind::LeftParen.into())
+            .filter(|it| {
+                let kind: CppTokenKind = it.kind().into();
+                !kind.is_trivia() && !matches!(kind, CppTokenKind::Scope | CppTokenKind::Ellipsis)
+            })
+            .collect()
+    }
+
+    pub fn get_list(&self) -> Option<CppAttributeList> {
+        self.get_parent()
+    }
+
+    /// The name without its namespace, `aligned` for `gnu::aligned(8)`
+    pub fn get_name_token(&self) -> Option<CppGeneralToken> {
+        self.get_name_parts()
+            .into_iter()
+            .last()
+            .and_then(CppGeneralToken::cast)
+    }
+
+    pub fn get_name_text(&self) -> String {
+        self.get_name_token()
+            .map(|it| it.syntax().text().to_string())
+            .unwrap_or_default()
+    }
+
+    /// `gnu` for `gnu::hot` and for the attributes of `[[using gnu: hot]]`
+    pub fn get_namespace_text(&self) -> Option<String> {
+        let parts = self.get_name_parts();
+        if parts.len() > 1 {
+            return Some(parts[0].text().to_string());
+        }
+
+        let token = self.get_list()?.get_using_namespace_token()?;
+        Some(token.syntax().text().to_string())
+    }
+
+    /// Whether this is the standard or GNU attribute `name`. The GNU spellings `__name__`
+    /// and `gnu::name` count, other vendor namespaces do not.
+    pub fn is_named(&self, name: &str) -> bool {
+        let namespace = self.get_namespace_text();
+        let is_gnu = namespace.as_deref() == Some("gnu")
+            || self
+                .get_list()
+                .is_some_and(|list| list.get_list_kind() == CppAttributeListKind::Gnu);
+        if namespace.is_some() && !is_gnu {
+            return false;
+        }
+
+        let text = self.get_name_text();
+        let text = match text.strip_prefix("__").and_then(|it| it.strip_suffix("__")) {
+            Some(stripped) if is_gnu => stripped,
+            _ => &text,
+        };
+        text == name
+    }
+
+    /// The tokens between the parentheses of the arguments
+    pub fn get_argument_tokens(&self) -> Vec<CppGeneralToken> {
+        let mut tokens: Vec<_> = self
+            .syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .skip_while(|it| it.kind() != CppTokenKind::LeftParen.into())
+            .skip(1)
+            .collect();
+        if let Some(index) = tokens
+            .iter()
+            .rposition(|it| it.kind() == CppTokenKind::RightParen.into())
+        {
+            tokens.truncate(index);
+        }
+        tokens
+            .into_iter()
+            .filter_map(CppGeneralToken::cast)
+            .collect()
+    }
+
+    /// The text of a string argument without its quotes, `msg` for `deprecated("msg")`.
+    /// Adjacent string literals are joined, escapes are kept as written.
+    pub fn get_string_argument(&self) -> Option<String> {
+        let mut text = None::<String>;
+        for token in self.get_argument_tokens() {
+            let token = token.syntax();
+            if token.kind() != CppTokenKind::StringLiteral.into() {
+                continue;
+            }
+
+            let literal = token.text();
+            let (Some(start), Some(end)) = (literal.find('"'), literal.rfind('"')) else {
+                continue;
+            };
+            if start < end {
+                text.get_or_insert_default()
+                    .push_str(&literal[start + 1..end]);
+            }
+        }
+        text
+    }
+}
+
+/// Accessors for the attributes of a declaration, the specifiers written in front of it,
+/// among its specifiers or after its name
+pub trait CppAttributeOwner: CppAstNode {
+    fn get_attribute_lists(&self) -> CppAstChildren<CppAttributeList> {
+        self.children()
+    }
+
+    fn get_attributes(&self) -> impl Iterator<Item = CppAttribute> {
+        self.get_attribute_lists()
+            .flat_map(|list| list.get_attributes())
+    }
+
+    /// The standard or GNU attribute `name`, see `CppAttribute::is_named`
+    fn get_attribute(&self, name: &str) -> Option<CppAttribute> {
+        self.get_attributes().find(|it| it.is_named(name))
+    }
+
+    /// `[[nodiscard]]`, `[[nodiscard("reason")]]` or GNU `warn_unused_result`
+    fn is_nodiscard(&self) -> bool {
+        self.get_attribute("nodiscard").is_some()
+            || self.get_attribute("warn_unused_result").is_some()
+    }
+
+    /// `[[deprecated]]`, `__attribute__((deprecated))` or `__declspec(deprecated)`
+    fn is_deprecated(&self) -> bool {
+        self.get_attribute("deprecated").is_some()
+    }
+
+    /// The message of `[[deprecated("msg")]]`
+    fn get_deprecated_message(&self) -> Option<String> {
+        self.get_attribute("deprecated")?.get_string_argument()
+    }
+
+    /// `[[maybe_unused]]` or GNU `unused`
+    fn is_maybe_unused(&self) -> bool {
+        self.get_attribute("maybe_unused").is_some() || self.get_attribute("unused").is_some()
+    }
+}
+
+impl CppAttributeOwner for CppDecl {}
+impl CppAttributeOwner for CppFunctionDecl {}
+impl CppAttributeOwner for CppFunctionDef {}
+impl CppAttributeOwner for CppClassDef {}
+impl CppAttributeOwner for CppEnumDef {}
+impl CppAttributeOwner for CppNamespaceDecl {}
+impl CppAttributeOwner for CppFieldDecl {}
+impl CppAttributeOwner for CppVariableDecl {}
+impl CppAttributeOwner for CppUsingDecl {}
+impl CppAttributeOwner for CppParameter {}
+impl CppAttributeOwner for CppEnumerator {}
+impl CppAttributeOwner for CppBaseSpecifier {}
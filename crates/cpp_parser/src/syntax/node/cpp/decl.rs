@@ -0,0 +1,756 @@
+use rowan::WalkEvent;
+
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    syntax::{
+        node::{CppGeneralToken, CppNameToken},
+        traits::{CppAstNode, CppAstToken, CppAstTokenChildren},
+    },
+    CppSyntaxNode,
+};
+
+use super::{
+    declarator_name_tokens, function_trait::CppFunctionTrait, CppBaseSpecifier, CppCompoundStat,
+    CppEnumerator, CppExportDecl, CppExpr, CppImportDecl, CppModuleDecl, CppRequiresClause,
+    CppTemplateParameterList, CppType,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CppDecl {
+    FunctionDecl(CppFunctionDecl),
+    FunctionDef(CppFunctionDef),
+    ClassDef(CppClassDef),
+    EnumDef(CppEnumDef),
+    NamespaceDecl(CppNamespaceDecl),
+    FieldDecl(CppFieldDecl),
+    VariableDecl(CppVariableDecl),
+    TemplateDecl(CppTemplateDecl),
+    UsingDecl(CppUsingDecl),
+    ConceptDecl(CppConceptDecl),
+    ModuleDecl(CppModuleDecl),
+    ImportDecl(CppImportDecl),
+    ExportDecl(CppExportDecl),
+}
+
+impl CppAstNode for CppDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        match self {
+            CppDecl::FunctionDecl(node) => node.syntax(),
+            CppDecl::FunctionDef(node) => node.syntax(),
+            CppDecl::ClassDef(node) => node.syntax(),
+            CppDecl::EnumDef(node) => node.syntax(),
+            CppDecl::NamespaceDecl(node) => node.syntax(),
+            CppDecl::FieldDecl(node) => node.syntax(),
+            CppDecl::VariableDecl(node) => node.syntax(),
+            CppDecl::TemplateDecl(node) => node.syntax(),
+            CppDecl::UsingDecl(node) => node.syntax(),
+            CppDecl::ConceptDecl(node) => node.syntax(),
+            CppDecl::ModuleDecl(node) => node.syntax(),
+            CppDecl::ImportDecl(node) => node.syntax(),
+            CppDecl::ExportDecl(node) => node.syntax(),
+        }
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        CppFunctionDecl::can_cast(kind)
+            || CppFunctionDef::can_cast(kind)
+            || CppClassDef::can_cast(kind)
+            || CppEnumDef::can_cast(kind)
+            || CppNamespaceDecl::can_cast(kind)
+            || CppFieldDecl::can_cast(kind)
+            || CppVariableDecl::can_cast(kind)
+            || CppTemplateDecl::can_cast(kind)
+            || CppUsingDecl::can_cast(kind)
+            || CppConceptDecl::can_cast(kind)
+            || CppModuleDecl::can_cast(kind)
+            || CppImportDecl::can_cast(kind)
+            || CppExportDecl::can_cast(kind)
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let kind: CppSyntaxKind = syntax.kind().into();
+        if CppFunctionDecl::can_cast(kind) {
+            Some(CppDecl::FunctionDecl(CppFunctionDecl::cast(syntax)?))
+        } else if CppFunctionDef::can_cast(kind) {
+            Some(CppDecl::FunctionDef(CppFunctionDef::cast(syntax)?))
+        } else if CppClassDef::can_cast(kind) {
+            Some(CppDecl::ClassDef(CppClassDef::cast(syntax)?))
+        } else if CppEnumDef::can_cast(kind) {
+            Some(CppDecl::EnumDef(CppEnumDef::cast(syntax)?))
+        } else if CppNamespaceDecl::can_cast(kind) {
+            Some(CppDecl::NamespaceDecl(CppNamespaceDecl::cast(syntax)?))
+        } else if CppFieldDecl::can_cast(kind) {
+            Some(CppDecl::FieldDecl(CppFieldDecl::cast(syntax)?))
+        } else if CppVariableDecl::can_cast(kind) {
+            Some(CppDecl::VariableDecl(CppVariableDecl::cast(syntax)?))
+        } else if CppTemplateDecl::can_cast(kind) {
+            Some(CppDecl::TemplateDecl(CppTemplateDecl::cast(syntax)?))
+        } else if CppUsingDecl::can_cast(kind) {
+            Some(CppDecl::UsingDecl(CppUsingDecl::cast(syntax)?))
+        } else if CppConceptDecl::can_cast(kind) {
+            Some(CppDecl::ConceptDecl(CppConceptDecl::cast(syntax)?))
+        } else if CppModuleDecl::can_cast(kind) {
+            Some(CppDecl::ModuleDecl(CppModuleDecl::cast(syntax)?))
+        } else if CppImportDecl::can_cast(kind) {
+            Some(CppDecl::ImportDecl(CppImportDecl::cast(syntax)?))
+        } else if CppExportDecl::can_cast(kind) {
+            Some(CppDecl::ExportDecl(CppExportDecl::cast(syntax)?))
+        } else {
+            None
+        }
+    }
+}
+
+/// Function declaration without a body: `int add(int a, int b);`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppFunctionDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppFunctionDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::FunctionDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppFunctionTrait for CppFunctionDecl {}
+
+/// Function definition with a body: `int add(int a, int b) { return a + b; }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppFunctionDef {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppFunctionDef {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::FunctionDef
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppFunctionTrait for CppFunctionDef {}
+
+impl CppFunctionDef {
+    pub fn get_body(&self) -> Option<CppCompoundStat> {
+        self.child()
+    }
+
+    /// Whether the body has a `co_await`, `co_yield` or `co_return`. Lambdas and local
+    /// classes are functions of their own, what they contain does not count.
+    pub fn is_coroutine(&self) -> bool {
+        let Some(body) = self.get_body() else {
+            return false;
+        };
+
+        let mut preorder = body.syntax().preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+            match node.kind().into() {
+                CppSyntaxKind::CoAwaitExpr
+                | CppSyntaxKind::CoYieldExpr
+                | CppSyntaxKind::CoReturnStat => return true,
+                kind if kind == CppSyntaxKind::LambdaExpr || CppClassDef::can_cast(kind) => {
+                    preorder.skip_subtree();
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
+/// Class, struct or union, both forward declarations and definitions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppClassDef {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppClassDef {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::ClassDecl
+                | CppSyntaxKind::ClassDef
+                | CppSyntaxKind::StructDecl
+                | CppSyntaxKind::StructDef
+                | CppSyntaxKind::UnionDecl
+                | CppSyntaxKind::UnionDef
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppClassDef {
+    /// The `class`, `struct` or `union` keyword
+    pub fn get_class_key(&self) -> Option<CppGeneralToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| {
+                matches!(
+                    it.kind().into(),
+                    CppTokenKind::ClassKeyword
+                        | CppTokenKind::StructKeyword
+                        | CppTokenKind::UnionKeyword
+                )
+            })
+            .and_then(CppGeneralToken::cast)
+    }
+
+    /// The unqualified name, `vector` for `class std::vector<int>`
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        declarator_name_tokens(self.syntax()).into_iter().next()
+    }
+
+    pub fn is_definition(&self) -> bool {
+        matches!(
+            self.syntax().kind().into(),
+            CppSyntaxKind::ClassDef | CppSyntaxKind::StructDef | CppSyntaxKind::UnionDef
+        )
+    }
+
+    pub fn get_bases(&self) -> impl Iterator<Item = CppBaseSpecifier> {
+        self.children()
+    }
+
+    pub fn get_body(&self) -> Option<CppCompoundStat> {
+        self.child()
+    }
+
+    pub fn get_members(&self) -> impl Iterator<Item = CppDecl> {
+        self.get_body()
+            .into_iter()
+            .flat_map(|body| body.get_decls())
+    }
+}
+
+/// Unscoped or scoped enumeration: `enum Color { Red }`, `enum class Mode : int;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppEnumDef {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppEnumDef {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::EnumDecl
+                | CppSyntaxKind::EnumDef
+                | CppSyntaxKind::EnumClassDecl
+                | CppSyntaxKind::EnumClassDef
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppEnumDef {
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.token()
+    }
+
+    /// `enum class` or `enum struct`
+    pub fn is_scoped(&self) -> bool {
+        matches!(
+            self.syntax().kind().into(),
+            CppSyntaxKind::EnumClassDecl | CppSyntaxKind::EnumClassDef
+        )
+    }
+
+    pub fn is_definition(&self) -> bool {
+        matches!(
+            self.syntax().kind().into(),
+            CppSyntaxKind::EnumDef | CppSyntaxKind::EnumClassDef
+        )
+    }
+
+    pub fn get_underlying_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    pub fn get_body(&self) -> Option<CppCompoundStat> {
+        self.child()
+    }
+
+    pub fn get_enumerators(&self) -> impl Iterator<Item = CppEnumerator> {
+        self.get_body()
+            .into_iter()
+            .flat_map(|body| body.children::<CppEnumerator>())
+    }
+}
+
+/// Namespace definition or namespace alias: `namespace a::b { }`, `namespace fs = std::filesystem;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppNamespaceDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppNamespaceDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::NamespaceDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppNamespaceDecl {
+    /// Name tokens of the namespace itself, `a` and `b` for `namespace a::b`,
+    /// the alias target of a namespace alias is not included
+    pub fn get_name_tokens(&self) -> impl Iterator<Item = CppNameToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .take_while(|it| it.kind() != CppTokenKind::Assign.into())
+            .filter_map(CppNameToken::cast)
+    }
+
+    /// `None` for an anonymous namespace
+    pub fn get_name_text(&self) -> Option<String> {
+        let names: Vec<String> = self
+            .get_name_tokens()
+            .map(|it| it.get_name_text().to_string())
+            .collect();
+        if names.is_empty() {
+            None
+        } else {
+            Some(names.join("::"))
+        }
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.token_by_kind(CppTokenKind::InlineKeyword).is_some()
+    }
+
+    pub fn is_alias(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Assign).is_some()
+    }
+
+    pub fn get_body(&self) -> Option<CppCompoundStat> {
+        self.child()
+    }
+
+    pub fn get_decls(&self) -> impl Iterator<Item = CppDecl> {
+        self.get_body()
+            .into_iter()
+            .flat_map(|body| body.get_decls())
+    }
+}
+
+/// Data member of a class: `int x = 0, *y;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppFieldDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppFieldDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::FieldDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppFieldDecl {
+    pub fn get_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.get_name_tokens().next()
+    }
+
+    /// One name per declarator: `x` and `y` for `int x, *y;`, `fp` for `void (*fp)(int);`
+    pub fn get_name_tokens(&self) -> impl Iterator<Item = CppNameToken> {
+        declarator_name_tokens(self.syntax()).into_iter()
+    }
+}
+
+/// Variable declaration at namespace or block scope: `static int x = 0, *y;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppVariableDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppVariableDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::VariableDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppVariableDecl {
+    pub fn get_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.get_name_tokens().next()
+    }
+
+    /// One name per declarator: `x` and `y` for `int x, *y;`, `fp` for `void (*fp)(int);`,
+    /// every name of a structured binding
+    pub fn get_name_tokens(&self) -> impl Iterator<Item = CppNameToken> {
+        declarator_name_tokens(self.syntax()).into_iter()
+    }
+
+    /// The names of `auto& [key, value] = pair;`
+    pub fn get_structured_binding(&self) -> Option<CppStructuredBinding> {
+        self.child()
+    }
+}
+
+/// Names introduced by a structured binding declaration: `[key, value]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppStructuredBinding {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppStructuredBinding {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::StructuredBinding
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppStructuredBinding {
+    pub fn get_name_tokens(&self) -> CppAstTokenChildren<CppNameToken> {
+        self.tokens()
+    }
+}
+
+/// `template <typename T> ...`, the explicit specialization `template <> ...`
+/// or the explicit instantiation `extern template ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppTemplateDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppTemplateDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::TemplateDecl
+                | CppSyntaxKind::TemplateSpecialization
+                | CppSyntaxKind::ExplicitInstantiation
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppTemplateDecl {
+    /// `None` for an explicit instantiation, empty for an explicit specialization
+    pub fn get_template_params(&self) -> Option<CppTemplateParameterList> {
+        self.child()
+    }
+
+    /// `template <>`
+    pub fn is_specialization(&self) -> bool {
+        self.syntax().kind() == CppSyntaxKind::TemplateSpecialization.into()
+    }
+
+    /// `template class Foo<int>;` or `extern template class Foo<int>;`
+    pub fn is_explicit_instantiation(&self) -> bool {
+        self.syntax().kind() == CppSyntaxKind::ExplicitInstantiation.into()
+    }
+
+    /// `extern template`
+    pub fn is_extern(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ExternKeyword).is_some()
+    }
+
+    /// `requires C<T>` after the template parameters
+    pub fn get_requires_clause(&self) -> Option<CppRequiresClause> {
+        self.child()
+    }
+
+    /// The templated declaration
+    pub fn get_decl(&self) -> Option<CppDecl> {
+        self.child()
+    }
+}
+
+/// `using std::string;`, `using T = int;` or `using namespace std;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppUsingDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppUsingDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::UsingDecl | CppSyntaxKind::UsingDirective
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppUsingDecl {
+    /// `using namespace std;`
+    pub fn is_directive(&self) -> bool {
+        self.syntax().kind() == CppSyntaxKind::UsingDirective.into()
+    }
+
+    /// `using T = int;`
+    pub fn is_alias(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Assign).is_some()
+    }
+
+    pub fn get_alias_name_token(&self) -> Option<CppNameToken> {
+        if self.is_alias() {
+            self.token()
+        } else {
+            None
+        }
+    }
+
+    pub fn get_aliased_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    /// The introduced name or namespace, `std::string` for `using std::string;`
+    pub fn get_name_text(&self) -> Option<String> {
+        if self.is_alias() {
+            return None;
+        }
+
+        let text: String = self
+            .syntax()
+            .children_with_tokens()
+            .filter(|it| {
+                let kind: CppTokenKind = it.kind().into();
+                !kind.is_trivia()
+                    && !matches!(
+                        kind,
+                        CppTokenKind::UsingKeyword
+                            | CppTokenKind::NamespaceKeyword
+                            | CppTokenKind::TypenameKeyword
+                            | CppTokenKind::Semicolon
+                    )
+            })
+            .map(|it| it.to_string())
+            .collect();
+        if text.is_empty() {
+            None
+        } else {
+            Some(text)
+        }
+    }
+}
+
+/// Concept definition, the template head is its parent: `concept Integral = ...;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppConceptDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppConceptDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::ConceptDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppConceptDecl {
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.token()
+    }
+
+    /// The constraint expression after `=`
+    pub fn get_constraint(&self) -> Option<CppExpr> {
+        self.child()
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    syntax::{
+        node::CppGeneralToken,
+        traits::{CppAstChildren, CppAstNode, CppAstToken},
+    },
+    CppSyntaxNode,
+};
+
+/// Non-trivia tokens that are direct children of `syntax`
+fn significant_tokens(syntax: &CppSyntaxNode) -> impl Iterator<Item = CppGeneralToken> + use<> {
+    syntax
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|token| !CppTokenKind::from(token.kind()).is_trivia())
+        .filter_map(CppGeneralToken::cast)
+}
+
+/// Any preprocessor directive line: `#pragma once`, `#if X > 1`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppDirective {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppDirective {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::IncludeDirective
+                | CppSyntaxKind::DefineDirective
+                | CppSyntaxKind::UndefDirective
+                | CppSyntaxKind::IfdefDirective
+                | CppSyntaxKind::IfndefDirective
+                | CppSyntaxKind::IfDirective
+                | CppSyntaxKind::ElseDirective
+                | CppSyntaxKind::ElifDirective
+                | CppSyntaxKind::EndifDirective
+                | CppSyntaxKind::PragmaDirective
+                | CppSyntaxKind::ErrorDirective
+                | CppSyntaxKind::WarningDirective
+                | CppSyntaxKind::LineDirective
+                | CppSyntaxKind::UnknownDirective
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppDirective {
+    pub fn get_directive_kind(&self) -> CppSyntaxKind {
+        self.syntax().kind().into()
+    }
+
+    /// The name after the `#`, e.g. `include` or `elifdef`
+    pub fn get_name_token(&self) -> Option<CppGeneralToken> {
+        significant_tokens(self.syntax())
+            .nth(1)
+            .filter(|token| token.get_token_kind() != CppTokenKind::IntegerLiteral)
+    }
+
+    /// Tokens after the directive name, e.g. the condition of `#if` or the message of `#error`
+    pub fn get_argument_tokens(&self) -> impl Iterator<Item = CppGeneralToken> + use<> {
+        let skip = if self.get_name_token().is_some() {
+            2
+        } else {
+            1
+        };
+        significant_tokens(self.syntax()).skip(skip)
+    }
+}
+
+/// `#include <vector>` or `#include "config.h"`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppIncludeDirective {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppIncludeDirective {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::IncludeDirective
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppIncludeDirective {
+    /// Missing for computed includes like `#include HEADER`
+    pub fn get_header_name(&self) -> Option<CppGeneralToken> {
+        self.token_by_kind(CppTokenKind::HeaderName)
+    }
+
+    /// The header without its delimiters, `vector` for `<vector>`
+    pub fn get_path(&self) -> Option<String> {
+        let text = self.get_header_name()?.get_text().to_string();
+        let end = if text.ends_with(['>', '"']) && text.len() > 1 {
+            text.len() - 1
+        } else {
+            text.len()
+        };
+        Some(text[1..end].to_string())
+    }
+
+    /// `<...>` headers are only searched in the system include paths
+    pub fn is_angled(&self) -> bool {
+        self.get_header_name()
+            .is_some_and(|token| token.get_text().starts_with('<'))
+    }
+
+    /// GNU `#include_next`, the search continues after the directory of the current file
+    pub fn is_include_next(&self) -> bool {
+        significant_tokens(self.syntax())
+            .nth(1)
+            .is_some_and(|token| token.get_text() == "include_next")
+    }
+}
+
+/// `#define MAX(a, b) ((a) > (b) ? (a) : (b))`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppDefineDirective {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppDefineDirective {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::DefineDirective
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppDefineDirective {
+    /// The macro name, keywords can be redefined so this is not always an identifier
+    pub fn get_name_token(&self) -> Option<CppGeneralToken> {
+        significant_tokens(self.syntax()).nth(2)
+    }
+
+    pub fn get_parameter_list(&self) -> Option<CppMacroParameterList> {
+        self.child()
+    }
+
+    pub fn is_function_like(&self) -> bool {
+        self.get_parameter_list().is_some()
+    }
+
+    pub fn get_body(&self) -> Option<CppMacroBody> {
+        self.child()
+    }
+}
+
+/// Parameters of a function-like macro: `(fmt, ...)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppMacroParameterList {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppMacroParameterList {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::MacroParameterList
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppMacroParameterList {
+    pub fn get_params(&self) -> CppAstChildren<CppMacroParameter> {
+        self.children()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.get_params().any(|param| param.is_variadic())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppMacroParameter {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppMacroParameter {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::MacroParameter
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppMacroParameter {
+    /// Missing for `...`, whose arguments are named `__VA_ARGS__`
+    pub fn get_name_token(&self) -> Option<CppGeneralToken> {
+        significant_tokens(self.syntax())
+            .find(|token| token.get_token_kind() != CppTokenKind::Ellipsis)
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Ellipsis).is_some()
+    }
+}
+
+/// Replacement list of a macro
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppMacroBody {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppMacroBody {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::MacroBody
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppMacroBody {
+    pub fn get_tokens(&self) -> impl Iterator<Item = CppGeneralToken> + use<> {
+        significant_tokens(self.syntax())
+    }
+}
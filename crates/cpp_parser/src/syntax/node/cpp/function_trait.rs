@@ -0,0 +1,195 @@
+use rowan::NodeOrToken;
+
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    CppAstNode, CppAstToken, CppSyntaxNode,
+};
+
+use super::{
+    declarator_name_tokens, CppMemberInitializer, CppNameToken, CppParameter, CppParameterList,
+    CppRequiresClause, CppType,
+};
+
+/// Accessors shared by function declarations and definitions
+pub trait CppFunctionTrait: CppAstNode {
+    /// `None` for constructors, destructors and conversion functions
+    fn get_return_type(&self) -> Option<CppType> {
+        match declarator_function_type(self.syntax()) {
+            Some(function_type) => function_type.get_inner_type(),
+            None => self.child(),
+        }
+    }
+
+    /// The unqualified name, `bar` for `int Foo::bar()`
+    fn get_name_token(&self) -> Option<CppNameToken> {
+        if let Some(function_type) = declarator_function_type(self.syntax()) {
+            return declarator_name_tokens(function_type.syntax()).pop();
+        }
+
+        self.syntax()
+            .children_with_tokens()
+            .take_while(|it| it.kind() != CppSyntaxKind::ParameterList.into())
+            .filter_map(|it| it.into_token())
+            .filter_map(CppNameToken::cast)
+            .last()
+    }
+
+    /// The declarator name as written, e.g. `Foo::bar`, `~Foo`, `operator==` or `operator new`
+    fn get_name_text(&self) -> Option<String> {
+        if declarator_function_type(self.syntax()).is_some() {
+            return self
+                .get_name_token()
+                .map(|it| it.get_name_text().to_string());
+        }
+
+        let mut text = String::new();
+        for element in self.syntax().children_with_tokens() {
+            match element {
+                NodeOrToken::Node(node) => {
+                    if node.kind() == CppSyntaxKind::ParameterList.into() {
+                        break;
+                    }
+
+                    if !text.is_empty() {
+                        text.push_str(&node.text().to_string());
+                    }
+                }
+                NodeOrToken::Token(token) => {
+                    let kind: CppTokenKind = token.kind().into();
+                    if kind.is_trivia() {
+                        continue;
+                    }
+
+                    if text.is_empty()
+                        && !matches!(
+                            kind,
+                            CppTokenKind::Identifier
+                                | CppTokenKind::Scope
+                                | CppTokenKind::Tilde
+                                | CppTokenKind::OperatorKeyword
+                        )
+                    {
+                        continue;
+                    }
+
+                    // `operator co_await` keeps the space between its words
+                    let is_word = |ch: char| ch.is_alphanumeric() || ch == '_';
+                    if text.ends_with(is_word) && token.text().starts_with(is_word) {
+                        text.push(' ');
+                    }
+                    text.push_str(token.text());
+                }
+            }
+        }
+
+        if text.is_empty() {
+            None
+        } else {
+            Some(text)
+        }
+    }
+
+    fn get_param_list(&self) -> Option<CppParameterList> {
+        match declarator_function_type(self.syntax()) {
+            Some(function_type) => declarator_param_list(function_type.syntax()),
+            None => self.child(),
+        }
+    }
+
+    fn get_params(&self) -> Vec<CppParameter> {
+        match self.get_param_list() {
+            Some(param_list) => param_list.get_params().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Trailing requires clause: `void f() requires C<T>;`
+    fn get_requires_clause(&self) -> Option<CppRequiresClause> {
+        self.child()
+    }
+
+    fn get_member_initializers(&self) -> impl Iterator<Item = CppMemberInitializer> {
+        self.children()
+    }
+
+    fn is_virtual(&self) -> bool {
+        self.token_by_kind(CppTokenKind::VirtualKeyword).is_some()
+    }
+
+    fn is_static(&self) -> bool {
+        self.token_by_kind(CppTokenKind::StaticKeyword).is_some()
+    }
+
+    /// `const` after the parameter list
+    fn is_const(&self) -> bool {
+        let mut after_params = false;
+        for element in self.syntax().children_with_tokens() {
+            match element {
+                NodeOrToken::Node(node) => {
+                    if node.kind() == CppSyntaxKind::ParameterList.into() {
+                        after_params = true;
+                    }
+                }
+                NodeOrToken::Token(token) => {
+                    if after_params && token.kind() == CppTokenKind::ConstKeyword.into() {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// `= 0`
+    fn is_pure_virtual(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Assign).is_some()
+            && self.token_by_kind(CppTokenKind::IntegerLiteral).is_some()
+    }
+
+    fn is_defaulted(&self) -> bool {
+        self.token_by_kind(CppTokenKind::DefaultKeyword).is_some()
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.token_by_kind(CppTokenKind::DeleteKeyword).is_some()
+    }
+}
+
+/// The FunctionType of a parenthesized function declarator, the declaration itself holds
+/// no parameter list then: `void (*signal(int, void (*)(int)))(int);`
+fn declarator_function_type(syntax: &CppSyntaxNode) -> Option<CppType> {
+    if syntax
+        .children()
+        .any(|it| it.kind() == CppSyntaxKind::ParameterList.into())
+    {
+        return None;
+    }
+
+    syntax
+        .children()
+        .find_map(CppType::cast)
+        .filter(|it| it.get_type_kind() == CppSyntaxKind::FunctionType)
+}
+
+/// The parameters right after the declarator name, the first parameter list among the
+/// declarator type nodes
+fn declarator_param_list(syntax: &CppSyntaxNode) -> Option<CppParameterList> {
+    for node in syntax.children() {
+        match node.kind().into() {
+            CppSyntaxKind::ParameterList => return CppParameterList::cast(node),
+            CppSyntaxKind::PointerType
+            | CppSyntaxKind::ReferenceType
+            | CppSyntaxKind::RValueReferenceType
+            | CppSyntaxKind::ArrayType
+            | CppSyntaxKind::FunctionType => {
+                if let Some(param_list) = declarator_param_list(&node) {
+                    return Some(param_list);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
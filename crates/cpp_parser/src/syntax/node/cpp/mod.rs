@@ -0,0 +1,983 @@
+mod attribute;
+mod decl;
+mod directive;
+mod function_trait;
+mod module;
+mod test;
+
+use rowan::NodeOrToken;
+
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    syntax::traits::{CppAstChildren, CppAstNode, CppAstToken, CppAstTokenChildren},
+    CppSyntaxNode,
+};
+
+pub use attribute::*;
+pub use decl::*;
+pub use directive::*;
+pub use function_trait::*;
+pub use module::*;
+
+use super::{CppGeneralToken, CppLiteralToken, CppNameToken};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppTranslationUnit {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppTranslationUnit {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::TranslationUnit
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppTranslationUnit {
+    pub fn get_decls(&self) -> CppAstChildren<CppDecl> {
+        self.children()
+    }
+}
+
+/// Brace enclosed body, shared by blocks, class bodies, enum bodies and namespace bodies
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppCompoundStat {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppCompoundStat {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::CompoundStat
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppCompoundStat {
+    pub fn get_decls(&self) -> CppAstChildren<CppDecl> {
+        self.children()
+    }
+}
+
+/// If statement or one of its `else if` branches: `if (x)`, `if constexpr (N > 0)`,
+/// `if !consteval { }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppIfStat {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppIfStat {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(kind, CppSyntaxKind::IfStat | CppSyntaxKind::ElseIfStat)
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppIfStat {
+    /// `if constexpr (...)`, the branch not taken is discarded at compile time
+    pub fn is_constexpr(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ConstexprKeyword).is_some()
+    }
+
+    /// `if consteval` or `if !consteval`, neither has a condition
+    pub fn is_consteval(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ConstevalKeyword).is_some()
+    }
+
+    /// `if !consteval`
+    pub fn is_negated_consteval(&self) -> bool {
+        self.is_consteval() && self.token_by_kind(CppTokenKind::LogicalNot).is_some()
+    }
+
+    /// `None` for `if consteval` and for a declaration condition
+    pub fn get_condition(&self) -> Option<CppExpr> {
+        self.child()
+    }
+
+    /// The declaration of `if (auto* d = dynamic_cast<D*>(b))`
+    pub fn get_condition_decl(&self) -> Option<CppVariableDecl> {
+        self.child()
+    }
+
+    /// The `else if` branches of an if statement
+    pub fn get_else_ifs(&self) -> CppAstChildren<CppIfStat> {
+        self.children()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppParameterList {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppParameterList {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::ParameterList
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppParameterList {
+    pub fn get_params(&self) -> CppAstChildren<CppParameter> {
+        self.children()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppParameter {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppParameter {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::Parameter
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppParameter {
+    pub fn get_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    /// `None` for unnamed parameters
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        declarator_name_tokens(self.syntax()).into_iter().next()
+    }
+
+    pub fn get_default_value(&self) -> Option<CppExpr> {
+        self.child()
+    }
+
+    /// C-style variadic `...` or a parameter pack `Ts... args`
+    pub fn is_variadic(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Ellipsis).is_some()
+    }
+}
+
+/// One entry of a base clause: `public virtual Base<T>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppBaseSpecifier {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppBaseSpecifier {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::BaseSpecifier
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppBaseSpecifier {
+    pub fn get_access_token(&self) -> Option<CppGeneralToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| {
+                matches!(
+                    it.kind().into(),
+                    CppTokenKind::PublicKeyword
+                        | CppTokenKind::ProtectedKeyword
+                        | CppTokenKind::PrivateKeyword
+                )
+            })
+            .and_then(CppGeneralToken::cast)
+    }
+
+    pub fn is_virtual(&self) -> bool {
+        self.token_by_kind(CppTokenKind::VirtualKeyword).is_some()
+    }
+
+    pub fn get_type(&self) -> Option<CppType> {
+        self.child()
+    }
+}
+
+/// Constructor member initializer: `x(0)` or `y{1}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppMemberInitializer {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppMemberInitializer {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::MemberInitializer
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppMemberInitializer {
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.token()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppEnumerator {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppEnumerator {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::EnumeratorDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppEnumerator {
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.token()
+    }
+
+    pub fn get_value(&self) -> Option<CppExpr> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppTemplateParameterList {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppTemplateParameterList {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::TemplateParameterList
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppTemplateParameterList {
+    pub fn get_params(&self) -> CppAstChildren<CppTemplateParameter> {
+        self.children()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppTemplateParameter {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppTemplateParameter {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::TemplateParameter
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppTemplateParameter {
+    /// `None` for unnamed parameters
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        declarator_name_tokens(self.syntax()).into_iter().next()
+    }
+
+    /// `typename T`, `class... Ts` or a template template parameter
+    pub fn is_type_parameter(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .find(|it| !CppTokenKind::from(it.kind()).is_trivia())
+            .is_some_and(|it| {
+                matches!(
+                    it.kind().into(),
+                    CppTokenKind::ClassKeyword
+                        | CppTokenKind::TypenameKeyword
+                        | CppTokenKind::TemplateKeyword
+                )
+            })
+    }
+
+    /// `template <class> class TT`
+    pub fn is_template_template(&self) -> bool {
+        self.get_template_params().is_some()
+    }
+
+    /// Parameters of a template template parameter
+    pub fn get_template_params(&self) -> Option<CppTemplateParameterList> {
+        self.child()
+    }
+
+    /// `class... Ts` or `int... Ns`
+    pub fn is_pack(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Ellipsis).is_some()
+    }
+
+    /// Type of a non-type template parameter: `int N`
+    pub fn get_type(&self) -> Option<CppType> {
+        if self.is_type_parameter() {
+            None
+        } else {
+            self.child()
+        }
+    }
+
+    /// Default argument of a type parameter: `int` for `typename T = int`
+    pub fn get_default_type(&self) -> Option<CppType> {
+        if self.is_type_parameter() {
+            self.child()
+        } else {
+            None
+        }
+    }
+
+    /// Default argument of a non-type parameter: `3` for `int N = 3`
+    pub fn get_default_value(&self) -> Option<CppExpr> {
+        self.child()
+    }
+}
+
+/// Any type node, the concrete form is available through `get_type_kind`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppType {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppType {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::BuiltinType
+                | CppSyntaxKind::PointerType
+                | CppSyntaxKind::ReferenceType
+                | CppSyntaxKind::RValueReferenceType
+                | CppSyntaxKind::ArrayType
+                | CppSyntaxKind::FunctionType
+                | CppSyntaxKind::QualifiedType
+                | CppSyntaxKind::NamedType
+                | CppSyntaxKind::TemplateType
+                | CppSyntaxKind::AutoType
+                | CppSyntaxKind::DecltypeType
+                | CppSyntaxKind::TypenameType
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppType {
+    pub fn get_type_kind(&self) -> CppSyntaxKind {
+        self.syntax().kind().into()
+    }
+
+    /// Pointee, referenced, element or cv-qualified type
+    pub fn get_inner_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    /// Source text of the type without surrounding trivia
+    pub fn get_text(&self) -> String {
+        self.syntax().text().to_string().trim().to_string()
+    }
+}
+
+/// Any expression node, the concrete form is available through `get_expr_kind`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppExpr {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppExpr {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::LiteralExpr
+                | CppSyntaxKind::IdentifierExpr
+                | CppSyntaxKind::ParenExpr
+                | CppSyntaxKind::UnaryExpr
+                | CppSyntaxKind::BinaryExpr
+                | CppSyntaxKind::TernaryExpr
+                | CppSyntaxKind::CallExpr
+                | CppSyntaxKind::MemberExpr
+                | CppSyntaxKind::ArrowExpr
+                | CppSyntaxKind::IndexExpr
+                | CppSyntaxKind::CastExpr
+                | CppSyntaxKind::SizeofExpr
+                | CppSyntaxKind::TypeidExpr
+                | CppSyntaxKind::AlignofExpr
+                | CppSyntaxKind::NoexceptExpr
+                | CppSyntaxKind::ThrowExpr
+                | CppSyntaxKind::CoAwaitExpr
+                | CppSyntaxKind::CoYieldExpr
+                | CppSyntaxKind::NewExpr
+                | CppSyntaxKind::DeleteExpr
+                | CppSyntaxKind::ThisExpr
+                | CppSyntaxKind::LambdaExpr
+                | CppSyntaxKind::RequiresExpr
+                | CppSyntaxKind::InitListExpr
+                | CppSyntaxKind::DesignatedInitExpr
+                | CppSyntaxKind::CompoundLiteralExpr
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppExpr {
+    pub fn get_expr_kind(&self) -> CppSyntaxKind {
+        self.syntax().kind().into()
+    }
+
+    /// Source text of the expression without surrounding trivia
+    pub fn get_text(&self) -> String {
+        self.syntax().text().to_string().trim().to_string()
+    }
+
+    /// Literal tokens of a literal expression, adjacent string literals yield several
+    pub fn get_literal_tokens(&self) -> CppAstTokenChildren<CppLiteralToken> {
+        self.tokens()
+    }
+}
+
+/// Lambda expression, also reachable as a `CppExpr`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppLambdaExpr {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppLambdaExpr {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::LambdaExpr
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppLambdaExpr {
+    pub fn get_capture_list(&self) -> Option<CppCaptureList> {
+        self.child()
+    }
+
+    pub fn get_captures(&self) -> impl Iterator<Item = CppCapture> + use<> {
+        self.get_capture_list()
+            .into_iter()
+            .flat_map(|list| list.get_captures())
+    }
+
+    /// Template parameters of a generic lambda: `[]<typename T>(T x) {}`
+    pub fn get_template_params(&self) -> Option<CppTemplateParameterList> {
+        self.child()
+    }
+
+    /// `None` when the parameter list is omitted: `[] { ... }`
+    pub fn get_params(&self) -> Option<CppParameterList> {
+        self.child()
+    }
+
+    /// Trailing return type: `long` for `[](int x) -> long { ... }`
+    pub fn get_return_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    pub fn get_body(&self) -> Option<CppCompoundStat> {
+        self.child()
+    }
+
+    pub fn is_mutable(&self) -> bool {
+        self.token_by_kind(CppTokenKind::MutableKeyword).is_some()
+    }
+
+    pub fn is_constexpr(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ConstexprKeyword).is_some()
+    }
+
+    /// `static` lambdas (C++23) have no implicit object parameter
+    pub fn is_static(&self) -> bool {
+        self.token_by_kind(CppTokenKind::StaticKeyword).is_some()
+    }
+
+    pub fn is_noexcept(&self) -> bool {
+        self.token_by_kind(CppTokenKind::NoexceptKeyword).is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppCaptureList {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppCaptureList {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::CaptureList
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppCaptureList {
+    pub fn get_captures(&self) -> CppAstChildren<CppCapture> {
+        self.children()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppCapture {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppCapture {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::Capture
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppCapture {
+    /// Captured variable, `None` for capture defaults and `this`
+    pub fn get_name_token(&self) -> Option<CppNameToken> {
+        self.token()
+    }
+
+    /// `[=]` or `[&]`
+    pub fn is_default(&self) -> bool {
+        self.get_name_token().is_none() && self.token_by_kind(CppTokenKind::ThisKeyword).is_none()
+    }
+
+    /// `&x`, `&x = init` or the `[&]` default
+    pub fn is_by_reference(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Ampersand).is_some()
+    }
+
+    /// `this` or `*this`
+    pub fn is_this(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ThisKeyword).is_some()
+    }
+
+    /// `*this` captures the object by copy (C++17)
+    pub fn is_this_copy(&self) -> bool {
+        self.is_this() && self.token_by_kind(CppTokenKind::Star).is_some()
+    }
+
+    /// `args...` or `...xs = std::move(args)`
+    pub fn is_pack(&self) -> bool {
+        self.token_by_kind(CppTokenKind::Ellipsis).is_some()
+    }
+
+    /// Initializer of an init-capture: `std::move(v)` for `y = std::move(v)`
+    pub fn get_init(&self) -> Option<CppExpr> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppRequiresClause {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppRequiresClause {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::RequiresClause
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppRequiresClause {
+    pub fn get_constraint(&self) -> Option<CppExpr> {
+        self.child()
+    }
+}
+
+/// Requires expression, also reachable as a `CppExpr`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppRequiresExpr {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppRequiresExpr {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::RequiresExpr
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppRequiresExpr {
+    /// `None` when the parameter list is omitted: `requires { ... }`
+    pub fn get_params(&self) -> Option<CppParameterList> {
+        self.child()
+    }
+
+    pub fn get_requirements(&self) -> CppAstChildren<CppRequirement> {
+        self.children()
+    }
+}
+
+/// Any requirement of a requires expression, the form is available through `get_requirement_kind`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppRequirement {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppRequirement {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::SimpleRequirement
+                | CppSyntaxKind::TypeRequirement
+                | CppSyntaxKind::CompoundRequirement
+                | CppSyntaxKind::NestedRequirement
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppRequirement {
+    pub fn get_requirement_kind(&self) -> CppSyntaxKind {
+        self.syntax().kind().into()
+    }
+
+    /// The checked expression, `None` for a type requirement
+    pub fn get_expr(&self) -> Option<CppExpr> {
+        self.child()
+    }
+
+    /// The required type of a type requirement or the return type-constraint
+    /// of a compound requirement: `std::same_as<T>` for `{ a + a } -> std::same_as<T>;`
+    pub fn get_type(&self) -> Option<CppType> {
+        self.child()
+    }
+
+    /// `{ expr } noexcept`
+    pub fn is_noexcept(&self) -> bool {
+        self.token_by_kind(CppTokenKind::NoexceptKeyword).is_some()
+    }
+}
+
+/// Names introduced by the declarators of a declaration, including the ones nested in
+/// type nodes such as the `fp` of `int (*fp)(int)`. The qualifier of `int Foo::x` is skipped.
+pub(crate) fn declarator_name_tokens(syntax: &CppSyntaxNode) -> Vec<CppNameToken> {
+    let mut names = Vec::new();
+    collect_declarator_names(syntax, &mut names);
+    names
+}
+
+fn collect_declarator_names(syntax: &CppSyntaxNode, names: &mut Vec<CppNameToken>) {
+    let mut last_is_name = false;
+    for element in syntax.children_with_tokens() {
+        match element {
+            NodeOrToken::Node(node) => {
+                last_is_name = false;
+                if matches!(
+                    node.kind().into(),
+                    CppSyntaxKind::PointerType
+                        | CppSyntaxKind::ReferenceType
+                        | CppSyntaxKind::RValueReferenceType
+                        | CppSyntaxKind::ArrayType
+                        | CppSyntaxKind::FunctionType
+                        | CppSyntaxKind::StructuredBinding
+                ) {
+                    collect_declarator_names(&node, names);
+                }
+            }
+            NodeOrToken::Token(token) => match token.kind().into() {
+                CppTokenKind::Identifier => {
+                    names.extend(CppNameToken::cast(token));
+                    last_is_name = true;
+                }
+                CppTokenKind::Scope if last_is_name => {
+                    names.pop();
+                    last_is_name = false;
+                }
+                kind if kind.is_trivia() => {}
+                _ => last_is_name = false,
+            },
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{
+    kind::{CppSyntaxKind, CppTokenKind},
+    syntax::{
+        node::{CppGeneralToken, CppNameToken},
+        traits::{CppAstNode, CppAstTokenChildren},
+    },
+    CppSyntaxNode,
+};
+
+use super::{CppCompoundStat, CppDecl};
+
+/// Module declaration or module fragment: `export module a.b:part;`, `module;` and
+/// `module :private;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppModuleDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppModuleDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            kind,
+            CppSyntaxKind::ModuleDecl
+                | CppSyntaxKind::GlobalModuleFragment
+                | CppSyntaxKind::PrivateModuleFragment
+        )
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppModuleDecl {
+    /// `module;`
+    pub fn is_global_fragment(&self) -> bool {
+        self.syntax().kind() == CppSyntaxKind::GlobalModuleFragment.into()
+    }
+
+    /// `module :private;`
+    pub fn is_private_fragment(&self) -> bool {
+        self.syntax().kind() == CppSyntaxKind::PrivateModuleFragment.into()
+    }
+
+    /// `export module a;` starts a module interface unit
+    pub fn is_exported(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ExportKeyword).is_some()
+    }
+
+    pub fn get_module_name(&self) -> Option<CppModuleName> {
+        self.child()
+    }
+
+    pub fn get_partition(&self) -> Option<CppModulePartition> {
+        self.child()
+    }
+}
+
+/// Import of a module, a partition or a header unit: `import a.b;`, `import :part;`,
+/// `import <vector>;`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppImportDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppImportDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::ImportDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppImportDecl {
+    /// `export import a;` makes `a` visible to the importers of this unit
+    pub fn is_exported(&self) -> bool {
+        self.token_by_kind(CppTokenKind::ExportKeyword).is_some()
+    }
+
+    pub fn get_module_name(&self) -> Option<CppModuleName> {
+        self.child()
+    }
+
+    pub fn get_partition(&self) -> Option<CppModulePartition> {
+        self.child()
+    }
+
+    /// The header of a header unit with its delimiters, `<vector>` or `"a.h"`
+    pub fn get_header_name_token(&self) -> Option<CppGeneralToken> {
+        self.token_by_kind(CppTokenKind::HeaderName)
+    }
+}
+
+/// Exported declaration or block of them: `export int f();`, `export { ... }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppExportDecl {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppExportDecl {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::ExportDecl
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppExportDecl {
+    /// The block of `export { ... }`
+    pub fn get_body(&self) -> Option<CppCompoundStat> {
+        self.child()
+    }
+
+    /// The exported declarations, the one after `export` or every one in its block
+    pub fn get_decls(&self) -> Vec<CppDecl> {
+        match self.get_body() {
+            Some(body) => body.get_decls().collect(),
+            None => self.children().collect(),
+        }
+    }
+}
+
+/// Dotted name of a module: `a.b`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppModuleName {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppModuleName {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::ModuleName
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppModuleName {
+    pub fn get_name_tokens(&self) -> CppAstTokenChildren<CppNameToken> {
+        self.tokens()
+    }
+
+    /// `a.b`
+    pub fn get_name_text(&self) -> String {
+        dotted_name_text(self.get_name_tokens())
+    }
+}
+
+/// Partition of a module: `:part`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppModulePartition {
+    syntax: CppSyntaxNode,
+}
+
+impl CppAstNode for CppModulePartition {
+    fn syntax(&self) -> &CppSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: CppSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == CppSyntaxKind::ModulePartition
+    }
+
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl CppModulePartition {
+    pub fn get_name_tokens(&self) -> CppAstTokenChildren<CppNameToken> {
+        self.tokens()
+    }
+
+    /// The name without the `:`, `part` for `:part`
+    pub fn get_name_text(&self) -> String {
+        dotted_name_text(self.get_name_tokens())
+    }
+}
+
+fn dotted_name_text(tokens: impl Iterator<Item = CppNameToken>) -> String {
+    tokens
+        .map(|it| it.get_name_text().to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
@@ -0,0 +1,756 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CppAst, CppAstNode, CppAstToken, CppAttributeListKind, CppAttributeOwner, CppClassDef,
+        CppDecl, CppDefineDirective, CppDirective, CppEnumDef, CppExpr, CppFieldDecl,
+        CppFunctionDecl, CppFunctionDef, CppFunctionTrait, CppIfStat, CppIncludeDirective,
+        CppLambdaExpr, CppLiteralEncoding, CppNamespaceDecl, CppParser, CppRequiresExpr,
+        CppSyntaxKind, CppSyntaxTree, CppTemplateDecl, CppUsingDecl, ParserConfig,
+    };
+
+    fn get_tree(code: &str) -> CppSyntaxTree {
+        let config = ParserConfig::default();
+        let tree = CppParser::parse(code, config);
+        assert!(
+            tree.get_errors().is_empty(),
+            "unexpected errors: {:?}",
+            tree.get_errors()
+        );
+        tree
+    }
+
+    #[test]
+    fn test_class_members() {
+        let code = r#"
+            class Derived : public Base, protected virtual Mixin<int> {
+            public:
+                Derived(int v) : value(v) {}
+                ~Derived();
+                virtual int get(int index, const char* name = "x") const override;
+                static void reset() = delete;
+            private:
+                int value = 0, *next;
+            };
+        "#;
+        let tree = get_tree(code);
+        let root = tree.get_translation_unit_node();
+        let class_def = root.descendants::<CppClassDef>().next().unwrap();
+        assert!(class_def.is_definition());
+        assert_eq!(class_def.get_class_key().unwrap().get_text(), "class");
+        assert_eq!(
+            class_def.get_name_token().unwrap().get_name_text(),
+            "Derived"
+        );
+
+        let bases: Vec<_> = class_def.get_bases().collect();
+        assert_eq!(bases.len(), 2);
+        assert_eq!(bases[0].get_access_token().unwrap().get_text(), "public");
+        assert!(!bases[0].is_virtual());
+        assert!(bases[1].is_virtual());
+        assert_eq!(
+            bases[1].get_type().unwrap().get_type_kind(),
+            CppSyntaxKind::TemplateType
+        );
+
+        let members: Vec<_> = class_def.get_members().collect();
+        assert_eq!(members.len(), 5);
+
+        let CppDecl::FunctionDef(ctor) = &members[0] else {
+            panic!("expected constructor definition");
+        };
+        assert!(ctor.get_return_type().is_none());
+        assert_eq!(ctor.get_name_text().unwrap(), "Derived");
+        assert_eq!(ctor.get_member_initializers().count(), 1);
+        assert!(ctor.get_body().is_some());
+
+        let CppDecl::FunctionDecl(dtor) = &members[1] else {
+            panic!("expected destructor declaration");
+        };
+        assert_eq!(dtor.get_name_text().unwrap(), "~Derived");
+        assert!(dtor.get_params().is_empty());
+
+        let CppDecl::FunctionDecl(get) = &members[2] else {
+            panic!("expected method declaration");
+        };
+        assert_eq!(get.get_name_token().unwrap().get_name_text(), "get");
+        assert_eq!(get.get_return_type().unwrap().get_text(), "int");
+        assert!(get.is_virtual());
+        assert!(get.is_const());
+        let params = get.get_params();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].get_name_token().unwrap().get_name_text(), "index");
+        assert_eq!(params[1].get_type().unwrap().get_text(), "const char*");
+        assert_eq!(params[1].get_default_value().unwrap().get_text(), r#""x""#);
+
+        let CppDecl::FunctionDecl(reset) = &members[3] else {
+            panic!("expected static method declaration");
+        };
+        assert!(reset.is_static());
+        assert!(reset.is_deleted());
+
+        let CppDecl::FieldDecl(field) = &members[4] else {
+            panic!("expected field declaration");
+        };
+        assert_eq!(field.get_type().unwrap().get_text(), "int");
+        let names: Vec<_> = field
+            .get_name_tokens()
+            .map(|it| it.get_name_text().to_string())
+            .collect();
+        assert_eq!(names, vec!["value", "next"]);
+    }
+
+    #[test]
+    fn test_declarator_names() {
+        let tree = get_tree(
+            "struct S { void (*callback)(int); int (&refs)[4], *ptrs[2]; int C::*member; };\n\
+             void f(int (*cmp)(int, int), char** argv, int (*)(int));",
+        );
+        let root = tree.get_translation_unit_node();
+        let names: Vec<_> = root
+            .descendants::<CppFieldDecl>()
+            .flat_map(|it| it.get_name_tokens().collect::<Vec<_>>())
+            .map(|it| it.get_name_text().to_string())
+            .collect();
+        assert_eq!(names, vec!["callback", "refs", "ptrs", "member"]);
+
+        let function = root.descendants::<CppFunctionDecl>().next().unwrap();
+        let params = function.get_params();
+        assert_eq!(params.len(), 3);
+        assert_eq!(params[0].get_name_token().unwrap().get_name_text(), "cmp");
+        let cmp_type = params[0].get_type().unwrap();
+        assert_eq!(cmp_type.get_type_kind(), CppSyntaxKind::PointerType);
+        assert_eq!(
+            cmp_type.get_inner_type().unwrap().get_type_kind(),
+            CppSyntaxKind::FunctionType
+        );
+        assert_eq!(params[1].get_name_token().unwrap().get_name_text(), "argv");
+        assert_eq!(params[1].get_type().unwrap().get_text(), "char**");
+        assert!(params[2].get_name_token().is_none());
+
+        let tree = get_tree("void (*signal(int sig, void (*handler)(int)))(int);");
+        let root = tree.get_translation_unit_node();
+        let signal = root.descendants::<CppFunctionDecl>().next().unwrap();
+        assert_eq!(signal.get_name_token().unwrap().get_name_text(), "signal");
+        assert_eq!(signal.get_name_text().as_deref(), Some("signal"));
+        let params = signal.get_params();
+        assert_eq!(params.len(), 2);
+        assert_eq!(
+            params[1].get_name_token().unwrap().get_name_text(),
+            "handler"
+        );
+        assert_eq!(
+            signal.get_return_type().unwrap().get_text(),
+            "void (*signal(int sig, void (*handler)(int)))(int)"
+        );
+        assert_eq!(
+            signal.get_return_type().unwrap().get_type_kind(),
+            CppSyntaxKind::PointerType
+        );
+    }
+
+    #[test]
+    fn test_struct_forward_decl() {
+        let tree = get_tree("struct Node; struct Point { int x; int y; };");
+        let root = tree.get_translation_unit_node();
+        let classes: Vec<_> = root.descendants::<CppClassDef>().collect();
+        assert_eq!(classes.len(), 2);
+        assert!(!classes[0].is_definition());
+        assert!(classes[0].get_body().is_none());
+        assert_eq!(classes[1].get_class_key().unwrap().get_text(), "struct");
+        assert_eq!(
+            classes[1]
+                .get_members()
+                .filter(|it| matches!(it, CppDecl::FieldDecl(_)))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_enum() {
+        let tree =
+            get_tree("enum class Mode : unsigned int { Read = 1, Write = 2, All }; enum Color;");
+        let root = tree.get_translation_unit_node();
+        let enums: Vec<_> = root.descendants::<CppEnumDef>().collect();
+        assert_eq!(enums.len(), 2);
+
+        let mode = &enums[0];
+        assert!(mode.is_scoped());
+        assert!(mode.is_definition());
+        assert_eq!(mode.get_name_token().unwrap().get_name_text(), "Mode");
+        assert_eq!(
+            mode.get_underlying_type().unwrap().get_text(),
+            "unsigned int"
+        );
+        let enumerators: Vec<_> = mode.get_enumerators().collect();
+        assert_eq!(enumerators.len(), 3);
+        assert_eq!(
+            enumerators[1].get_name_token().unwrap().get_name_text(),
+            "Write"
+        );
+        assert_eq!(enumerators[1].get_value().unwrap().get_text(), "2");
+        assert!(enumerators[2].get_value().is_none());
+
+        assert!(!enums[1].is_scoped());
+        assert!(!enums[1].is_definition());
+    }
+
+    #[test]
+    fn test_namespace() {
+        let code = r#"
+            namespace a::b {
+                struct Inner {};
+            }
+            inline namespace v1 {}
+            namespace {}
+            namespace fs = std::filesystem;
+        "#;
+        let tree = get_tree(code);
+        let root = tree.get_translation_unit_node();
+        let namespaces: Vec<_> = root.get_decls().collect();
+        assert_eq!(namespaces.len(), 4);
+
+        let CppDecl::NamespaceDecl(nested) = &namespaces[0] else {
+            panic!("expected namespace");
+        };
+        assert_eq!(nested.get_name_text().unwrap(), "a::b");
+        assert!(!nested.is_alias());
+        let decls: Vec<_> = nested.get_decls().collect();
+        assert_eq!(decls.len(), 1);
+        assert!(matches!(decls[0], CppDecl::ClassDef(_)));
+
+        let inline_ns = CppNamespaceDecl::cast(namespaces[1].syntax().clone()).unwrap();
+        assert!(inline_ns.is_inline());
+        assert_eq!(inline_ns.get_name_text().unwrap(), "v1");
+
+        let anonymous = CppNamespaceDecl::cast(namespaces[2].syntax().clone()).unwrap();
+        assert!(anonymous.get_name_text().is_none());
+
+        let alias = CppNamespaceDecl::cast(namespaces[3].syntax().clone()).unwrap();
+        assert!(alias.is_alias());
+        assert_eq!(alias.get_name_text().unwrap(), "fs");
+        assert!(alias.get_body().is_none());
+    }
+
+    #[test]
+    fn test_using() {
+        let tree = get_tree("using namespace std; using std::string; using Int = const int;");
+        let root = tree.get_translation_unit_node();
+        let usings: Vec<_> = root.descendants::<CppUsingDecl>().collect();
+        assert_eq!(usings.len(), 3);
+
+        assert!(usings[0].is_directive());
+        assert_eq!(usings[0].get_name_text().unwrap(), "std");
+
+        assert!(!usings[1].is_directive());
+        assert!(!usings[1].is_alias());
+        assert_eq!(usings[1].get_name_text().unwrap(), "std::string");
+
+        assert!(usings[2].is_alias());
+        assert_eq!(
+            usings[2].get_alias_name_token().unwrap().get_name_text(),
+            "Int"
+        );
+        assert_eq!(
+            usings[2].get_aliased_type().unwrap().get_text(),
+            "const int"
+        );
+    }
+
+    #[test]
+    fn test_templates() {
+        let code = r#"
+            template <typename T = int, std::size_t N = 4, class... Ts, template <class> class TT>
+            struct Array {};
+            template <> struct Array<bool> {};
+            extern template class std::vector<int>;
+        "#;
+        let tree = get_tree(code);
+        let root = tree.get_translation_unit_node();
+        let templates: Vec<_> = root.descendants::<CppTemplateDecl>().collect();
+        assert_eq!(templates.len(), 3);
+
+        let params: Vec<_> = templates[0]
+            .get_template_params()
+            .unwrap()
+            .get_params()
+            .collect();
+        assert_eq!(params.len(), 4);
+        assert!(params[0].is_type_parameter());
+        assert_eq!(params[0].get_name_token().unwrap().get_name_text(), "T");
+        assert!(params[0].get_type().is_none());
+        assert_eq!(params[0].get_default_type().unwrap().get_text(), "int");
+        assert!(!params[1].is_type_parameter());
+        assert_eq!(params[1].get_type().unwrap().get_text(), "std::size_t");
+        assert_eq!(params[1].get_default_value().unwrap().get_text(), "4");
+        assert!(params[2].is_pack());
+        assert!(params[3].is_template_template());
+        assert_eq!(params[3].get_name_token().unwrap().get_name_text(), "TT");
+        let Some(CppDecl::ClassDef(array)) = templates[0].get_decl() else {
+            panic!("expected class template");
+        };
+        assert_eq!(array.get_name_token().unwrap().get_name_text(), "Array");
+
+        assert!(templates[1].is_specialization());
+        assert_eq!(
+            templates[1]
+                .get_template_params()
+                .unwrap()
+                .get_params()
+                .count(),
+            0
+        );
+
+        assert!(templates[2].is_explicit_instantiation());
+        assert!(templates[2].is_extern());
+        assert!(templates[2].get_template_params().is_none());
+        let Some(CppDecl::ClassDef(vector)) = templates[2].get_decl() else {
+            panic!("expected class declaration");
+        };
+        assert!(!vector.is_definition());
+        assert_eq!(vector.get_name_token().unwrap().get_name_text(), "vector");
+    }
+
+    #[test]
+    fn test_lambdas() {
+        let code = r#"
+            auto f = [=, &total, *this, v = std::move(items), args...](int k) mutable noexcept -> long {
+                return k;
+            };
+        "#;
+        let tree = get_tree(code);
+        let root = tree.get_translation_unit_node();
+        let lambda = root.descendants::<CppLambdaExpr>().next().unwrap();
+        assert!(lambda.is_mutable());
+        assert!(lambda.is_noexcept());
+        assert!(!lambda.is_static());
+        assert!(lambda.get_template_params().is_none());
+        assert_eq!(lambda.get_params().unwrap().get_params().count(), 1);
+        assert_eq!(lambda.get_return_type().unwrap().get_text(), "long");
+        assert!(lambda.get_body().is_some());
+
+        let captures: Vec<_> = lambda.get_captures().collect();
+        assert_eq!(captures.len(), 5);
+        assert!(captures[0].is_default());
+        assert!(!captures[0].is_by_reference());
+        assert!(captures[1].is_by_reference());
+        assert_eq!(
+            captures[1].get_name_token().unwrap().get_name_text(),
+            "total"
+        );
+        assert!(captures[2].is_this_copy());
+        assert!(!captures[2].is_default());
+        assert_eq!(captures[3].get_name_token().unwrap().get_name_text(), "v");
+        assert_eq!(
+            captures[3].get_init().unwrap().get_text(),
+            "std::move(items)"
+        );
+        assert!(captures[4].is_pack());
+        assert!(captures[4].get_init().is_none());
+    }
+
+    #[test]
+    fn test_concepts() {
+        let code = r#"
+            template <typename T>
+            concept Hashable = requires (T a) {
+                { std::hash<T>{}(a) } noexcept -> std::convertible_to<std::size_t>;
+                typename T::key_type;
+            };
+
+            template <typename T> requires Hashable<T>
+            void insert(T value) requires (sizeof(T) < 64);
+        "#;
+        let tree = get_tree(code);
+        let root = tree.get_translation_unit_node();
+        let templates: Vec<_> = root.descendants::<CppTemplateDecl>().collect();
+        assert_eq!(templates.len(), 2);
+
+        let Some(CppDecl::ConceptDecl(concept)) = templates[0].get_decl() else {
+            panic!("expected concept definition");
+        };
+        assert_eq!(
+            concept.get_name_token().unwrap().get_name_text(),
+            "Hashable"
+        );
+        let constraint = concept.get_constraint().unwrap();
+        assert_eq!(constraint.get_expr_kind(), CppSyntaxKind::RequiresExpr);
+        let requires_expr = CppRequiresExpr::cast(constraint.syntax().clone()).unwrap();
+        assert_eq!(requires_expr.get_params().unwrap().get_params().count(), 1);
+        let requirements: Vec<_> = requires_expr.get_requirements().collect();
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(
+            requirements[0].get_requirement_kind(),
+            CppSyntaxKind::CompoundRequirement
+        );
+        assert!(requirements[0].is_noexcept());
+        assert_eq!(
+            requirements[0].get_type().unwrap().get_text(),
+            "std::convertible_to<std::size_t>"
+        );
+        assert_eq!(
+            requirements[1].get_requirement_kind(),
+            CppSyntaxKind::TypeRequirement
+        );
+        assert!(requirements[1].get_expr().is_none());
+
+        assert_eq!(
+            templates[1]
+                .get_requires_clause()
+                .unwrap()
+                .get_constraint()
+                .unwrap()
+                .get_text(),
+            "Hashable<T>"
+        );
+        let Some(CppDecl::FunctionDecl(insert)) = templates[1].get_decl() else {
+            panic!("expected function declaration");
+        };
+        assert_eq!(
+            insert
+                .get_requires_clause()
+                .unwrap()
+                .get_constraint()
+                .unwrap()
+                .get_text(),
+            "(sizeof(T) < 64)"
+        );
+    }
+
+    #[test]
+    fn test_literals() {
+        let tree = get_tree(
+            r#"auto a = u8R"x(raw)x"; auto b = L"w" "s"_t; auto c = 0x10_km; auto d = 1.5e3_deg;"#,
+        );
+        let root = tree.get_translation_unit_node();
+        let literals: Vec<_> = root
+            .descendants::<CppExpr>()
+            .filter(|expr| expr.get_expr_kind() == CppSyntaxKind::LiteralExpr)
+            .flat_map(|expr| expr.get_literal_tokens())
+            .map(|token| {
+                (
+                    token.get_encoding(),
+                    token.is_raw(),
+                    token.get_ud_suffix().map(|suffix| suffix.to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            literals,
+            vec![
+                (CppLiteralEncoding::Utf8, true, None),
+                (CppLiteralEncoding::Wide, false, None),
+                (CppLiteralEncoding::Ordinary, false, Some("_t".to_string())),
+                (CppLiteralEncoding::Ordinary, false, Some("_km".to_string())),
+                (
+                    CppLiteralEncoding::Ordinary,
+                    false,
+                    Some("_deg".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cpp_ast_cast() {
+        let tree = get_tree("struct S { int f(); int x; };");
+        let root = tree.get_red_root();
+        let kinds: Vec<_> = root
+            .descendants()
+            .filter_map(CppAst::cast)
+            .filter_map(|it| match it {
+                CppAst::CppClassDef(_) => Some("class"),
+                CppAst::CppFunctionDecl(_) => Some("function"),
+                CppAst::CppFieldDecl(_) => Some("field"),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(kinds, vec!["class", "function", "field"]);
+
+        let field = root.descendants().find_map(CppFieldDecl::cast).unwrap();
+        assert!(CppFunctionDef::cast(field.syntax().clone()).is_none());
+    }
+
+    #[test]
+    fn test_directives() {
+        let tree = get_tree(
+            "#include <sys/types.h>\n#include_next \"local.h\"\n#define LOG(fmt, ...) printf(fmt, __VA_ARGS__)\n#define ONE 1\n#if ONE > 0\n#endif\n",
+        );
+        let root = tree.get_red_root();
+
+        let includes: Vec<_> = root
+            .descendants()
+            .filter_map(CppIncludeDirective::cast)
+            .map(|it| (it.get_path().unwrap(), it.is_angled(), it.is_include_next()))
+            .collect();
+        assert_eq!(
+            includes,
+            vec![
+                ("sys/types.h".to_string(), true, false),
+                ("local.h".to_string(), false, true),
+            ]
+        );
+
+        let defines: Vec<_> = root
+            .descendants()
+            .filter_map(CppDefineDirective::cast)
+            .collect();
+        assert_eq!(defines.len(), 2);
+        let log = &defines[0];
+        assert_eq!(log.get_name_token().unwrap().get_text(), "LOG");
+        assert!(log.is_function_like());
+        let params = log.get_parameter_list().unwrap();
+        assert!(params.is_variadic());
+        let names: Vec<_> = params
+            .get_params()
+            .map(|param| param.get_name_token().map(|it| it.get_text().to_string()))
+            .collect();
+        assert_eq!(names, vec![Some("fmt".to_string()), None]);
+        let body: Vec<_> = log
+            .get_body()
+            .unwrap()
+            .get_tokens()
+            .map(|it| it.get_text().to_string())
+            .collect();
+        assert_eq!(body.join(" "), "printf ( fmt , __VA_ARGS__ )");
+        assert!(!defines[1].is_function_like());
+
+        let condition = root
+            .descendants()
+            .filter_map(CppDirective::cast)
+            .find(|it| it.get_directive_kind() == CppSyntaxKind::IfDirective)
+            .unwrap();
+        assert_eq!(condition.get_name_token().unwrap().get_text(), "if");
+        let arguments: Vec<_> = condition
+            .get_argument_tokens()
+            .map(|it| it.get_text().to_string())
+            .collect();
+        assert_eq!(arguments, vec!["ONE", ">", "0"]);
+    }
+
+    #[test]
+    fn test_modules() {
+        let tree = get_tree(
+            "module;\nexport module a.b:part;\nimport <vector>;\nexport import :impl;\nexport int f();\nexport {\n    int g();\n    struct S {};\n}\nmodule :private;\n",
+        );
+        let decls: Vec<_> = tree.get_translation_unit_node().get_decls().collect();
+        assert_eq!(decls.len(), 7);
+
+        let CppDecl::ModuleDecl(fragment) = &decls[0] else {
+            panic!("expected the global module fragment");
+        };
+        assert!(fragment.is_global_fragment());
+        let CppDecl::ModuleDecl(module) = &decls[1] else {
+            panic!("expected a module declaration");
+        };
+        assert!(module.is_exported());
+        assert_eq!(module.get_module_name().unwrap().get_name_text(), "a.b");
+        assert_eq!(module.get_partition().unwrap().get_name_text(), "part");
+
+        let CppDecl::ImportDecl(header) = &decls[2] else {
+            panic!("expected an import");
+        };
+        assert!(!header.is_exported());
+        assert_eq!(
+            header.get_header_name_token().unwrap().get_text(),
+            "<vector>"
+        );
+        let CppDecl::ImportDecl(partition) = &decls[3] else {
+            panic!("expected an import");
+        };
+        assert!(partition.is_exported());
+        assert!(partition.get_module_name().is_none());
+        assert_eq!(partition.get_partition().unwrap().get_name_text(), "impl");
+
+        let CppDecl::ExportDecl(function) = &decls[4] else {
+            panic!("expected an export");
+        };
+        assert!(matches!(
+            function.get_decls().as_slice(),
+            [CppDecl::FunctionDecl(_)]
+        ));
+        let CppDecl::ExportDecl(block) = &decls[5] else {
+            panic!("expected an export");
+        };
+        assert!(block.get_body().is_some());
+        assert!(matches!(
+            block.get_decls().as_slice(),
+            [CppDecl::FunctionDecl(_), CppDecl::ClassDef(_)]
+        ));
+
+        let CppDecl::ModuleDecl(private) = &decls[6] else {
+            panic!("expected the private module fragment");
+        };
+        assert!(private.is_private_fragment());
+        assert!(private.get_module_name().is_none());
+    }
+
+    #[test]
+    fn test_coroutines() {
+        let tree = get_tree(
+            r#"
+            task<int> read() { auto n = co_await next(); co_return n; }
+            generator<int> count() { for (int i = 0;; ++i) co_yield i; }
+            task<void> plain() { auto f = []() -> task<void> { co_return; }; }
+            auto Awaitable::operator co_await() { return awaiter{}; }
+        "#,
+        );
+        let functions: Vec<_> = tree
+            .get_translation_unit_node()
+            .get_decls()
+            .filter_map(|decl| match decl {
+                CppDecl::FunctionDef(function) => {
+                    Some((function.get_name_text().unwrap(), function.is_coroutine()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            functions,
+            vec![
+                ("read".to_string(), true),
+                ("count".to_string(), true),
+                ("plain".to_string(), false),
+                ("Awaitable::operator co_await".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let tree = get_tree(
+            r#"
+            [[nodiscard("check it")]] int f();
+            [[deprecated("use g2")]] void g([[maybe_unused]] int a, int b);
+            __attribute__((warn_unused_result, __deprecated__)) int h();
+            __declspec(deprecated) void k();
+            struct [[gnu::unused]] S { [[clang::annotate("x")]] int x; };
+            [[using gnu: unused]] int v;
+        "#,
+        );
+        let decls: Vec<CppDecl> = tree.get_translation_unit_node().get_decls().collect();
+        assert!(decls[0].is_nodiscard());
+        assert!(!decls[0].is_deprecated());
+        assert_eq!(
+            decls[0]
+                .get_attribute("nodiscard")
+                .unwrap()
+                .get_string_argument(),
+            Some("check it".to_string())
+        );
+
+        assert!(decls[1].is_deprecated());
+        assert_eq!(
+            decls[1].get_deprecated_message(),
+            Some("use g2".to_string())
+        );
+        let CppDecl::FunctionDecl(g) = &decls[1] else {
+            panic!("expected function declaration");
+        };
+        let unused: Vec<bool> = g
+            .get_param_list()
+            .unwrap()
+            .get_params()
+            .map(|parameter| parameter.is_maybe_unused())
+            .collect();
+        assert_eq!(unused, vec![true, false]);
+
+        assert!(decls[2].is_nodiscard());
+        assert!(decls[2].is_deprecated());
+        assert_eq!(decls[2].get_deprecated_message(), None);
+        let list = decls[2].get_attribute_lists().next().unwrap();
+        assert_eq!(list.get_list_kind(), CppAttributeListKind::Gnu);
+
+        assert!(decls[3].is_deprecated());
+
+        let CppDecl::ClassDef(s) = &decls[4] else {
+            panic!("expected class");
+        };
+        assert!(s.is_maybe_unused());
+        let CppDecl::FieldDecl(x) = s.get_members().next().unwrap() else {
+            panic!("expected field");
+        };
+        let annotate = x.get_attributes().next().unwrap();
+        assert_eq!(annotate.get_name_text(), "annotate");
+        assert_eq!(annotate.get_namespace_text(), Some("clang".to_string()));
+        assert!(!annotate.is_named("annotate"));
+
+        let unused = decls[5].get_attributes().next().unwrap();
+        assert_eq!(unused.get_namespace_text(), Some("gnu".to_string()));
+        assert!(decls[5].is_maybe_unused());
+    }
+
+    #[test]
+    fn test_structured_bindings() {
+        let tree = get_tree("auto& [key, value] = pair; int x, *y;");
+        let names: Vec<Vec<String>> = tree
+            .get_translation_unit_node()
+            .get_decls()
+            .filter_map(|decl| match decl {
+                CppDecl::VariableDecl(variable) => Some(
+                    variable
+                        .get_name_tokens()
+                        .map(|it| it.get_name_text().to_string())
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                vec!["key".to_string(), "value".to_string()],
+                vec!["x".to_string(), "y".to_string()]
+            ]
+        );
+
+        let Some(CppDecl::VariableDecl(variable)) =
+            tree.get_translation_unit_node().get_decls().next()
+        else {
+            panic!("expected variable");
+        };
+        let binding = variable.get_structured_binding().unwrap();
+        assert_eq!(binding.get_name_tokens().count(), 2);
+    }
+
+    #[test]
+    fn test_if_stat() {
+        let tree = get_tree(
+            r#"
+            void f() {
+                if constexpr (N > 0) {} else if (x) {}
+                if !consteval {}
+            }
+        "#,
+        );
+        let ifs: Vec<CppIfStat> = tree
+            .get_translation_unit_node()
+            .descendants::<CppIfStat>()
+            .filter(|it| it.syntax().kind() == CppSyntaxKind::IfStat.into())
+            .collect();
+        assert_eq!(ifs.len(), 2);
+        assert!(ifs[0].is_constexpr());
+        assert!(!ifs[0].is_consteval());
+        assert!(ifs[0].get_condition().is_some());
+        let else_if = ifs[0].get_else_ifs().next().unwrap();
+        assert!(!else_if.is_constexpr());
+
+        assert!(ifs[1].is_consteval());
+        assert!(ifs[1].is_negated_consteval());
+        assert!(ifs[1].get_condition().is_none());
+
+        let tree = get_tree("void f() { if (auto* d = dynamic_cast<D*>(b)) {} }");
+        let if_stat = tree
+            .get_translation_unit_node()
+            .descendants::<CppIfStat>()
+            .next()
+            .unwrap();
+        assert!(if_stat.get_condition().is_none());
+        let decl = if_stat.get_condition_decl().unwrap();
+        let names: Vec<_> = decl
+            .get_name_tokens()
+            .map(|it| it.get_name_text().to_string())
+            .collect();
+        assert_eq!(names, vec!["d"]);
+    }
+}
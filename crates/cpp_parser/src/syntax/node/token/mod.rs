@@ -1,9 +1,4 @@
-mod number_analyzer;
-mod string_analyzer;
-mod test;
 mod tokens;
 
-pub use number_analyzer::{float_token_value, int_token_value};
-pub use string_analyzer::string_token_value;
 #[allow(unused)]
 pub use tokens::*;
@@ -4,75 +4,75 @@ use rowan::{TextRange, TextSize, WalkEvent};
 
 use crate::kind::{CppSyntaxKind, CppTokenKind};
 
-use super::LuaSyntaxId;
+use super::{CppSyntaxId, CppSyntaxNodePtr};
 pub use super::{
-    node::*, LuaSyntaxElementChildren, LuaSyntaxNode, LuaSyntaxNodeChildren, LuaSyntaxToken,
+    node::*, CppSyntaxElementChildren, CppSyntaxNode, CppSyntaxNodeChildren, CppSyntaxToken,
 };
 
-pub trait LuaAstNode {
-    fn syntax(&self) -> &LuaSyntaxNode;
+pub trait CppAstNode {
+    fn syntax(&self) -> &CppSyntaxNode;
 
     fn can_cast(kind: CppSyntaxKind) -> bool
     where
         Self: Sized;
 
-    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    fn cast(syntax: CppSyntaxNode) -> Option<Self>
     where
         Self: Sized;
 
-    fn child<N: LuaAstNode>(&self) -> Option<N> {
+    fn child<N: CppAstNode>(&self) -> Option<N> {
         self.syntax().children().find_map(N::cast)
     }
 
-    fn token<N: LuaAstToken>(&self) -> Option<N> {
+    fn token<N: CppAstToken>(&self) -> Option<N> {
         self.syntax()
             .children_with_tokens()
             .find_map(|it| it.into_token().and_then(N::cast))
     }
 
-    fn token_by_kind(&self, kind: CppTokenKind) -> Option<LuaGeneralToken> {
+    fn token_by_kind(&self, kind: CppTokenKind) -> Option<CppGeneralToken> {
         let token = self
             .syntax()
             .children_with_tokens()
             .filter_map(|it| it.into_token())
             .find(|it| it.kind() == kind.into())?;
 
-        LuaGeneralToken::cast(token)
+        CppGeneralToken::cast(token)
     }
 
-    fn tokens<N: LuaAstToken>(&self) -> LuaAstTokenChildren<N> {
-        LuaAstTokenChildren::new(self.syntax())
+    fn tokens<N: CppAstToken>(&self) -> CppAstTokenChildren<N> {
+        CppAstTokenChildren::new(self.syntax())
     }
 
-    fn children<N: LuaAstNode>(&self) -> LuaAstChildren<N> {
-        LuaAstChildren::new(self.syntax())
+    fn children<N: CppAstNode>(&self) -> CppAstChildren<N> {
+        CppAstChildren::new(self.syntax())
     }
 
-    fn descendants<N: LuaAstNode>(&self) -> impl Iterator<Item = N> {
+    fn descendants<N: CppAstNode>(&self) -> impl Iterator<Item = N> {
         self.syntax().descendants().filter_map(N::cast)
     }
 
-    fn walk_descendants<N: LuaAstNode>(&self) -> impl Iterator<Item = WalkEvent<N>> {
+    fn walk_descendants<N: CppAstNode>(&self) -> impl Iterator<Item = WalkEvent<N>> {
         self.syntax().preorder().filter_map(|event| match event {
             WalkEvent::Enter(node) => N::cast(node).map(WalkEvent::Enter),
             WalkEvent::Leave(node) => N::cast(node).map(WalkEvent::Leave),
         })
     }
 
-    fn ancestors<N: LuaAstNode>(&self) -> impl Iterator<Item = N> {
+    fn ancestors<N: CppAstNode>(&self) -> impl Iterator<Item = N> {
         self.syntax().ancestors().filter_map(N::cast)
     }
 
-    fn get_root(&self) -> LuaSyntaxNode {
+    fn get_root(&self) -> CppSyntaxNode {
         let syntax = self.syntax();
-        if syntax.kind() == CppSyntaxKind::Chunk.into() {
+        if syntax.kind() == CppSyntaxKind::TranslationUnit.into() {
             syntax.clone()
         } else {
             syntax.ancestors().last().unwrap()
         }
     }
 
-    fn get_parent<N: LuaAstNode>(&self) -> Option<N> {
+    fn get_parent<N: CppAstNode>(&self) -> Option<N> {
         self.syntax().parent().and_then(N::cast)
     }
 
@@ -85,8 +85,15 @@ pub trait LuaAstNode {
         self.syntax().text_range()
     }
 
-    fn get_syntax_id(&self) -> LuaSyntaxId {
-        LuaSyntaxId::from_node(self.syntax())
+    fn get_syntax_id(&self) -> CppSyntaxId {
+        CppSyntaxId::from_node(self.syntax())
+    }
+
+    fn to_ptr(&self) -> CppAstPtr<Self>
+    where
+        Self: Sized,
+    {
+        CppAstPtr::new(self)
     }
 
     fn dump(&self) -> String {
@@ -96,21 +103,21 @@ pub trait LuaAstNode {
 
 /// An iterator over `SyntaxNode` children of a particular AST type.
 #[derive(Debug, Clone)]
-pub struct LuaAstChildren<N> {
-    inner: LuaSyntaxNodeChildren,
+pub struct CppAstChildren<N> {
+    inner: CppSyntaxNodeChildren,
     ph: PhantomData<N>,
 }
 
-impl<N> LuaAstChildren<N> {
-    pub fn new(parent: &LuaSyntaxNode) -> LuaAstChildren<N> {
-        LuaAstChildren {
+impl<N> CppAstChildren<N> {
+    pub fn new(parent: &CppSyntaxNode) -> CppAstChildren<N> {
+        CppAstChildren {
             inner: parent.children(),
             ph: PhantomData,
         }
     }
 }
 
-impl<N: LuaAstNode> Iterator for LuaAstChildren<N> {
+impl<N: CppAstNode> Iterator for CppAstChildren<N> {
     type Item = N;
 
     fn next(&mut self) -> Option<N> {
@@ -118,14 +125,14 @@ impl<N: LuaAstNode> Iterator for LuaAstChildren<N> {
     }
 }
 
-pub trait LuaAstToken {
-    fn syntax(&self) -> &LuaSyntaxToken;
+pub trait CppAstToken {
+    fn syntax(&self) -> &CppSyntaxToken;
 
     fn can_cast(kind: CppTokenKind) -> bool
     where
         Self: Sized;
 
-    fn cast(syntax: LuaSyntaxToken) -> Option<Self>
+    fn cast(syntax: CppSyntaxToken) -> Option<Self>
     where
         Self: Sized;
 
@@ -142,19 +149,19 @@ pub trait LuaAstToken {
         self.syntax().text_range()
     }
 
-    fn get_syntax_id(&self) -> LuaSyntaxId {
-        LuaSyntaxId::from_token(self.syntax())
+    fn get_syntax_id(&self) -> CppSyntaxId {
+        CppSyntaxId::from_token(self.syntax())
     }
 
     fn get_text(&self) -> &str {
         self.syntax().text()
     }
 
-    fn get_parent<N: LuaAstNode>(&self) -> Option<N> {
+    fn get_parent<N: CppAstNode>(&self) -> Option<N> {
         self.syntax().parent().and_then(N::cast)
     }
 
-    fn ancestors<N: LuaAstNode>(&self) -> impl Iterator<Item = N> {
+    fn ancestors<N: CppAstNode>(&self) -> impl Iterator<Item = N> {
         self.syntax().parent_ancestors().filter_map(N::cast)
     }
 
@@ -164,24 +171,50 @@ pub trait LuaAstToken {
 }
 
 #[derive(Debug, Clone)]
-pub struct LuaAstTokenChildren<N> {
-    inner: LuaSyntaxElementChildren,
+pub struct CppAstTokenChildren<N> {
+    inner: CppSyntaxElementChildren,
     ph: PhantomData<N>,
 }
 
-impl<N> LuaAstTokenChildren<N> {
-    pub fn new(parent: &LuaSyntaxNode) -> LuaAstTokenChildren<N> {
-        LuaAstTokenChildren {
+impl<N> CppAstTokenChildren<N> {
+    pub fn new(parent: &CppSyntaxNode) -> CppAstTokenChildren<N> {
+        CppAstTokenChildren {
             inner: parent.children_with_tokens(),
             ph: PhantomData,
         }
     }
 }
 
-impl<N: LuaAstToken> Iterator for LuaAstTokenChildren<N> {
+impl<N: CppAstToken> Iterator for CppAstTokenChildren<N> {
     type Item = N;
 
     fn next(&mut self) -> Option<N> {
         self.inner.find_map(|it| it.into_token().and_then(N::cast))
     }
 }
+
+/// A pointer to a typed AST node that does not keep the tree alive,
+/// it is resolved again from the root of the tree it was created in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CppAstPtr<N: CppAstNode> {
+    pub syntax_ptr: CppSyntaxNodePtr,
+    ph: PhantomData<N>,
+}
+
+impl<N: CppAstNode> CppAstPtr<N> {
+    pub fn new(node: &N) -> Self {
+        CppAstPtr {
+            syntax_ptr: CppSyntaxNodePtr::new(node.syntax()),
+            ph: PhantomData,
+        }
+    }
+
+    pub fn get_syntax_id(&self) -> CppSyntaxId {
+        CppSyntaxId::from_ptr(self.syntax_ptr)
+    }
+
+    pub fn to_node(&self, root: &CppSyntaxNode) -> Option<N> {
+        let syntax_node = self.syntax_ptr.try_to_node(root)?;
+        N::cast(syntax_node)
+    }
+}
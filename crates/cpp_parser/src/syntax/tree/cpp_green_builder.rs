@@ -6,7 +6,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone)]
-enum LuaGreenElement {
+enum CppGreenElement {
     None,
     Node {
         kind: CppSyntaxKind,
@@ -19,21 +19,21 @@ enum LuaGreenElement {
 }
 /// A builder for a green tree.
 #[derive(Default, Debug)]
-pub struct LuaGreenNodeBuilder<'cache> {
+pub struct CppGreenNodeBuilder<'cache> {
     parents: Vec<(CppSyntaxKind, usize)>,
     children: Vec<usize>, /*index for elements*/
-    elements: Vec<LuaGreenElement>,
+    elements: Vec<CppGreenElement>,
     builder: rowan::GreenNodeBuilder<'cache>,
 }
 
-impl LuaGreenNodeBuilder<'_> {
+impl CppGreenNodeBuilder<'_> {
     /// Creates new builder.
-    pub fn new() -> LuaGreenNodeBuilder<'static> {
-        LuaGreenNodeBuilder::default()
+    pub fn new() -> CppGreenNodeBuilder<'static> {
+        CppGreenNodeBuilder::default()
     }
 
-    pub fn with_cache(cache: &mut NodeCache) -> LuaGreenNodeBuilder<'_> {
-        LuaGreenNodeBuilder {
+    pub fn with_cache(cache: &mut NodeCache) -> CppGreenNodeBuilder<'_> {
+        CppGreenNodeBuilder {
             parents: Vec::new(),
             children: Vec::new(),
             elements: Vec::new(),
@@ -44,7 +44,7 @@ impl LuaGreenNodeBuilder<'_> {
     #[inline]
     pub fn token(&mut self, kind: CppTokenKind, range: SourceRange) {
         let len = self.elements.len();
-        self.elements.push(LuaGreenElement::Token { kind, range });
+        self.elements.push(CppGreenElement::Token { kind, range });
         self.children.push(len);
     }
 
@@ -56,36 +56,22 @@ impl LuaGreenNodeBuilder<'_> {
 
     #[inline]
     pub fn finish_node(&mut self) {
-        if self.parents.is_empty() || self.children.is_empty() {
+        let Some((parent_kind, first_start)) = self.parents.pop() else {
+            return;
+        };
+        // a node without any child is dropped
+        if first_start >= self.children.len() {
             return;
         }
 
-        let (parent_kind, mut first_start) = self.parents.pop().unwrap();
-        let mut child_start = first_start;
-        let mut child_end = self.children.len() - 1;
         let child_count = self.children.len();
-        let green = match parent_kind {
-            CppSyntaxKind::Block | CppSyntaxKind::Chunk => {
-                while child_start > 0 {
-                    if self.is_trivia(self.children[child_start - 1]) {
-                        child_start -= 1;
-                    } else {
-                        break;
-                    }
-                }
-                if child_start < first_start {
-                    first_start = child_start;
-                }
-
-                let children = self.children.drain(first_start..).collect::<Vec<_>>();
-
-                LuaGreenElement::Node {
-                    kind: parent_kind,
-                    children,
-                }
-            }
+        let (child_start, child_end) = match parent_kind {
+            // the root owns every trivia of the file
+            CppSyntaxKind::TranslationUnit => (first_start, child_count - 1),
             _ => {
-                while child_start < child_count {
+                let mut child_start = first_start;
+                let mut child_end = child_count - 1;
+                while child_start < child_end {
                     if self.is_trivia(self.children[child_start]) {
                         child_start += 1;
                     } else {
@@ -99,20 +85,19 @@ impl LuaGreenNodeBuilder<'_> {
                         break;
                     }
                 }
-
-                let children = self
-                    .children
-                    .drain(child_start..=child_end)
-                    .collect::<Vec<_>>();
-                LuaGreenElement::Node {
-                    kind: parent_kind,
-                    children,
-                }
+                (child_start, child_end)
             }
         };
 
+        let children = self
+            .children
+            .drain(child_start..=child_end)
+            .collect::<Vec<_>>();
         let pos = self.elements.len();
-        self.elements.push(green);
+        self.elements.push(CppGreenElement::Node {
+            kind: parent_kind,
+            children,
+        });
 
         if child_end + 1 < child_count {
             self.children.insert(child_start, pos);
@@ -122,21 +107,10 @@ impl LuaGreenNodeBuilder<'_> {
     }
 
     fn is_trivia(&self, pos: usize) -> bool {
-        if let Some(element) = self.elements.get(pos) {
-            match element {
-                LuaGreenElement::Token {
-                    kind: CppTokenKind::TkWhitespace | CppTokenKind::TkEndOfLine,
-                    ..
-                } => true,
-                LuaGreenElement::Node {
-                    kind: CppSyntaxKind::Comment,
-                    ..
-                } => true,
-                _ => false,
-            }
-        } else {
-            false
-        }
+        matches!(
+            self.elements.get(pos),
+            Some(CppGreenElement::Token { kind, .. }) if kind.is_trivia()
+        )
     }
 
     fn build_rowan_green(&mut self, parent: usize, text: &str) {
@@ -156,9 +130,9 @@ impl LuaGreenNodeBuilder<'_> {
                 continue;
             }
 
-            let element = std::mem::replace(&mut self.elements[item.index], LuaGreenElement::None);
+            let element = std::mem::replace(&mut self.elements[item.index], CppGreenElement::None);
             match element {
-                LuaGreenElement::Node { kind, children } => {
+                CppGreenElement::Node { kind, children } => {
                     self.builder.start_node(kind.into());
                     stack.push(StackItem {
                         index: item.index,
@@ -172,7 +146,7 @@ impl LuaGreenNodeBuilder<'_> {
                         });
                     }
                 }
-                LuaGreenElement::Token { kind, range } => {
+                CppGreenElement::Token { kind, range } => {
                     let start = range.start_offset;
                     let end = range.end_offset();
                     let token_text = &text[start..end];
@@ -185,28 +159,26 @@ impl LuaGreenNodeBuilder<'_> {
 
     #[inline]
     pub fn finish(mut self, text: &str) -> GreenNode {
-        if let Some(root_pos) = self.children.first() {
-            let is_chunk_root = matches!(
-                self.elements[*root_pos],
-                LuaGreenElement::Node {
-                    kind: CppSyntaxKind::Chunk,
+        let is_unit_root = self.children.len() == 1
+            && matches!(
+                self.elements[self.children[0]],
+                CppGreenElement::Node {
+                    kind: CppSyntaxKind::TranslationUnit,
                     ..
                 }
             );
-            if !is_chunk_root {
-                self.builder.start_node(CppSyntaxKind::Chunk.into());
-            }
-
-            self.build_rowan_green(*root_pos, text);
-
-            if !is_chunk_root {
-                self.builder.finish_node();
-            }
 
+        if is_unit_root {
+            self.build_rowan_green(self.children[0], text);
             return self.builder.finish();
         }
 
-        self.builder.start_node(CppSyntaxKind::Chunk.into());
+        self.builder
+            .start_node(CppSyntaxKind::TranslationUnit.into());
+        let roots = std::mem::take(&mut self.children);
+        for root in roots {
+            self.build_rowan_green(root, text);
+        }
         self.builder.finish_node();
         self.builder.finish()
     }
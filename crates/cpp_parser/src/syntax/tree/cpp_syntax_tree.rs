@@ -0,0 +1,52 @@
+use rowan::{GreenNode, TextRange};
+
+use crate::{
+    parser_error::{CppParseError, CppParseErrorKind},
+    syntax::{CppAstNode, CppSyntaxNode, CppTranslationUnit},
+};
+
+#[derive(Debug, Clone)]
+pub struct CppSyntaxTree {
+    // store GreenNode instead of SyntaxNode, because SyntaxNode is not send and sync
+    root: GreenNode,
+    errors: Vec<CppParseError>,
+    inactive_ranges: Vec<TextRange>,
+}
+
+impl CppSyntaxTree {
+    pub fn new(
+        root: GreenNode,
+        errors: Vec<CppParseError>,
+        inactive_ranges: Vec<TextRange>,
+    ) -> Self {
+        CppSyntaxTree {
+            root,
+            errors,
+            inactive_ranges,
+        }
+    }
+
+    // get root node, its kind is always TranslationUnit
+    pub fn get_red_root(&self) -> CppSyntaxNode {
+        CppSyntaxNode::new_root(self.root.clone())
+    }
+
+    pub fn get_translation_unit_node(&self) -> CppTranslationUnit {
+        CppTranslationUnit::cast(self.get_red_root()).unwrap()
+    }
+
+    pub fn get_errors(&self) -> &[CppParseError] {
+        &self.errors
+    }
+
+    /// Lines skipped by conditional compilation, e.g. the `#else` branch of a taken `#ifdef`
+    pub fn get_inactive_ranges(&self) -> &[TextRange] {
+        &self.inactive_ranges
+    }
+
+    pub fn has_syntax_errors(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|e| e.kind == CppParseErrorKind::SyntaxError)
+    }
+}
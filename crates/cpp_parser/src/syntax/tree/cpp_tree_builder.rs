@@ -6,37 +6,36 @@ use crate::{
     text::SourceRange,
 };
 
-use super::lua_green_builder::LuaGreenNodeBuilder;
+use super::cpp_green_builder::CppGreenNodeBuilder;
 
 #[derive(Debug)]
-pub struct LuaTreeBuilder<'a> {
+pub struct CppTreeBuilder<'a> {
     text: &'a str,
     events: Vec<MarkEvent>,
-    green_builder: LuaGreenNodeBuilder<'a>,
+    green_builder: CppGreenNodeBuilder<'a>,
 }
 
-impl<'a> LuaTreeBuilder<'a> {
+impl<'a> CppTreeBuilder<'a> {
     pub fn new(
         text: &'a str,
         events: Vec<MarkEvent>,
         node_cache: Option<&'a mut NodeCache>,
     ) -> Self {
         match node_cache {
-            Some(cache) => LuaTreeBuilder {
+            Some(cache) => CppTreeBuilder {
                 text,
                 events,
-                green_builder: LuaGreenNodeBuilder::with_cache(cache),
+                green_builder: CppGreenNodeBuilder::with_cache(cache),
             },
-            None => LuaTreeBuilder {
+            None => CppTreeBuilder {
                 text,
                 events,
-                green_builder: LuaGreenNodeBuilder::new(),
+                green_builder: CppGreenNodeBuilder::new(),
             },
         }
     }
 
     pub fn build(&mut self) {
-        self.start_node(CppSyntaxKind::Chunk);
         let mut parents: Vec<CppSyntaxKind> = Vec::new();
         for i in 0..self.events.len() {
             match std::mem::replace(&mut self.events[i], MarkEvent::none()) {
@@ -53,6 +52,11 @@ impl<'a> LuaTreeBuilder<'a> {
                             &mut self.events[parent_position],
                             MarkEvent::none(),
                         ) {
+                            // Already started by a node in front of it, see `extend_to`
+                            MarkEvent::NodeStart {
+                                kind: CppSyntaxKind::None,
+                                ..
+                            } => break,
                             MarkEvent::NodeStart { kind, parent } => {
                                 parents.push(kind);
                                 parent_position = parent;
@@ -73,8 +77,6 @@ impl<'a> LuaTreeBuilder<'a> {
                 }
             }
         }
-
-        self.finish_node();
     }
 
     fn token(&mut self, kind: CppTokenKind, range: SourceRange) {
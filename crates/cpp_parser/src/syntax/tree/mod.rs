@@ -1,7 +1,7 @@
-mod lua_green_builder;
-mod lua_syntax_tree;
-mod lua_tree_builder;
+mod cpp_green_builder;
+mod cpp_syntax_tree;
+mod cpp_tree_builder;
 mod test;
 
-pub use lua_syntax_tree::LuaSyntaxTree;
-pub use lua_tree_builder::LuaTreeBuilder;
+pub use cpp_syntax_tree::CppSyntaxTree;
+pub use cpp_tree_builder::CppTreeBuilder;
@@ -1,82 +1,139 @@
 #[cfg(test)]
-mod test {
-    use crate::{set_locale, LuaAstNode, LuaLanguageLevel, LuaParser, ParserConfig};
-    // use std::time::Instant;
-    use std::{collections::HashMap, thread};
+mod tests {
+    use crate::{
+        set_locale, CppLanguageLevel, CppParseErrorKind, CppParser, CppSyntaxId, CppSyntaxKind,
+        CppSyntaxNodePtr, CppTokenKind, ParserConfig,
+    };
+    use std::thread;
 
     #[test]
     fn test_multithreaded_syntax_tree_traversal() {
         let code = r#"
-            local a = 1
-            local b = 2
-            print(a + b)
+            int a = 1;
+            int b = 2;
+            print(a + b);
         "#;
-        let tree = LuaParser::parse(code, ParserConfig::default());
+        let tree = CppParser::parse(code, ParserConfig::default());
         let tree_arc = std::sync::Arc::new(tree);
 
         let mut handles = vec![];
 
-        for i in 0..4 {
+        for _ in 0..4 {
             let tree_ref = tree_arc.clone();
             let handle = thread::spawn(move || {
-                let node = tree_ref.get_chunk_node();
-                println!("{:?} {}", node.dump(), i);
+                let node = tree_ref.get_red_root();
+                (node.text().to_string(), node.descendants().count())
             });
             handles.push(handle);
         }
 
+        let expected = tree_arc.get_red_root().descendants().count();
         for handle in handles {
-            handle.join().unwrap();
+            assert_eq!(handle.join().unwrap(), (code.to_string(), expected));
         }
     }
 
     #[test]
-    fn test_lua51() {
+    fn test_cpp11() {
         let code = r#"
-if a ~= b then
-end
+struct A {
+};
         "#;
-        let parse_config = ParserConfig::new(LuaLanguageLevel::Lua51, None, HashMap::new());
-        let tree = LuaParser::parse(code, parse_config);
+        let parse_config = ParserConfig::new(CppLanguageLevel::Cpp11, None);
+        let tree = CppParser::parse(code, parse_config);
         assert_eq!(tree.get_errors().len(), 0);
     }
 
     #[test]
     fn test_tree_struct() {
         let code = r#"
-function f()
-    -- hh
-    local t
-end
+void f() {
+    // hh
+    int t;
+}
         "#;
-        let tree = LuaParser::parse(code, ParserConfig::default());
-        let chunk = tree.get_chunk_node();
-        println!("{:?}", chunk.dump());
+        let tree = CppParser::parse(code, ParserConfig::default());
+        let root = tree.get_red_root();
+        assert_eq!(root.text().to_string(), code);
+
+        // The comment belongs to the body, before the declaration that follows it
+        let body = root
+            .descendants()
+            .find(|node| node.kind() == CppSyntaxKind::CompoundStat.into())
+            .unwrap();
+        let kinds: Vec<String> = body
+            .children_with_tokens()
+            .filter(|it| {
+                !matches!(
+                    CppTokenKind::from(it.kind()),
+                    CppTokenKind::Whitespace | CppTokenKind::Newline
+                ) || it.as_node().is_some()
+            })
+            .map(|it| format!("{:?}", it.kind()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "Token(LeftBrace)",
+                "Token(LineComment)",
+                "Syntax(VariableDecl)",
+                "Token(RightBrace)"
+            ]
+        );
     }
 
     #[test]
     fn test_error() {
         let code = r#"
-local
+class
 "#;
         set_locale("zh_CN");
-        let tree = LuaParser::parse(code, ParserConfig::default());
+        let tree = CppParser::parse(code, ParserConfig::default());
         let errors = tree.get_errors();
-        for error in errors {
-            println!("{:?}", error);
-        }
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, CppParseErrorKind::SyntaxError);
+        assert_eq!(&code[errors[0].range], "\n");
     }
 
     #[test]
     fn test_bad_syntax() {
         let code = r#"
-JsonData.this[] = nil
+JsonData.this[] = nullptr;
 
----@param key string
----@return boolean
-local t
+// @param key string
+int t;
         "#;
 
-        let _ = LuaParser::parse(code, ParserConfig::default());
+        let _ = CppParser::parse(code, ParserConfig::default());
+    }
+
+    #[test]
+    fn test_syntax_id_and_ptr() {
+        let code = "int a = 1 + 2;\nvoid f() { while (a + 1) {} }\n";
+        let tree = CppParser::parse(code, ParserConfig::default());
+        let root = tree.get_red_root();
+
+        let binary_expr = root
+            .descendants()
+            .find(|node| node.kind() == CppSyntaxKind::BinaryExpr.into())
+            .unwrap();
+        let id = CppSyntaxId::from_node(&binary_expr);
+        assert!(id.is_node());
+        assert_eq!(id.get_kind(), CppSyntaxKind::BinaryExpr);
+        assert_eq!(id.to_node(&tree), Some(binary_expr.clone()));
+
+        let ptr = CppSyntaxNodePtr::new(&binary_expr);
+        assert_eq!(CppSyntaxId::from_ptr(ptr), id);
+        assert_eq!(ptr.to_node(&root), binary_expr);
+
+        let plus = binary_expr
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| it.kind() == CppTokenKind::Plus.into())
+            .unwrap();
+        let token_id = CppSyntaxId::from_token(&plus);
+        assert!(token_id.is_token());
+        assert_eq!(token_id.get_token_kind(), CppTokenKind::Plus);
+        assert_eq!(token_id.to_token(&tree), Some(plus));
     }
 }
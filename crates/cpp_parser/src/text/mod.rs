@@ -1,8 +1,10 @@
 mod line_index;
 mod reader;
+mod source_chars;
 mod test;
 mod text_range;
 
 pub use line_index::LineIndex;
 pub use reader::Reader;
+pub(crate) use source_chars::remove_line_splices;
 pub(crate) use text_range::SourceRange;
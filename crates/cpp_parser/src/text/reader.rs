@@ -1,18 +1,20 @@
-use std::{iter::Peekable, str::Chars};
+use std::iter::Peekable;
 
-use super::text_range::SourceRange;
+use super::{source_chars::SourceChars, text_range::SourceRange};
 pub const EOF: char = '\0';
 
 #[derive(Debug, Clone)]
 pub struct Reader<'a> {
     text: &'a str,
     valid_range: SourceRange,
-    chars: Peekable<Chars<'a>>,
+    chars: Peekable<SourceChars<'a>>,
     save_buffer_byte_pos: usize,
     save_buffer_byte_len: usize,
     current_char_len: usize,
+    current_splice_len: usize,
     current: char,
     start: bool,
+    line_start: bool,
 }
 
 impl<'a> Reader<'a> {
@@ -20,30 +22,44 @@ impl<'a> Reader<'a> {
         Self::new_with_range(text, SourceRange::new(0, text.len()))
     }
 
+    /// Read the characters of `range` with line splices removed, ranges keep referring to
+    /// the original text
     pub fn new_with_range(text: &'a str, range: SourceRange) -> Self {
         let text = text[range.start_offset..range.end_offset()].as_ref();
         Self {
             text,
             valid_range: range,
-            chars: text.chars().peekable(),
+            chars: SourceChars::new(text).peekable(),
             save_buffer_byte_pos: 0,
             save_buffer_byte_len: 0,
             current_char_len: 0,
+            current_splice_len: 0,
             current: EOF,
             start: false,
+            line_start: true,
         }
     }
 
     pub fn bump(&mut self) {
+        if self.current_char_len > 0 {
+            match self.current {
+                '\n' | '\r' => self.line_start = true,
+                ' ' | '\t' | '\u{FEFF}' => {}
+                _ => self.line_start = false,
+            }
+        }
+
         if let Some(c) = self.chars.next() {
-            self.current = c;
+            self.current = c.ch;
             self.save_buffer_byte_len += self.current_char_len;
-            self.current_char_len = c.len_utf8();
+            self.current_char_len = c.len;
+            self.current_splice_len = c.splice_len;
         } else {
             self.current = EOF;
             if self.current_char_len > 0 {
                 self.save_buffer_byte_len += self.current_char_len;
                 self.current_char_len = 0;
+                self.current_splice_len = 0;
             }
         }
     }
@@ -61,16 +77,18 @@ impl<'a> Reader<'a> {
         self.current == EOF && self.start
     }
 
+    /// Whether nothing but whitespace was read since the last newline
     pub fn is_start_of_line(&self) -> bool {
-        self.save_buffer_byte_pos == 0
+        self.line_start
     }
 
     pub fn current_char(&self) -> char {
         self.current
     }
 
+    #[allow(unused)]
     pub fn next_char(&mut self) -> char {
-        self.chars.peek().cloned().unwrap_or(EOF)
+        self.chars.peek().map(|it| it.ch).unwrap_or(EOF)
     }
 
     pub fn saved_range(&self) -> SourceRange {
@@ -80,11 +98,23 @@ impl<'a> Reader<'a> {
         )
     }
 
+    /// Range of the current, not yet consumed character, empty at end of input
+    pub fn current_char_range(&self) -> SourceRange {
+        SourceRange::new(
+            self.valid_range.start_offset
+                + self.save_buffer_byte_pos
+                + self.save_buffer_byte_len
+                + self.current_splice_len,
+            self.current_char_len - self.current_splice_len,
+        )
+    }
+
     pub fn current_saved_text(&self) -> &str {
         &self.text
             [self.save_buffer_byte_pos..(self.save_buffer_byte_pos + self.save_buffer_byte_len)]
     }
 
+    #[allow(unused)]
     pub fn eat_when(&mut self, ch: char) -> usize {
         let mut count = 0;
         while !self.is_eof() && self.current_char() == ch {
@@ -94,6 +124,7 @@ impl<'a> Reader<'a> {
         count
     }
 
+    #[allow(unused)]
     pub fn consume_char_n_times(&mut self, ch: char, count: usize) -> usize {
         let mut eaten = 0;
         while !self.is_eof() && self.current_char() == ch && eaten < count {
@@ -115,10 +146,12 @@ impl<'a> Reader<'a> {
         count
     }
 
+    #[allow(unused)]
     pub fn get_source_text(&self) -> &str {
         self.text
     }
 
+    #[allow(unused)]
     pub fn get_current_end_pos(&self) -> usize {
         self.save_buffer_byte_pos + self.save_buffer_byte_len
     }
@@ -221,4 +254,21 @@ mod tests {
         assert_eq!(count, 5);
         assert_eq!(reader.current_char(), 'H');
     }
+
+    #[test]
+    fn test_line_splices() {
+        let text = "ab\\\ncd\\\r\n\\\nef \\\n";
+        let mut reader = Reader::new(text);
+        reader.reset_buff();
+        let count = reader.eat_while(|c| c.is_ascii_alphabetic());
+        assert_eq!(count, 6);
+        assert_eq!(reader.current_saved_text(), "ab\\\ncd\\\r\n\\\nef");
+        assert_eq!(reader.current_char_range(), SourceRange::new(13, 1));
+
+        // A splice at the end of the text is not removed
+        reader.reset_buff();
+        reader.bump();
+        assert_eq!(reader.current_char(), '\\');
+        assert_eq!(reader.next_char(), '\n');
+    }
 }
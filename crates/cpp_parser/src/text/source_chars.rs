@@ -0,0 +1,74 @@
+use std::str::Chars;
+
+/// A character of the source after translation phases 1 and 2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceChar {
+    pub ch: char,
+    /// Bytes taken in the original text, including the line splices in front of the character
+    pub len: usize,
+    /// Bytes of the line splices in front of the character
+    pub splice_len: usize,
+}
+
+/// Iterates the characters of the source with line splices (a backslash directly followed
+/// by a newline) removed, so that continued lines read as one logical line.
+#[derive(Debug, Clone)]
+pub struct SourceChars<'a> {
+    chars: Chars<'a>,
+}
+
+impl<'a> SourceChars<'a> {
+    pub fn new(text: &'a str) -> Self {
+        SourceChars {
+            chars: text.chars(),
+        }
+    }
+
+    /// Length of the line splice at the start of `text`, 0 if there is none. A splice at the
+    /// very end of the text is left alone so that every byte still belongs to a token.
+    fn splice_len(text: &str) -> usize {
+        let Some(rest) = text.strip_prefix('\\') else {
+            return 0;
+        };
+        let len = if rest.starts_with("\r\n") {
+            3
+        } else if rest.starts_with(['\n', '\r']) {
+            2
+        } else {
+            return 0;
+        };
+        if len == text.len() {
+            0
+        } else {
+            len
+        }
+    }
+}
+
+impl Iterator for SourceChars<'_> {
+    type Item = SourceChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut splice_len = 0;
+        loop {
+            let len = Self::splice_len(self.chars.as_str());
+            if len == 0 {
+                break;
+            }
+            splice_len += len;
+            self.chars = self.chars.as_str()[len..].chars();
+        }
+
+        let ch = self.chars.next()?;
+        Some(SourceChar {
+            ch,
+            len: splice_len + ch.len_utf8(),
+            splice_len,
+        })
+    }
+}
+
+/// Remove the line splices from a piece of source text
+pub fn remove_line_splices(text: &str) -> String {
+    SourceChars::new(text).map(|it| it.ch).collect()
+}